@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! Simple performance test for writing formatted cells, with runs of the same
+//! format and with alternating formats. Run it in release mode:
+//!
+//! cargo run --release --example app_perf_test_formats
+
+use std::time::{Duration, Instant};
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+const ROW_MAX: u32 = 40_000;
+const COL_MAX: u16 = 50;
+const ROUNDS: usize = 9;
+
+fn main() -> Result<(), XlsxError> {
+    let number_format = Format::new()
+        .set_num_format("#,##0.00")
+        .set_font_name("Arial");
+    let bold = Format::new().set_bold();
+
+    let same_time = time_formats(&[&number_format])?;
+    let alternating_time = time_formats(&[&number_format, &bold])?;
+
+    let cells = u64::from(ROW_MAX) * u64::from(COL_MAX);
+    println!("Cells written:      {cells}");
+    println!("Same format:        {same_time:?}");
+    println!("Alternating format: {alternating_time:?}");
+
+    Ok(())
+}
+
+// Write formatted numbers, cycling through the formats in each row, and
+// return the fastest of several rounds to reduce the timing noise.
+fn time_formats(formats: &[&Format]) -> Result<Duration, XlsxError> {
+    let mut best_time = Duration::MAX;
+
+    for _ in 0..ROUNDS {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        let start = Instant::now();
+        for row in 0..ROW_MAX {
+            for col in 0..COL_MAX {
+                let format = formats[usize::from(col) % formats.len()];
+                worksheet.write_with_format(row, col, 12345.0, format)?;
+            }
+        }
+        best_time = best_time.min(start.elapsed());
+    }
+
+    Ok(best_time)
+}
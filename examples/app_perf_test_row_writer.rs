@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! Simple performance comparison of writing row ordered data with
+//! `worksheet.write()` and with a `RowWriter`. Run it in release mode:
+//!
+//! cargo run --release --example app_perf_test_row_writer
+
+use std::time::{Duration, Instant};
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+const ROW_MAX: u32 = 40_000;
+const COL_MAX: u16 = 50;
+const ROUNDS: usize = 9;
+
+fn main() -> Result<(), XlsxError> {
+    let mut write_time = Duration::MAX;
+    let mut row_writer_time = Duration::MAX;
+
+    // Take the fastest of several rounds to reduce the timing noise.
+    for _ in 0..ROUNDS {
+        write_time = write_time.min(time_write()?);
+        row_writer_time = row_writer_time.min(time_row_writer()?);
+    }
+
+    let cells = u64::from(ROW_MAX) * u64::from(COL_MAX);
+    println!("Cells written:          {cells}");
+    println!("worksheet.write():      {write_time:?}");
+    println!("row_writer.push_cell(): {row_writer_time:?}");
+
+    Ok(())
+}
+
+// Write the data cell by cell.
+fn time_write() -> Result<Duration, XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let start = Instant::now();
+    for row in 0..ROW_MAX {
+        for col in 0..COL_MAX {
+            if col % 2 == 1 {
+                worksheet.write(row, col, "Foo")?;
+            } else {
+                worksheet.write(row, col, 12345.0)?;
+            }
+        }
+    }
+
+    Ok(start.elapsed())
+}
+
+// Write the same data with a row writer.
+fn time_row_writer() -> Result<Duration, XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let start = Instant::now();
+    let mut row_writer = worksheet.row_writer();
+    for _ in 0..ROW_MAX {
+        for col in 0..COL_MAX {
+            if col % 2 == 1 {
+                row_writer.push_cell("Foo")?;
+            } else {
+                row_writer.push_cell(12345.0)?;
+            }
+        }
+        row_writer.end_row();
+    }
+    drop(row_writer);
+
+    Ok(start.elapsed())
+}
@@ -9,7 +9,7 @@ use rust_xlsxwriter::{Workbook, XlsxError};
 
 fn main() -> Result<(), XlsxError> {
     // Some sample data we want to write to a spreadsheet.
-    let expenses = vec![("Rent", 2000), ("Gas", 200), ("Food", 500), ("Gym", 100)];
+    let expenses = [("Rent", 2000), ("Gas", 200), ("Food", 500), ("Gym", 100)];
 
     // Create a new Excel file object.
     let mut workbook = Workbook::new();
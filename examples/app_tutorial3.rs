@@ -10,7 +10,7 @@ use rust_xlsxwriter::{Format, Workbook, XlsxError};
 
 fn main() -> Result<(), XlsxError> {
     // Some sample data we want to write to a spreadsheet.
-    let expenses = vec![
+    let expenses = [
         ("Rent", 2000, "2022-09-01"),
         ("Gas", 200, "2022-09-05"),
         ("Food", 500, "2022-09-21"),
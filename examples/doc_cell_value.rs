@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing rows of mixed data types to a
+//! worksheet.
+
+use chrono::NaiveDate;
+use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    let date = NaiveDate::from_ymd_opt(2023, 1, 25).unwrap();
+
+    let records = [
+        vec![
+            CellValue::String("Alice".to_string()),
+            CellValue::Number(1234.5),
+            CellValue::Boolean(true),
+            CellValue::Date(date),
+        ],
+        vec![
+            CellValue::String("Bob".to_string()),
+            CellValue::Blank,
+            CellValue::Boolean(false),
+            CellValue::Date(date),
+        ],
+    ];
+
+    for (row, record) in records.iter().enumerate() {
+        worksheet.write_row(row as u32, 0, record)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating setting the point where the axes cross.
+
+use rust_xlsxwriter::{Chart, ChartAxisCrossing, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 5)?;
+    worksheet.write(1, 0, 30)?;
+    worksheet.write(2, 0, 40)?;
+    worksheet.write(3, 0, 30)?;
+    worksheet.write(4, 0, 5)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+
+    // Add a data series using Excel formula syntax to describe the range.
+    chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+    // Set the value axis to cross at the 3rd category and the category axis to
+    // cross at the maximum value.
+    chart
+        .x_axis()
+        .set_crossing(ChartAxisCrossing::AxisValue(3.0));
+    chart.y_axis().set_crossing(ChartAxisCrossing::Max);
+
+    // Hide legend for clarity.
+    chart.legend().set_hidden();
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! An example of deleting an entry from a chart legend.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 50)?;
+    worksheet.write(1, 0, 30)?;
+    worksheet.write(2, 0, 40)?;
+    worksheet.write(0, 1, 45)?;
+    worksheet.write(1, 1, 45)?;
+    worksheet.write(2, 1, 45)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+
+    // Add the data series and a target line series.
+    chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    chart.add_series().set_values("Sheet1!$B$1:$B$3");
+
+    // Remove the target series from the legend.
+    chart.legend().set_delete_entries(&[1]);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 3, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! An example of exploding a segment of a pie chart.
+
+use rust_xlsxwriter::{Chart, ChartPoint, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 10)?;
+    worksheet.write(1, 0, 40)?;
+    worksheet.write(2, 0, 50)?;
+
+    // Pull out the second segment of the pie.
+    let points = vec![ChartPoint::new(), ChartPoint::new().set_explosion(20)];
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Pie);
+
+    // Add a data series with the exploded point.
+    chart
+        .add_series()
+        .set_values("Sheet1!$A$1:$A$3")
+        .set_points(&points);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! An example of using a formatted chart as a template for several charts with
+//! different data ranges.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the charts.
+    let data = [[10, 40, 50], [20, 10, 50], [30, 60, 20]];
+    for (col_num, col_data) in data.iter().enumerate() {
+        worksheet.write_column(0, col_num as u16, *col_data)?;
+    }
+
+    // Create a formatted chart to use as a template.
+    let mut template = Chart::new(ChartType::Column);
+    template.add_series().set_values("Sheet1!$A$1:$A$3");
+    template.set_style(26);
+    template.legend().set_hidden();
+    template.y_axis().set_max(100);
+
+    // Create a chart for each column of data from the template.
+    for col_num in 0..3 {
+        let mut chart = template.clone();
+        chart
+            .series(0)?
+            .set_values(("Sheet1", 0, col_num, 2, col_num));
+
+        worksheet.insert_chart(u32::from(col_num) * 16, 4, &chart)?;
+    }
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating setting the rotation, perspective and depth
+//! of a 3-D chart.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 50)?;
+    worksheet.write(1, 0, 30)?;
+    worksheet.write(2, 0, 40)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column3D);
+
+    // Add a data series using Excel formula syntax to describe the range.
+    chart.add_series().set_values("Sheet1!$A$1:$A$3");
+
+    // Set the 3-D view of the chart.
+    chart.set_3d_rotation(30, 40);
+    chart.set_3d_perspective(15);
+    chart.set_3d_depth(200);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
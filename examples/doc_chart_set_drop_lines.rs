@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! An example of adding drop lines and high-low lines to a line chart.
+
+use rust_xlsxwriter::{Chart, ChartLine, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 10)?;
+    worksheet.write(1, 0, 40)?;
+    worksheet.write(2, 0, 50)?;
+    worksheet.write(0, 1, 20)?;
+    worksheet.write(1, 1, 25)?;
+    worksheet.write(2, 1, 30)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Line);
+
+    // Add the data series.
+    chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    chart.add_series().set_values("Sheet1!$B$1:$B$3");
+
+    // Add drop lines and formatted high-low lines.
+    chart.set_drop_lines(true);
+    chart.set_high_low_lines_line(ChartLine::new().set_color("#FF0000"));
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 3, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating displaying empty cells in a chart as a line
+//! that spans the gap and showing data from hidden rows.
+
+use rust_xlsxwriter::{Chart, ChartEmptyCells, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart, with a blank cell.
+    worksheet.write(0, 0, 10)?;
+    worksheet.write(1, 0, 40)?;
+    worksheet.write(3, 0, 20)?;
+    worksheet.write(4, 0, 30)?;
+
+    // Hide one of the data rows.
+    worksheet.set_row_hidden(4)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Line);
+
+    // Add a data series using Excel formula syntax to describe the range.
+    chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+    // Span the gap caused by the blank cell and show the hidden data.
+    chart.show_blanks_as(ChartEmptyCells::Span);
+    chart.show_hidden_data(true);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing boolean values as checkboxes.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new().set_checkbox();
+
+    worksheet.write_boolean_with_format(0, 0, true, &format)?;
+    worksheet.write_boolean_with_format(1, 0, false, &format)?;
+
+    workbook.save("formats.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a worksheet header and footer
+//! using the `HeaderFooter` builder.
+
+use rust_xlsxwriter::{HeaderFooter, HeaderFooterField, HeaderFooterText, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Equivalent to "&L&BConfidential&B&CPage &P of &N".
+    let header = HeaderFooter::new()
+        .add_left(HeaderFooterText::new("Confidential").set_bold())
+        .add_center("Page ")
+        .add_center(HeaderFooterField::PageNumber)
+        .add_center(" of ")
+        .add_center(HeaderFooterField::TotalPages);
+
+    // Equivalent to "&RR&&D Department".
+    let footer = HeaderFooter::new().add_right("R&D Department");
+
+    worksheet.set_header(&header);
+    worksheet.set_footer(&footer);
+
+    worksheet.write_string(0, 0, "Hello")?;
+    worksheet.write_string(200, 0, "Hello")?;
+    worksheet.set_view_page_layout();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates reducing the stored size of an image.
+//! It requires the `image-compression` feature.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object.
+    let mut image = Image::new("examples/watermark.png")?;
+
+    // Store the image at a maximum of 200 pixels wide or high.
+    image.recompress(200, 85)?;
+
+    // Insert the image.
+    worksheet.insert_image(1, 2, &image)?;
+
+    // Save the file to disk.
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates cropping an image in a worksheet.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object.
+    let mut image = Image::new("examples/rust_logo.png")?;
+
+    // Crop the right half of the image.
+    image.set_crop(0.0, 0.0, 50.0, 0.0);
+
+    // Insert the image.
+    worksheet.insert_image(1, 2, &image)?;
+
+    // Save the file to disk.
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates overriding the DPI of an image so that
+//! it is displayed at its pixel size.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object. This image has a DPI of 330.
+    let mut image = Image::new("examples/watermark.png")?;
+
+    // Display the image at its pixel size by using the default DPI.
+    image.set_dpi(96.0, 96.0);
+
+    // Insert the image.
+    worksheet.insert_image(1, 2, &image)?;
+
+    // Save the file to disk.
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a hyperlink to an image.
+
+use rust_xlsxwriter::{Image, Url, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object.
+    let mut image = Image::new("examples/rust_logo.png")?;
+
+    // Add a hyperlink with a screen tip to the image.
+    image.set_url(Url::new("https://www.rust-lang.org").set_tip("Learn Rust"));
+
+    // Insert the image.
+    worksheet.insert_image(1, 2, &image)?;
+
+    // Save the file to disk.
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
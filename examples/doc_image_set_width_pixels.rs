@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the displayed size of an image
+//! in pixels.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object.
+    let mut image = Image::new("examples/rust_logo.png")?;
+
+    // Display the image at 200 x 100 pixels.
+    image.set_width_pixels(200).set_height_pixels(100);
+
+    // Insert the image.
+    worksheet.insert_image(1, 2, &image)?;
+
+    // Save the file to disk.
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates applying the same page setup to several
+//! worksheets.
+
+use rust_xlsxwriter::{PageSetup, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let page_setup = PageSetup::new()
+        .set_landscape()
+        .set_paper_size(9) // A4 paper size.
+        .set_margins(0.5, 0.5, 0.75, 0.75, 0.3, 0.3)
+        .set_print_fit_to_pages(1, 0)
+        .set_header("&CQuarterly Sales")
+        .set_footer("&CPage &P of &N");
+
+    for region in ["North", "South", "East", "West"] {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(region)?;
+        worksheet.set_page_setup(&page_setup)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a user defined heading to the
+//! document properties.
+
+use rust_xlsxwriter::{DocProperties, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.add_worksheet().set_name("Summary")?;
+    workbook.add_worksheet().set_name("Q1")?;
+    workbook.add_worksheet().set_name("Q2")?;
+
+    let properties = DocProperties::new().set_heading_pair("Quarterly Reports", &["Q1", "Q2"]);
+
+    workbook.set_properties(&properties);
+
+    workbook.save("properties.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a thumbnail image to the
+//! document properties.
+
+use rust_xlsxwriter::{DocProperties, Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let image = Image::new("examples/rust_logo.png")?;
+    let properties = DocProperties::new().set_thumbnail(&image);
+    workbook.set_properties(&properties);
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Hello")?;
+
+    workbook.save("properties.xlsx")?;
+
+    Ok(())
+}
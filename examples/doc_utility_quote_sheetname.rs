@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates building a formula that refers to a user
+//! provided worksheet name.
+
+use rust_xlsxwriter::utility::quote_sheetname;
+
+fn main() {
+    assert_eq!(quote_sheetname("Sales"), "Sales");
+    assert_eq!(quote_sheetname("Sales Data"), "'Sales Data'");
+    assert_eq!(quote_sheetname("Bob's"), "'Bob''s'");
+    assert_eq!(quote_sheetname("Q1"), "'Q1'");
+
+    let formula = format!("=SUM({}!B2:B10)", quote_sheetname("Sales Data"));
+    assert_eq!(formula, "=SUM('Sales Data'!B2:B10)");
+}
@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates building a formula that contains a user
+//! provided string.
+
+use rust_xlsxwriter::utility::quote_string;
+
+fn main() {
+    let formula = format!("=COUNTIF(A:A,{})", quote_string(r#"12" pipe"#));
+
+    assert_eq!(formula, r#"=COUNTIF(A:A,"12"" pipe")"#);
+}
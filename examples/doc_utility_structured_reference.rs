@@ -0,0 +1,18 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates building formulas that refer to table
+//! columns.
+
+use rust_xlsxwriter::utility::structured_reference;
+
+fn main() {
+    assert_eq!(structured_reference("Sales", "Amount"), "Sales[Amount]");
+    assert_eq!(
+        structured_reference("Sales", "Amount (USD)"),
+        "Sales[[Amount (USD)]]"
+    );
+    assert_eq!(structured_reference("Sales", "Item #"), "Sales[[Item '#]]");
+    assert_eq!(structured_reference("", "Amount"), "[Amount]");
+}
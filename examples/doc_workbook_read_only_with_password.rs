@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a simple workbook that requires
+//! a password to save changes to the file.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let _worksheet = workbook.add_worksheet();
+
+    workbook.read_only_with_password("abc123");
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
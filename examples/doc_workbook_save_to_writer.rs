@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a simple workbook and
+//! streaming it to STDOUT, which doesn't support seeking.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Hello")?;
+
+    // Stream the file to STDOUT, for example: `cargo run > hello.xlsx`.
+    workbook.save_to_writer(std::io::stdout())?;
+
+    Ok(())
+}
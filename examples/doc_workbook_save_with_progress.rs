@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates displaying the progress of saving a
+//! workbook.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    for _ in 0..3 {
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_string(0, 0, "Hello")?;
+    }
+
+    workbook.save_with_progress("workbook.xlsx", |stage, fraction| {
+        println!("{:3.0}% {stage}", fraction * 100.0);
+    })?;
+
+    Ok(())
+}
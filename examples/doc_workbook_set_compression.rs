@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates saving a workbook without zip
+//! compression to reduce the save time.
+
+use rust_xlsxwriter::{CompressionLevel, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Hello")?;
+
+    workbook.set_compression(CompressionLevel::Stored);
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates saving a workbook in deterministic mode
+//! so that the output is the same each time it is saved.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_string(0, 0, "Hello")?;
+
+    workbook.set_deterministic(true);
+
+    let first = workbook.save_to_buffer()?;
+    let second = workbook.save_to_buffer()?;
+
+    assert_eq!(first, second);
+
+    Ok(())
+}
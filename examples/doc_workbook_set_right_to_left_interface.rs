@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates changing the direction of all the
+//! worksheets in a workbook to right-to-left.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.add_worksheet().write_string(0, 0, "نص عربي")?;
+    workbook.add_worksheet().write_string(0, 0, "نص عربي")?;
+
+    workbook.set_right_to_left_interface(true);
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates truncating long strings in all the
+//! worksheets in a workbook.
+
+use rust_xlsxwriter::{StringOverflow, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    workbook.set_string_overflow(StringOverflow::Truncate);
+
+    // The strings are truncated to 32,767 characters, ending in "…".
+    let notes = "Lorem ipsum. ".repeat(3000);
+    workbook.add_worksheet().write_string(0, 0, &notes)?;
+    workbook.add_worksheet().write_string(0, 0, &notes)?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
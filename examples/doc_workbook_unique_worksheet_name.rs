@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating worksheets with names derived
+//! from data.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    for customer in ["Acme Corp.", "ACME Corp.", "Smith & Sons [UK]"] {
+        let name = workbook.unique_worksheet_name(customer);
+        workbook.add_worksheet().set_name(name)?;
+    }
+
+    // The worksheet names are "Acme Corp.", "ACME Corp. (2)" and
+    // "Smith & Sons UK".
+    assert_eq!("ACME Corp. (2)", workbook.worksheet_from_index(1)?.name());
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
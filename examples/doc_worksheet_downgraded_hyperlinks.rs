@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates checking the number of urls that were
+//! written as text once the hyperlink limit was reached.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_hyperlink_limit_fallback(true);
+
+    // Write more urls than Excel allows in a worksheet. The urls after the
+    // limit are written as text.
+    for row in 0..70_000 {
+        worksheet.write_url(row, 0, "https://www.rust-lang.org")?;
+    }
+
+    // Get the number of urls written as text. This could be logged as a
+    // warning that some of the links are missing.
+    let count = worksheet.downgraded_hyperlinks();
+    assert_eq!(count, 4470);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates freezing the top row and the first
+//! column of worksheets.
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Freeze the top row only.
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.write_string(0, 0, "Scroll down")?;
+    worksheet1.freeze_top_row();
+
+    // Freeze the leftmost column only.
+    let worksheet2 = workbook.add_worksheet();
+    worksheet2.write_string(0, 0, "Scroll across")?;
+    worksheet2.freeze_first_column();
+
+    // Freeze the top two rows and leftmost column.
+    let worksheet3 = workbook.add_worksheet();
+    worksheet3.write_string(0, 0, "Scroll down or across")?;
+    worksheet3.freeze_headers(2, 1)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
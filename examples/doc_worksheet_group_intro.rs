@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing the same headers and page setup
+//! to a group of worksheets.
+use rust_xlsxwriter::{Format, PageSetup, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let bold = Format::new().set_bold();
+    let page_setup = PageSetup::new()
+        .set_landscape()
+        .set_header("&CMonthly Sales");
+
+    for month in ["Jan", "Feb", "Mar"] {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(month)?;
+    }
+
+    // Apply the same layout to all three worksheets.
+    let mut group = workbook.group(&["Jan", "Feb", "Mar"])?;
+    group.write_with_format(0, 0, "Region", &bold)?;
+    group.write_with_format(0, 1, "Sales", &bold)?;
+    group.set_page_setup(&page_setup)?;
+    group.apply(|worksheet| {
+        worksheet.set_column_width(0, 16)?;
+        Ok(())
+    })?;
+
+    // Add the worksheet specific data.
+    let worksheet = workbook.worksheet_from_name("Feb")?;
+    worksheet.write(1, 0, "North")?;
+    worksheet.write(1, 1, 1234)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
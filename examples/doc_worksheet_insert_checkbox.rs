@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates inserting checkboxes in worksheet cells.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, "Done")?;
+    worksheet.insert_checkbox(1, 0, true)?;
+    worksheet.insert_checkbox(2, 0, false)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding an image to a worksheet at an
+//! absolute position.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a new image object.
+    let image = Image::new("examples/rust_logo.png")?;
+
+    // Insert the image 100 pixels from the left and 50 pixels from the
+    // top of the worksheet, at 9,525 EMUs per pixel.
+    worksheet.insert_image_absolute(&image, 100 * 9525, 50 * 9525)?;
+
+    // Save the file to disk.
+    workbook.save("image.xlsx")?;
+
+    Ok(())
+}
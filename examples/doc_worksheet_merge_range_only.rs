@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates merging cells without a format and then
+//! unmerging a range.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Merge some cells without a format.
+    worksheet.merge_range_only(1, 1, 1, 3, "Merged cells")?;
+    worksheet.merge_range_only(3, 1, 4, 3, 12345)?;
+
+    // Unmerge one of the ranges.
+    worksheet.unmerge_range(3, 1, 4, 3)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates applying the same options to several
+//! worksheets.
+
+use rust_xlsxwriter::{Color, Workbook, WorksheetOptions, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let options = WorksheetOptions::new()
+        .set_tab_color(Color::Green)
+        .set_zoom(80)
+        .set_freeze_panes(1, 0)
+        .set_screen_gridlines(false);
+
+    for region in ["North", "South", "East", "West"] {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(region)?.set_options(&options)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing rows of data to a worksheet
+//! using a row writer.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+    let bold = Format::new().set_bold();
+
+    // Write a header row.
+    worksheet.write_with_format(0, 0, "Item", &bold)?;
+    worksheet.write_with_format(0, 1, "Cost", &bold)?;
+
+    // Some data to write.
+    let expenses = [("Rent", 2000), ("Gas", 200), ("Food", 500)];
+
+    // Append the data to the worksheet, starting after the header row.
+    let mut row_writer = worksheet.row_writer();
+    for (item, cost) in expenses {
+        row_writer.push_cell(item)?.push_cell(cost)?.end_row();
+    }
+
+    // Release the worksheet borrow before saving.
+    drop(row_writer);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates converting a string into a valid
+//! worksheet name.
+
+use rust_xlsxwriter::Worksheet;
+
+fn main() {
+    let name = Worksheet::sanitize_name("Sales: 2023/04 [Draft]");
+
+    assert_eq!(name, "Sales 202304 Draft");
+    assert!(Worksheet::validate_name(&name).is_ok());
+}
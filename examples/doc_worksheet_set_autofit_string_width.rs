@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates autofitting columns for data written in
+//! a larger font and with CJK characters.
+
+use rust_xlsxwriter::{utility, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new().set_font_size(16);
+
+    worksheet.write_with_format(0, 0, "Quarterly sales report", &format)?;
+    worksheet.write_with_format(1, 0, "四半期売上報告", &format)?;
+
+    // Scale the default Calibri 11 widths to 16 points, and treat CJK
+    // characters as double width.
+    worksheet.set_autofit_string_width(|string| {
+        let width: u32 = string
+            .chars()
+            .map(|char| {
+                if char > '\u{2E80}' {
+                    16
+                } else {
+                    u32::from(utility::pixel_width(&char.to_string()))
+                }
+            })
+            .sum();
+
+        (width * 16 / 11) as u16
+    });
+
+    worksheet.autofit();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
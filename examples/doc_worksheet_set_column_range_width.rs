@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the width of a range of columns
+//! in Excel.
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add some text.
+    worksheet.write_string(0, 0, "Normal")?;
+    worksheet.write_string(0, 2, "Wider")?;
+
+    // Set the width of columns C to J in Excel character units.
+    worksheet.set_column_range_width(2, 9, 16)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
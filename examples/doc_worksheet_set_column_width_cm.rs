@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the width of columns, the height
+//! of rows and the page margins in metric units.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Set the column widths and row heights in metric units.
+    worksheet.set_column_width_cm(0, 5)?;
+    worksheet.set_column_width_mm(1, 25)?;
+    worksheet.set_row_height_cm(0, 1.5)?;
+
+    // Set the page margins in centimeters.
+    worksheet.set_margins_cm(2.0, 2.0, 2.5, 2.5, -1.0, -1.0);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding images to the left and right
+//! sections of a worksheet header.
+
+use rust_xlsxwriter::{Image, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create an image from a file and another from an in-memory buffer.
+    let mut image1 = Image::new("examples/rust_logo.png")?;
+    image1.set_scale_width(0.5).set_scale_height(0.5);
+
+    let buffer = std::fs::read("examples/rust_logo.png")?;
+    let mut image2 = Image::new_from_buffer(&buffer)?;
+    image2.set_scale_width(0.5).set_scale_height(0.5);
+
+    // Insert the images in the left and right sections of the header.
+    worksheet.set_header("&L&[Picture]&CQuarterly Report&R&[Picture]");
+    worksheet.set_header_images([Some(&image1), None, Some(&image2)])?;
+
+    // Increase the top margin to 1.2 for clarity. The -1.0 values are ignored.
+    worksheet.set_margins(-1.0, -1.0, 1.2, -1.0, -1.0, -1.0);
+
+    // Set Page View mode so the images are visible.
+    worksheet.set_view_page_layout();
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing the worksheet strings as inline
+//! strings.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_inline_strings(true);
+
+    for row in 0..100 {
+        worksheet.write_string(row, 0, format!("Order {}", row + 1))?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates hiding the worksheet gridlines and the
+//! row and column headers on the screen.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(1, 1, "Form style worksheet")?;
+
+    // Hide the gridlines and the row and column headers.
+    worksheet.set_screen_gridlines(false);
+    worksheet.set_row_col_headers(false);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
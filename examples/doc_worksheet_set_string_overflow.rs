@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates truncating strings that are longer than
+//! Excel's limit.
+
+use rust_xlsxwriter::{StringOverflow, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_string_overflow(StringOverflow::Truncate);
+
+    // The string is truncated to 32,767 characters, ending in "…".
+    let notes = "Lorem ipsum. ".repeat(3000);
+    worksheet.write_string(0, 0, notes)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting statistics about the content of
+//! a worksheet.
+use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write some data.
+    worksheet.write(0, 0, "Apples")?;
+    worksheet.write(0, 1, 10)?;
+    worksheet.write(1, 0, "Pears")?;
+    worksheet.write(1, 1, 20)?;
+    worksheet.write(2, 1, Formula::new("=SUM(B1:B2)"))?;
+
+    // Check the worksheet content.
+    let stats = worksheet.stats();
+
+    assert_eq!(stats.strings, 2);
+    assert_eq!(stats.numbers, 2);
+    assert_eq!(stats.formulas, 1);
+    assert_eq!(stats.used_range, Some((0, 0, 2, 1)));
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates turning off the default date format so
+//! that dates adopt a column format.
+
+use chrono::NaiveDate;
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    let date_format = Format::new().set_num_format("d mmmm yyyy");
+    worksheet.set_column_format(0, &date_format)?;
+    worksheet.set_column_width(0, 20)?;
+
+    worksheet.use_default_datetime_formats(false);
+
+    let date = NaiveDate::from_ymd_opt(2023, 1, 25).unwrap();
+    worksheet.write(0, 0, &date)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
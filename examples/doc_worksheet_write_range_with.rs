@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a multiplication table to a
+//! worksheet using a closure.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write the row and column headers.
+    worksheet.write_row(0, 1, 1..=10)?;
+    worksheet.write_column(1, 0, 1..=10)?;
+
+    // Write the table body.
+    worksheet.write_range_with(1, 1, 10, 10, |row, col| row * u32::from(col))?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
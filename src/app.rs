@@ -42,6 +42,19 @@ impl App {
         self.table_parts.push(part_name.to_string());
     }
 
+    // Add the user defined heading pairs, and their part names, from the
+    // document properties.
+    pub(crate) fn add_property_heading_pairs(&mut self) {
+        for (heading, titles) in &self.properties.heading_pairs {
+            self.heading_pairs
+                .push((heading.to_string(), titles.len() as u16));
+
+            for title in titles {
+                self.table_parts.push(title.to_string());
+            }
+        }
+    }
+
     // -----------------------------------------------------------------------
     // XML assembly methods.
     // -----------------------------------------------------------------------
@@ -108,7 +121,7 @@ impl App {
     // Write the <Application> element.
     fn write_application(&mut self) {
         self.writer
-            .xml_data_element_only("Application", "Microsoft Excel");
+            .xml_data_element_only("Application", &self.properties.application);
     }
 
     // Write the <DocSecurity> element.
@@ -119,7 +132,13 @@ impl App {
 
     // Write the <ScaleCrop> element.
     fn write_scale_crop(&mut self) {
-        self.writer.xml_data_element_only("ScaleCrop", "false");
+        let scale_crop = if self.properties.scale_crop {
+            "true"
+        } else {
+            "false"
+        };
+
+        self.writer.xml_data_element_only("ScaleCrop", scale_crop);
     }
 
     // Write the <HeadingPairs> element.
@@ -204,7 +223,14 @@ impl App {
 
     // Write the <LinksUpToDate> element.
     fn write_links_up_to_date(&mut self) {
-        self.writer.xml_data_element_only("LinksUpToDate", "false");
+        let links_up_to_date = if self.properties.links_up_to_date {
+            "true"
+        } else {
+            "false"
+        };
+
+        self.writer
+            .xml_data_element_only("LinksUpToDate", links_up_to_date);
     }
 
     // Write the <SharedDoc> element.
@@ -228,7 +254,8 @@ impl App {
 
     // Write the <AppVersion> element.
     fn write_app_version(&mut self) {
-        self.writer.xml_data_element_only("AppVersion", "12.0000");
+        self.writer
+            .xml_data_element_only("AppVersion", &self.properties.app_version);
     }
 }
 
@@ -240,6 +267,7 @@ mod tests {
 
     use crate::app::App;
     use crate::test_functions::xml_to_vec;
+    use crate::DocProperties;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -393,4 +421,161 @@ mod tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble4() {
+        let mut app = App::new();
+
+        app.properties = DocProperties::new()
+            .set_scale_crop(true)
+            .set_links_up_to_date(true);
+        app.add_heading_pair("Worksheets", 1);
+        app.add_part_name("Sheet1");
+
+        app.assemble_xml_file();
+
+        let got = app.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+                    <Application>Microsoft Excel</Application>
+                    <DocSecurity>0</DocSecurity>
+                    <ScaleCrop>true</ScaleCrop>
+                    <HeadingPairs>
+                        <vt:vector size="2" baseType="variant">
+                        <vt:variant>
+                            <vt:lpstr>Worksheets</vt:lpstr>
+                        </vt:variant>
+                        <vt:variant>
+                            <vt:i4>1</vt:i4>
+                        </vt:variant>
+                        </vt:vector>
+                    </HeadingPairs>
+                    <TitlesOfParts>
+                        <vt:vector size="1" baseType="lpstr">
+                        <vt:lpstr>Sheet1</vt:lpstr>
+                        </vt:vector>
+                    </TitlesOfParts>
+                    <Company>
+                    </Company>
+                    <LinksUpToDate>true</LinksUpToDate>
+                    <SharedDoc>false</SharedDoc>
+                    <HyperlinksChanged>false</HyperlinksChanged>
+                    <AppVersion>12.0000</AppVersion>
+                </Properties>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble5() {
+        let mut app = App::new();
+
+        app.properties = DocProperties::new()
+            .set_application("Report Service")
+            .set_app_version("2.0100");
+        app.add_heading_pair("Worksheets", 1);
+        app.add_part_name("Sheet1");
+
+        app.assemble_xml_file();
+
+        let got = app.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+                    <Application>Report Service</Application>
+                    <DocSecurity>0</DocSecurity>
+                    <ScaleCrop>false</ScaleCrop>
+                    <HeadingPairs>
+                        <vt:vector size="2" baseType="variant">
+                        <vt:variant>
+                            <vt:lpstr>Worksheets</vt:lpstr>
+                        </vt:variant>
+                        <vt:variant>
+                            <vt:i4>1</vt:i4>
+                        </vt:variant>
+                        </vt:vector>
+                    </HeadingPairs>
+                    <TitlesOfParts>
+                        <vt:vector size="1" baseType="lpstr">
+                        <vt:lpstr>Sheet1</vt:lpstr>
+                        </vt:vector>
+                    </TitlesOfParts>
+                    <Company>
+                    </Company>
+                    <LinksUpToDate>false</LinksUpToDate>
+                    <SharedDoc>false</SharedDoc>
+                    <HyperlinksChanged>false</HyperlinksChanged>
+                    <AppVersion>2.0100</AppVersion>
+                </Properties>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble6() {
+        let mut app = App::new();
+
+        app.properties = DocProperties::new().set_heading_pair("Reports", &["Q1", "Q2"]);
+        app.add_heading_pair("Worksheets", 1);
+        app.add_part_name("Sheet1");
+        app.add_property_heading_pairs();
+
+        app.assemble_xml_file();
+
+        let got = app.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+                    <Application>Microsoft Excel</Application>
+                    <DocSecurity>0</DocSecurity>
+                    <ScaleCrop>false</ScaleCrop>
+                    <HeadingPairs>
+                        <vt:vector size="4" baseType="variant">
+                        <vt:variant>
+                            <vt:lpstr>Worksheets</vt:lpstr>
+                        </vt:variant>
+                        <vt:variant>
+                            <vt:i4>1</vt:i4>
+                        </vt:variant>
+                        <vt:variant>
+                            <vt:lpstr>Reports</vt:lpstr>
+                        </vt:variant>
+                        <vt:variant>
+                            <vt:i4>2</vt:i4>
+                        </vt:variant>
+                        </vt:vector>
+                    </HeadingPairs>
+                    <TitlesOfParts>
+                        <vt:vector size="3" baseType="lpstr">
+                        <vt:lpstr>Sheet1</vt:lpstr>
+                        <vt:lpstr>Q1</vt:lpstr>
+                        <vt:lpstr>Q2</vt:lpstr>
+                        </vt:vector>
+                    </TitlesOfParts>
+                    <Company>
+                    </Company>
+                    <LinksUpToDate>false</LinksUpToDate>
+                    <SharedDoc>false</SharedDoc>
+                    <HyperlinksChanged>false</HyperlinksChanged>
+                    <AppVersion>12.0000</AppVersion>
+                </Properties>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
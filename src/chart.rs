@@ -140,6 +140,15 @@ pub struct Chart {
     style: u8,
     hole_size: u8,
     rotation: u16,
+    drop_lines: bool,
+    drop_lines_line: Option<ChartLine>,
+    high_low_lines: bool,
+    high_low_lines_line: Option<ChartLine>,
+    rotation_3d: Option<(i16, u16)>,
+    perspective_3d: Option<u8>,
+    depth_3d: u16,
+    show_blanks_as: ChartEmptyCells,
+    show_hidden_data: bool,
 }
 
 impl Chart {
@@ -233,6 +242,15 @@ impl Chart {
             style: 2,
             hole_size: 50,
             rotation: 0,
+            drop_lines: false,
+            drop_lines_line: None,
+            high_low_lines: false,
+            high_low_lines_line: None,
+            rotation_3d: None,
+            perspective_3d: None,
+            depth_3d: 100,
+            show_blanks_as: ChartEmptyCells::Gap,
+            show_hidden_data: false,
             default_label_position: ChartDataLabelPosition::Default,
         };
 
@@ -241,21 +259,28 @@ impl Chart {
                 Self::initialize_area_chart(chart)
             }
 
-            ChartType::Bar | ChartType::BarStacked | ChartType::BarPercentStacked => {
-                Self::initialize_bar_chart(chart)
-            }
+            ChartType::Bar
+            | ChartType::BarStacked
+            | ChartType::BarPercentStacked
+            | ChartType::Bar3D
+            | ChartType::Bar3DStacked
+            | ChartType::Bar3DPercentStacked => Self::initialize_bar_chart(chart),
 
-            ChartType::Column | ChartType::ColumnStacked | ChartType::ColumnPercentStacked => {
-                Self::initialize_column_chart(chart)
-            }
+            ChartType::Column
+            | ChartType::ColumnStacked
+            | ChartType::ColumnPercentStacked
+            | ChartType::Column3D
+            | ChartType::Column3DStacked
+            | ChartType::Column3DPercentStacked => Self::initialize_column_chart(chart),
 
             ChartType::Doughnut => Self::initialize_doughnut_chart(chart),
 
-            ChartType::Line | ChartType::LineStacked | ChartType::LinePercentStacked => {
-                Self::initialize_line_chart(chart)
-            }
+            ChartType::Line
+            | ChartType::LineStacked
+            | ChartType::LinePercentStacked
+            | ChartType::Line3D => Self::initialize_line_chart(chart),
 
-            ChartType::Pie => Self::initialize_pie_chart(chart),
+            ChartType::Pie | ChartType::Pie3D => Self::initialize_pie_chart(chart),
 
             ChartType::Radar | ChartType::RadarWithMarkers | ChartType::RadarFilled => {
                 Self::initialize_radar_chart(chart)
@@ -424,6 +449,100 @@ impl Chart {
         self
     }
 
+    /// Get a chart series by index in order to change its properties.
+    ///
+    /// Get a reference to a chart series that has already been added to the
+    /// chart. This is mainly useful for using a chart as a template: a fully
+    /// formatted chart can be cloned with [`Chart::clone()`] and then the data
+    /// ranges of the series can be changed for each copy.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero indexed series number, in the order that the
+    ///   series were added to the chart.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ChartError`] - The series index doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// An example of using a formatted chart as a template for several charts
+    /// with different data ranges.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_template.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the charts.
+    /// #     let data = [[10, 40, 50], [20, 10, 50], [30, 60, 20]];
+    /// #     for (col_num, col_data) in data.iter().enumerate() {
+    /// #         worksheet.write_column(0, col_num as u16, *col_data)?;
+    /// #     }
+    /// #
+    ///     // Create a formatted chart to use as a template.
+    ///     let mut template = Chart::new(ChartType::Column);
+    ///     template.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///     template.set_style(26);
+    ///     template.legend().set_hidden();
+    ///     template.y_axis().set_max(100);
+    ///
+    ///     // Create a chart for each column of data from the template.
+    ///     for col_num in 0..3 {
+    ///         let mut chart = template.clone();
+    ///         chart
+    ///             .series(0)?
+    ///             .set_values(("Sheet1", 0, col_num, 2, col_num));
+    ///
+    ///         worksheet.insert_chart(u32::from(col_num) * 16, 4, &chart)?;
+    ///     }
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn series(&mut self, index: usize) -> Result<&mut ChartSeries, XlsxError> {
+        let num_series = self.series.len();
+
+        match self.series.get_mut(index) {
+            Some(series) => Ok(series),
+            None => Err(XlsxError::ChartError(format!(
+                "Chart series index '{index}' is out of range. The chart has {num_series} series"
+            ))),
+        }
+    }
+
+    /// Remove a chart series by index.
+    ///
+    /// Remove a series that has already been added to the chart. The indices
+    /// of any following series are reduced by one. This can be used, for
+    /// example, to drop series without data from a chart that is built in a
+    /// loop.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero indexed series number, in the order that the
+    ///   series were added to the chart.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ChartError`] - The series index doesn't exist.
+    ///
+    pub fn remove_series(&mut self, index: usize) -> Result<&mut Chart, XlsxError> {
+        self.series(index)?;
+        self.series.remove(index);
+
+        Ok(self)
+    }
+
     /// Get the chart title object in order to set its properties.
     ///
     /// Get a reference to the chart's X-Axis [`ChartTitle`] object in order to
@@ -841,7 +960,7 @@ impl Chart {
     /// # Arguments
     ///
     /// * `rotation`: The rotation of the first segment of a Pie/Doughnut chart.
-    /// The range is 0 <= rotation <= 360 and the default is 0.
+    ///   The range is 0 <= rotation <= 360 and the default is 0.
     ///
     ///
     /// # Examples
@@ -899,7 +1018,7 @@ impl Chart {
     /// # Arguments
     ///
     /// * `hole_size`: The hole size for a Doughnut chart. The range is 0 <=
-    /// `hole_size` <= 90 and the default is 50.
+    ///   `hole_size` <= 90 and the default is 50.
     ///
     ///
     /// # Examples
@@ -950,6 +1069,283 @@ impl Chart {
         self
     }
 
+    /// Set the X and Y rotation of a 3-D chart.
+    ///
+    /// The `set_3d_rotation()` method is used to set the rotation of the 3-D
+    /// chart types such as [`ChartType::Column3D`] and [`ChartType::Pie3D`].
+    /// It has no effect on 2-D charts.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_rotation`: The rotation around the X axis, i.e. the elevation of
+    ///   the view. The range is -90 <= x_rotation <= 90 and the default is 15,
+    ///   or 30 for 3-D Pie charts.
+    /// * `y_rotation`: The rotation around the Y axis. The range is 0 <=
+    ///   y_rotation <= 360 and the default is 20, or 0 for 3-D Pie charts.
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating setting the rotation, perspective and
+    /// depth of a 3-D chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_set_3d_rotation.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 50)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(2, 0, 40)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column3D);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///
+    ///     // Set the 3-D view of the chart.
+    ///     chart.set_3d_rotation(30, 40);
+    ///     chart.set_3d_perspective(15);
+    ///     chart.set_3d_depth(200);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_3d_rotation(&mut self, x_rotation: i16, y_rotation: u16) -> &mut Chart {
+        if (-90..=90).contains(&x_rotation) && (0..=360).contains(&y_rotation) {
+            self.rotation_3d = Some((x_rotation, y_rotation));
+        }
+        self
+    }
+
+    /// Set the perspective of a 3-D chart.
+    ///
+    /// Set the perspective, or field of view, of a 3-D chart. Setting a
+    /// perspective turns off the "Right angle axes" option in Excel. The
+    /// perspective isn't supported for 3-D Pie charts.
+    ///
+    /// # Arguments
+    ///
+    /// * `perspective`: The field of view in degrees. The range is 0 <=
+    ///   perspective <= 100. The default is 15 degrees for 3-D Line charts.
+    ///   Other 3-D charts default to right angle axes with no perspective.
+    ///
+    pub fn set_3d_perspective(&mut self, perspective: u8) -> &mut Chart {
+        if (0..=100).contains(&perspective) {
+            self.perspective_3d = Some(perspective);
+        }
+        self
+    }
+
+    /// Set the depth of a 3-D chart.
+    ///
+    /// Set the depth of a 3-D chart as a percentage of the chart base width.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth`: The depth as a percentage. The range is 20 <= depth <= 2000
+    ///   and the default is 100.
+    ///
+    pub fn set_3d_depth(&mut self, depth: u16) -> &mut Chart {
+        if (20..=2000).contains(&depth) {
+            self.depth_3d = depth;
+        }
+        self
+    }
+
+    /// Set the option for displaying empty cells in a chart.
+    ///
+    /// Excel allows you to display empty or blank cells in a chart range as
+    /// gaps, as zeros or as a line that spans the gap between the adjacent
+    /// data points. This is useful when charting sparse ranges.
+    ///
+    /// # Arguments
+    ///
+    /// * `option` - A [`ChartEmptyCells`] enum value. The default is
+    ///   [`ChartEmptyCells::Gap`].
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating displaying empty cells in a chart as a
+    /// line that spans the gap and showing data from hidden rows.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_show_blanks_as.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartEmptyCells, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart, with a blank cell.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(3, 0, 20)?;
+    /// #     worksheet.write(4, 0, 30)?;
+    /// #
+    /// #     // Hide one of the data rows.
+    /// #     worksheet.set_row_hidden(4)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Line);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///
+    ///     // Span the gap caused by the blank cell and show the hidden data.
+    ///     chart.show_blanks_as(ChartEmptyCells::Span);
+    ///     chart.show_hidden_data(true);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn show_blanks_as(&mut self, option: ChartEmptyCells) -> &mut Chart {
+        self.show_blanks_as = option;
+        self
+    }
+
+    /// Display data from hidden rows or columns in a chart.
+    ///
+    /// By default Excel only plots the data in visible rows and columns. This
+    /// means that data in rows hidden by an autofilter, for example, won't be
+    /// displayed in the chart. This option allows the hidden data to be
+    /// plotted. See [`show_blanks_as()`](Chart::show_blanks_as) for an
+    /// example.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn show_hidden_data(&mut self, enable: bool) -> &mut Chart {
+        self.show_hidden_data = enable;
+        self
+    }
+
+    /// Turn on/off drop lines for a Line or Area chart.
+    ///
+    /// Drop lines are vertical lines that extend from each data point down to
+    /// the category axis. They are only supported by Line and Area charts and
+    /// are ignored for other chart types.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// An example of adding drop lines and high-low lines to a line chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_set_drop_lines.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartLine, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(2, 0, 50)?;
+    /// #     worksheet.write(0, 1, 20)?;
+    /// #     worksheet.write(1, 1, 25)?;
+    /// #     worksheet.write(2, 1, 30)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Line);
+    ///
+    ///     // Add the data series.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///     chart.add_series().set_values("Sheet1!$B$1:$B$3");
+    ///
+    ///     // Add drop lines and formatted high-low lines.
+    ///     chart.set_drop_lines(true);
+    ///     chart.set_high_low_lines_line(ChartLine::new().set_color("#FF0000"));
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 3, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_drop_lines(&mut self, enable: bool) -> &mut Chart {
+        self.drop_lines = enable;
+        self
+    }
+
+    /// Set the line formatting for the chart drop lines.
+    ///
+    /// This also turns on the drop lines, see
+    /// [`set_drop_lines()`](Chart::set_drop_lines). See the [`ChartLine`]
+    /// struct for details on the line properties that can be set.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A [`ChartLine`] struct reference.
+    ///
+    pub fn set_drop_lines_line(&mut self, line: &ChartLine) -> &mut Chart {
+        self.drop_lines_line = Some(line.clone());
+        self.drop_lines = true;
+        self
+    }
+
+    /// Turn on/off high-low lines for a Line chart.
+    ///
+    /// High-low lines are vertical lines that extend from the highest to the
+    /// lowest value at each category across all the series in the chart.
+    /// They are only supported by Line charts and are ignored for other chart
+    /// types. See [`set_drop_lines()`](Chart::set_drop_lines) for an example.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_high_low_lines(&mut self, enable: bool) -> &mut Chart {
+        self.high_low_lines = enable;
+        self
+    }
+
+    /// Set the line formatting for the chart high-low lines.
+    ///
+    /// This also turns on the high-low lines, see
+    /// [`set_high_low_lines()`](Chart::set_high_low_lines). See the
+    /// [`ChartLine`] struct for details on the line properties that can be set.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - A [`ChartLine`] struct reference.
+    ///
+    pub fn set_high_low_lines_line(&mut self, line: &ChartLine) -> &mut Chart {
+        self.high_low_lines_line = Some(line.clone());
+        self.high_low_lines = true;
+        self
+    }
+
     /// Set the width of the chart.
     ///
     /// The default width of an Excel chart is 480 pixels. The `set_width()`
@@ -1131,7 +1527,7 @@ impl Chart {
     /// # Errors
     ///
     /// * [`XlsxError::ChartError`] - A general error that is raised when a
-    /// chart parameter is incorrect or a chart is configured incorrectly.
+    ///   chart parameter is incorrect or a chart is configured incorrectly.
     ///
     pub fn validate(&mut self) -> Result<&mut Chart, XlsxError> {
         // Check for chart without series.
@@ -1142,8 +1538,8 @@ impl Chart {
         }
 
         for series in &self.series {
-            // Check for a series without a values range.
-            if !series.value_range.has_data() {
+            // Check for a series without a values range or data.
+            if !series.value_range.has_data() && !series.value_cache_data.has_data() {
                 return Err(XlsxError::ChartError(
                     "Chart series must contain a values range".to_string(),
                 ));
@@ -1151,14 +1547,19 @@ impl Chart {
 
             // Check for scatter charts without category ranges. It is optional
             // for all other types.
-            if self.chart_group_type == ChartType::Scatter && !series.category_range.has_data() {
+            if self.chart_group_type == ChartType::Scatter
+                && !series.category_range.has_data()
+                && !series.category_cache_data.has_data()
+            {
                 return Err(XlsxError::ChartError(
                     "Scatter style charts must contain a categories range".to_string(),
                 ));
             }
 
             // Validate the series values range.
-            series.value_range.validate()?;
+            if series.value_range.has_data() {
+                series.value_range.validate()?;
+            }
 
             // Validate the series category range.
             if series.category_range.has_data() {
@@ -1195,6 +1596,21 @@ impl Chart {
         self.axis_ids = (axis_id_1, axis_id_2);
     }
 
+    // Check if the chart is one of the 3-D chart types.
+    fn is_3d_chart(&self) -> bool {
+        matches!(
+            self.chart_type,
+            ChartType::Bar3D
+                | ChartType::Bar3DStacked
+                | ChartType::Bar3DPercentStacked
+                | ChartType::Column3D
+                | ChartType::Column3DStacked
+                | ChartType::Column3DPercentStacked
+                | ChartType::Line3D
+                | ChartType::Pie3D
+        )
+    }
+
     // -----------------------------------------------------------------------
     // Chart specific methods.
     // -----------------------------------------------------------------------
@@ -1239,13 +1655,17 @@ impl Chart {
 
         self.chart_group_type = ChartType::Bar;
 
-        if self.chart_type == ChartType::Bar {
+        if self.chart_type == ChartType::Bar || self.chart_type == ChartType::Bar3D {
             self.grouping = ChartGrouping::Clustered;
-        } else if self.chart_type == ChartType::BarStacked {
+        } else if self.chart_type == ChartType::BarStacked
+            || self.chart_type == ChartType::Bar3DStacked
+        {
             self.grouping = ChartGrouping::Stacked;
             self.has_overlap = true;
             self.overlap = 100;
-        } else if self.chart_type == ChartType::BarPercentStacked {
+        } else if self.chart_type == ChartType::BarPercentStacked
+            || self.chart_type == ChartType::Bar3DPercentStacked
+        {
             self.grouping = ChartGrouping::PercentStacked;
             self.default_num_format = "0%".to_string();
             self.has_overlap = true;
@@ -1268,13 +1688,17 @@ impl Chart {
 
         self.chart_group_type = ChartType::Column;
 
-        if self.chart_type == ChartType::Column {
+        if self.chart_type == ChartType::Column || self.chart_type == ChartType::Column3D {
             self.grouping = ChartGrouping::Clustered;
-        } else if self.chart_type == ChartType::ColumnStacked {
+        } else if self.chart_type == ChartType::ColumnStacked
+            || self.chart_type == ChartType::Column3DStacked
+        {
             self.grouping = ChartGrouping::Stacked;
             self.has_overlap = true;
             self.overlap = 100;
-        } else if self.chart_type == ChartType::ColumnPercentStacked {
+        } else if self.chart_type == ChartType::ColumnPercentStacked
+            || self.chart_type == ChartType::Column3DPercentStacked
+        {
             self.grouping = ChartGrouping::PercentStacked;
             self.default_num_format = "0%".to_string();
             self.has_overlap = true;
@@ -1307,7 +1731,7 @@ impl Chart {
 
         self.chart_group_type = ChartType::Line;
 
-        if self.chart_type == ChartType::Line {
+        if self.chart_type == ChartType::Line || self.chart_type == ChartType::Line3D {
             self.grouping = ChartGrouping::Standard;
         } else if self.chart_type == ChartType::LineStacked {
             self.grouping = ChartGrouping::Stacked;
@@ -1377,6 +1801,9 @@ impl Chart {
         // Write the c:ser elements.
         self.write_series();
 
+        // Write the c:dropLines element.
+        self.write_drop_lines();
+
         // Write the c:axId elements.
         self.write_ax_ids();
 
@@ -1412,12 +1839,12 @@ impl Chart {
         self.writer.xml_end_tag("c:barChart");
     }
 
-    // Write the <c:barChart> element for Column charts.
-    fn write_column_chart(&mut self) {
-        self.writer.xml_start_tag_only("c:barChart");
+    // Write the <c:bar3DChart> element for 3-D Bar and Column charts.
+    fn write_bar_3d_chart(&mut self, direction: &str) {
+        self.writer.xml_start_tag_only("c:bar3DChart");
 
         // Write the c:barDir element.
-        self.write_bar_dir("col");
+        self.write_bar_dir(direction);
 
         // Write the c:grouping element.
         self.write_grouping();
@@ -1430,20 +1857,45 @@ impl Chart {
             self.write_gap_width(self.gap);
         }
 
-        if self.overlap != 0 {
-            // Write the c:overlap element.
-            self.write_overlap();
-        }
-
         // Write the c:axId elements.
         self.write_ax_ids();
+        self.write_ax_id(0);
 
-        self.writer.xml_end_tag("c:barChart");
+        self.writer.xml_end_tag("c:bar3DChart");
     }
 
-    // Write the <c:doughnutChart> element for Column charts.
-    fn write_doughnut_chart(&mut self) {
-        self.writer.xml_start_tag_only("c:doughnutChart");
+    // Write the <c:barChart> element for Column charts.
+    fn write_column_chart(&mut self) {
+        self.writer.xml_start_tag_only("c:barChart");
+
+        // Write the c:barDir element.
+        self.write_bar_dir("col");
+
+        // Write the c:grouping element.
+        self.write_grouping();
+
+        // Write the c:ser elements.
+        self.write_series();
+
+        if self.gap != 150 {
+            // Write the c:gapWidth element.
+            self.write_gap_width(self.gap);
+        }
+
+        if self.overlap != 0 {
+            // Write the c:overlap element.
+            self.write_overlap();
+        }
+
+        // Write the c:axId elements.
+        self.write_ax_ids();
+
+        self.writer.xml_end_tag("c:barChart");
+    }
+
+    // Write the <c:doughnutChart> element for Column charts.
+    fn write_doughnut_chart(&mut self) {
+        self.writer.xml_start_tag_only("c:doughnutChart");
 
         // Write the c:varyColors element.
         self.write_vary_colors();
@@ -1470,6 +1922,12 @@ impl Chart {
         // Write the c:ser elements.
         self.write_series();
 
+        // Write the c:dropLines element.
+        self.write_drop_lines();
+
+        // Write the c:hiLowLines element.
+        self.write_high_low_lines();
+
         // Write the c:marker element.
         self.write_marker_value();
 
@@ -1479,6 +1937,40 @@ impl Chart {
         self.writer.xml_end_tag("c:lineChart");
     }
 
+    // Write the <c:line3DChart> element.
+    fn write_line_3d_chart(&mut self) {
+        self.writer.xml_start_tag_only("c:line3DChart");
+
+        // Write the c:grouping element.
+        self.write_grouping();
+
+        // Write the c:ser elements.
+        self.write_series();
+
+        // Write the c:dropLines element.
+        self.write_drop_lines();
+
+        // Write the c:axId elements. 3-D line charts have a third, series,
+        // axis.
+        self.write_ax_ids();
+        self.write_ax_id(self.axis_ids.1 + 1);
+
+        self.writer.xml_end_tag("c:line3DChart");
+    }
+
+    // Write the <c:pie3DChart> element.
+    fn write_pie_3d_chart(&mut self) {
+        self.writer.xml_start_tag_only("c:pie3DChart");
+
+        // Write the c:varyColors element.
+        self.write_vary_colors();
+
+        // Write the c:ser elements.
+        self.write_series();
+
+        self.writer.xml_end_tag("c:pie3DChart");
+    }
+
     // Write the <c:pieChart> element for Column charts.
     fn write_pie_chart(&mut self) {
         self.writer.xml_start_tag_only("c:pieChart");
@@ -1597,6 +2089,11 @@ impl Chart {
             self.write_chart_title(&self.title.clone());
         }
 
+        // Write the c:view3D element.
+        if self.is_3d_chart() {
+            self.write_view_3d();
+        }
+
         // Write the c:plotArea element.
         self.write_plot_area();
 
@@ -1604,7 +2101,14 @@ impl Chart {
         self.write_legend();
 
         // Write the c:plotVisOnly element.
-        self.write_plot_vis_only();
+        if !self.show_hidden_data {
+            self.write_plot_vis_only();
+        }
+
+        // Write the c:dispBlanksAs element.
+        if self.show_blanks_as != ChartEmptyCells::Gap {
+            self.write_disp_blanks_as();
+        }
 
         self.writer.xml_end_tag("c:chart");
     }
@@ -1645,18 +2149,30 @@ impl Chart {
                 self.write_bar_chart();
             }
 
+            ChartType::Bar3D | ChartType::Bar3DStacked | ChartType::Bar3DPercentStacked => {
+                self.write_bar_3d_chart("bar");
+            }
+
             ChartType::Column | ChartType::ColumnStacked | ChartType::ColumnPercentStacked => {
                 self.write_column_chart();
             }
 
+            ChartType::Column3D
+            | ChartType::Column3DStacked
+            | ChartType::Column3DPercentStacked => self.write_bar_3d_chart("col"),
+
             ChartType::Doughnut => self.write_doughnut_chart(),
 
             ChartType::Line | ChartType::LineStacked | ChartType::LinePercentStacked => {
                 self.write_line_chart();
             }
 
+            ChartType::Line3D => self.write_line_3d_chart(),
+
             ChartType::Pie => self.write_pie_chart(),
 
+            ChartType::Pie3D => self.write_pie_3d_chart(),
+
             ChartType::Radar | ChartType::RadarWithMarkers | ChartType::RadarFilled => {
                 self.write_radar_chart();
             }
@@ -1689,6 +2205,11 @@ impl Chart {
 
                 // Write the c:valAx element.
                 self.write_val_ax();
+
+                // Write the c:serAx element.
+                if self.chart_type == ChartType::Line3D {
+                    self.write_ser_ax();
+                }
             }
         }
 
@@ -1703,6 +2224,53 @@ impl Chart {
         self.writer.xml_end_tag("c:plotArea");
     }
 
+    // Write the <c:view3D> element. The default rotation and perspective
+    // match the Excel defaults for each 3-D chart type.
+    fn write_view_3d(&mut self) {
+        let is_pie = self.chart_type == ChartType::Pie3D;
+        let is_line = self.chart_type == ChartType::Line3D;
+
+        let (rot_x, rot_y) = match self.rotation_3d {
+            Some(rotation) => rotation,
+            None if is_pie => (30, 0),
+            None => (15, 20),
+        };
+
+        let perspective = match self.perspective_3d {
+            _ if is_pie => None,
+            Some(perspective) => Some(perspective),
+            None if is_line => Some(15),
+            None => None,
+        };
+
+        self.writer.xml_start_tag_only("c:view3D");
+
+        self.writer
+            .xml_empty_tag("c:rotX", &[("val", rot_x.to_string())]);
+
+        self.writer
+            .xml_empty_tag("c:rotY", &[("val", rot_y.to_string())]);
+
+        if self.depth_3d != 100 {
+            self.writer
+                .xml_empty_tag("c:depthPercent", &[("val", self.depth_3d.to_string())]);
+        }
+
+        // Right angle axes are turned off for perspective views.
+        let right_angle_axes = !is_pie && perspective.is_none();
+        self.writer
+            .xml_empty_tag("c:rAngAx", &[("val", right_angle_axes.to_xml_bool())]);
+
+        // The perspective field of view is stored in units of half a degree.
+        if let Some(perspective) = perspective {
+            let perspective = u16::from(perspective) * 2;
+            self.writer
+                .xml_empty_tag("c:perspective", &[("val", perspective.to_string())]);
+        }
+
+        self.writer.xml_end_tag("c:view3D");
+    }
+
     // Write the <c:layout> element.
     fn write_layout(&mut self) {
         self.writer.xml_empty_tag_only("c:layout");
@@ -1740,7 +2308,7 @@ impl Chart {
     // Write the <c:ser> element.
     fn write_series(&mut self) {
         for (index, series) in self.series.clone().iter_mut().enumerate() {
-            let max_points = series.value_range.number_of_points();
+            let max_points = series.number_of_points();
 
             self.writer.xml_start_tag_only("c:ser");
 
@@ -1788,7 +2356,7 @@ impl Chart {
             }
 
             // Write the c:cat element.
-            if series.category_range.has_data() {
+            if series.category_range.has_data() || series.category_cache_data.has_data() {
                 self.category_has_num_format = true;
                 self.write_cat(&series.category_range, &series.category_cache_data);
             }
@@ -1796,11 +2364,14 @@ impl Chart {
             // Write the c:val element.
             self.write_val(&series.value_range, &series.value_cache_data);
 
-            if !series.inverted_color.is_auto_or_default() {
-                // Write the c:extLst element for the inverted fill color.
-                self.write_extension_list(series.inverted_color);
+            // Write the c:smooth element.
+            if self.chart_group_type == ChartType::Line && series.smooth == Some(true) {
+                self.write_smooth(true);
             }
 
+            // Write the c:extLst element.
+            self.write_series_extension_list(series);
+
             self.writer.xml_end_tag("c:ser");
         }
     }
@@ -1808,7 +2379,7 @@ impl Chart {
     // Write the <c:ser> element for scatter charts.
     fn write_scatter_series(&mut self) {
         for (index, series) in self.series.clone().iter_mut().enumerate() {
-            let max_points = series.value_range.number_of_points();
+            let max_points = series.number_of_points();
 
             self.writer.xml_start_tag_only("c:ser");
 
@@ -1852,13 +2423,20 @@ impl Chart {
 
             self.write_y_val(&series.value_range, &series.value_cache_data);
 
-            if self.chart_type == ChartType::ScatterSmooth
-                || self.chart_type == ChartType::ScatterSmoothWithMarkers
-            {
-                // Write the c:smooth element.
-                self.write_smooth();
+            // Write the c:smooth element. Series in the smooth scatter chart
+            // types are smoothed unless it is turned off explicitly.
+            let default_smooth = self.chart_type == ChartType::ScatterSmooth
+                || self.chart_type == ChartType::ScatterSmoothWithMarkers;
+
+            match series.smooth {
+                Some(smooth) if smooth != default_smooth => self.write_smooth(smooth),
+                _ if default_smooth => self.write_smooth(true),
+                _ => {}
             }
 
+            // Write the c:extLst element.
+            self.write_series_extension_list(series);
+
             self.writer.xml_end_tag("c:ser");
         }
     }
@@ -1867,6 +2445,8 @@ impl Chart {
     fn write_d_pt(&mut self, points: &[ChartPoint], max_points: usize) {
         let has_marker =
             self.chart_group_type == ChartType::Scatter || self.chart_group_type == ChartType::Line;
+        let has_explosion =
+            self.chart_group_type == ChartType::Pie || self.chart_group_type == ChartType::Doughnut;
 
         // Write the point formatting for the series.
         for (index, point) in points.iter().enumerate() {
@@ -1882,6 +2462,11 @@ impl Chart {
                     self.writer.xml_start_tag_only("c:marker");
                 }
 
+                // Write the c:explosion element.
+                if has_explosion && point.explosion > 0 {
+                    self.write_explosion(point.explosion);
+                }
+
                 // Write the c:spPr formatting element.
                 self.write_sp_pr(&point.format);
 
@@ -1894,6 +2479,13 @@ impl Chart {
         }
     }
 
+    // Write the <c:explosion> element.
+    fn write_explosion(&mut self, explosion: u16) {
+        let attributes = [("val", explosion.to_string())];
+
+        self.writer.xml_empty_tag("c:explosion", &attributes);
+    }
+
     // Write the <c:idx> element.
     fn write_idx(&mut self, index: usize) {
         let attributes = [("val", index.to_string())];
@@ -1916,7 +2508,37 @@ impl Chart {
     }
 
     // Write the <c:extLst> element for inverted fill colors.
-    fn write_extension_list(&mut self, color: Color) {
+    fn write_series_extension_list(&mut self, series: &ChartSeries) {
+        let has_inverted_color = !series.inverted_color.is_auto_or_default();
+        let label_range = match &series.data_label {
+            Some(data_label) if data_label.label_range.has_data() => Some(data_label),
+            _ => None,
+        };
+
+        if !has_inverted_color && label_range.is_none() {
+            return;
+        }
+
+        self.writer.xml_start_tag_only("c:extLst");
+
+        if has_inverted_color {
+            // Write the c:ext element for the inverted fill color.
+            self.write_inverted_color_extension(series.inverted_color);
+        }
+
+        if let Some(data_label) = label_range {
+            // Write the c:ext element for the data label range.
+            self.write_data_labels_range_extension(
+                &data_label.label_range,
+                &data_label.label_cache_data,
+            );
+        }
+
+        self.writer.xml_end_tag("c:extLst");
+    }
+
+    // Write the <c:ext> element for the inverted fill color.
+    fn write_inverted_color_extension(&mut self, color: Color) {
         let attributes1 = [
             ("uri", "{6F2FDCE9-48DA-4B69-8628-5D25D57E5C99}"),
             (
@@ -1929,7 +2551,6 @@ impl Chart {
             "http://schemas.microsoft.com/office/drawing/2007/8/2/chart",
         )];
 
-        self.writer.xml_start_tag_only("c:extLst");
         self.writer.xml_start_tag("c:ext", &attributes1);
         self.writer.xml_start_tag_only("c14:invertSolidFillFmt");
         self.writer.xml_start_tag("c14:spPr", &attributes2);
@@ -1939,7 +2560,45 @@ impl Chart {
         self.writer.xml_end_tag("c14:spPr");
         self.writer.xml_end_tag("c14:invertSolidFillFmt");
         self.writer.xml_end_tag("c:ext");
-        self.writer.xml_end_tag("c:extLst");
+    }
+
+    // Write the <c:ext> element for the data label range.
+    fn write_data_labels_range_extension(
+        &mut self,
+        range: &ChartRange,
+        cache: &ChartSeriesCacheData,
+    ) {
+        let attributes = [
+            ("uri", "{02D57815-91ED-43cb-92C2-25804820EDAC}"),
+            (
+                "xmlns:c15",
+                "http://schemas.microsoft.com/office/drawing/2012/chart",
+            ),
+        ];
+
+        self.writer.xml_start_tag("c:ext", &attributes);
+        self.writer.xml_start_tag_only("c15:datalabelsRange");
+
+        // Write the c15:f element.
+        self.writer.xml_data_element_only("c15:f", &range.formula());
+
+        // Write the c15:dlblRangeCache element.
+        if cache.has_data() {
+            self.writer.xml_start_tag_only("c15:dlblRangeCache");
+
+            // Write the c:ptCount element.
+            self.write_pt_count(cache.data.len());
+
+            // Write the c:pt elements.
+            for (index, value) in cache.data.iter().enumerate() {
+                self.write_pt(index, value);
+            }
+
+            self.writer.xml_end_tag("c15:dlblRangeCache");
+        }
+
+        self.writer.xml_end_tag("c15:datalabelsRange");
+        self.writer.xml_end_tag("c:ext");
     }
 
     // Write the <c:cat> element.
@@ -1978,9 +2637,16 @@ impl Chart {
         self.writer.xml_end_tag("c:yVal");
     }
 
-    // Write the <c:numRef> or <c:strRef> elements.
+    // Write the <c:numRef> or <c:strRef> elements, or the <c:numLit> or
+    // <c:strLit> elements for literal data that isn't in a worksheet range.
     fn write_cache_ref(&mut self, range: &ChartRange, cache: &ChartSeriesCacheData) {
-        if cache.is_numeric {
+        if !range.has_data() {
+            if cache.is_numeric {
+                self.write_num_data("c:numLit", cache);
+            } else {
+                self.write_str_data("c:strLit", cache);
+            }
+        } else if cache.is_numeric {
             self.write_num_ref(range, cache);
         } else {
             self.write_str_ref(range, cache);
@@ -1996,7 +2662,7 @@ impl Chart {
 
         // Write the c:numCache element.
         if cache.has_data() {
-            self.write_num_cache(cache);
+            self.write_num_data("c:numCache", cache);
         }
 
         self.writer.xml_end_tag("c:numRef");
@@ -2011,15 +2677,15 @@ impl Chart {
 
         // Write the c:strCache element.
         if cache.has_data() {
-            self.write_str_cache(cache);
+            self.write_str_data("c:strCache", cache);
         }
 
         self.writer.xml_end_tag("c:strRef");
     }
 
-    // Write the <c:numCache> element.
-    fn write_num_cache(&mut self, cache: &ChartSeriesCacheData) {
-        self.writer.xml_start_tag_only("c:numCache");
+    // Write the <c:numCache> or <c:numLit> element.
+    fn write_num_data(&mut self, tag: &str, cache: &ChartSeriesCacheData) {
+        self.writer.xml_start_tag_only(tag);
 
         // Write the c:formatCode element.
         self.write_format_code();
@@ -2034,12 +2700,12 @@ impl Chart {
             }
         }
 
-        self.writer.xml_end_tag("c:numCache");
+        self.writer.xml_end_tag(tag);
     }
 
-    // Write the <c:strCache> element.
-    fn write_str_cache(&mut self, cache: &ChartSeriesCacheData) {
-        self.writer.xml_start_tag_only("c:strCache");
+    // Write the <c:strCache> or <c:strLit> element.
+    fn write_str_data(&mut self, tag: &str, cache: &ChartSeriesCacheData) {
+        self.writer.xml_start_tag_only(tag);
 
         // Write the c:ptCount element.
         self.write_pt_count(cache.data.len());
@@ -2049,7 +2715,7 @@ impl Chart {
             self.write_pt(index, value);
         }
 
-        self.writer.xml_end_tag("c:strCache");
+        self.writer.xml_end_tag(tag);
     }
 
     // Write the <c:f> element.
@@ -2146,8 +2812,9 @@ impl Chart {
         // Write the c:crossAx element.
         self.write_cross_ax(self.axis_ids.1);
 
-        // Write the c:crosses element.
-        self.write_crosses();
+        // Write the c:crosses or c:crossesAt element. Note, the crossing of
+        // the category axis is set on the value axis.
+        self.write_crossing(self.y_axis.crossing);
 
         // Write the c:auto element.
         self.write_auto();
@@ -2226,8 +2893,8 @@ impl Chart {
         // Write the c:crossAx element.
         self.write_cross_ax(self.axis_ids.0);
 
-        // Write the c:crosses element.
-        self.write_crosses();
+        // Write the c:crosses or c:crossesAt element.
+        self.write_crossing(self.x_axis.crossing);
 
         // Write the c:crossBetween element.
         self.write_cross_between(self.x_axis.position_between_ticks);
@@ -2242,6 +2909,11 @@ impl Chart {
             self.write_minor_unit(self.y_axis.minor_unit.clone());
         }
 
+        // Write the c:dispUnits element.
+        if self.y_axis.axis_type == ChartAxisType::Value {
+            self.write_disp_units(&self.y_axis.clone());
+        }
+
         self.writer.xml_end_tag("c:valAx");
     }
 
@@ -2301,8 +2973,8 @@ impl Chart {
         // Write the c:crossAx element.
         self.write_cross_ax(self.axis_ids.1);
 
-        // Write the c:crosses element.
-        self.write_crosses();
+        // Write the c:crosses or c:crossesAt element.
+        self.write_crossing(self.y_axis.crossing);
 
         // Write the c:crossBetween element.
         self.write_cross_between(self.y_axis.position_between_ticks);
@@ -2317,9 +2989,40 @@ impl Chart {
             self.write_minor_unit(self.x_axis.minor_unit.clone());
         }
 
+        // Write the c:dispUnits element.
+        if self.x_axis.axis_type == ChartAxisType::Value {
+            self.write_disp_units(&self.x_axis.clone());
+        }
+
         self.writer.xml_end_tag("c:valAx");
     }
 
+    // Write the <c:serAx> element for 3-D Line charts.
+    fn write_ser_ax(&mut self) {
+        self.writer.xml_start_tag_only("c:serAx");
+
+        self.write_ax_id(self.axis_ids.1 + 1);
+
+        // Write the c:scaling element.
+        self.writer.xml_start_tag_only("c:scaling");
+        self.write_orientation(false);
+        self.writer.xml_end_tag("c:scaling");
+
+        // Write the c:axPos element.
+        self.write_ax_pos(ChartAxisPosition::Bottom, false);
+
+        // Write the c:tickLblPos element.
+        self.write_tick_label_position(ChartAxisLabelPosition::NextTo);
+
+        // Write the c:crossAx element.
+        self.write_cross_ax(self.axis_ids.1);
+
+        // Write the c:crosses element.
+        self.write_crosses(ChartAxisCrossing::Automatic);
+
+        self.writer.xml_end_tag("c:serAx");
+    }
+
     // Write the <c:scaling> element.
     fn write_scaling(&mut self, axis: &ChartAxis) {
         self.writer.xml_start_tag_only("c:scaling");
@@ -2418,6 +3121,46 @@ impl Chart {
         }
     }
 
+    // Write the <c:dropLines> element.
+    fn write_drop_lines(&mut self) {
+        if !self.drop_lines {
+            return;
+        }
+
+        if let Some(line) = &self.drop_lines_line.clone() {
+            self.writer.xml_start_tag_only("c:dropLines");
+            self.writer.xml_start_tag_only("c:spPr");
+
+            // Write the a:ln element.
+            self.write_a_ln(line);
+
+            self.writer.xml_end_tag("c:spPr");
+            self.writer.xml_end_tag("c:dropLines");
+        } else {
+            self.writer.xml_empty_tag_only("c:dropLines");
+        }
+    }
+
+    // Write the <c:hiLowLines> element.
+    fn write_high_low_lines(&mut self) {
+        if !self.high_low_lines {
+            return;
+        }
+
+        if let Some(line) = &self.high_low_lines_line.clone() {
+            self.writer.xml_start_tag_only("c:hiLowLines");
+            self.writer.xml_start_tag_only("c:spPr");
+
+            // Write the a:ln element.
+            self.write_a_ln(line);
+
+            self.writer.xml_end_tag("c:spPr");
+            self.writer.xml_end_tag("c:hiLowLines");
+        } else {
+            self.writer.xml_empty_tag_only("c:hiLowLines");
+        }
+    }
+
     // Write the <c:minorGridlines> element.
     fn write_minor_gridlines(&mut self, axis: ChartAxis) {
         if axis.minor_gridlines {
@@ -2450,13 +3193,28 @@ impl Chart {
         self.writer.xml_empty_tag("c:crossAx", &attributes);
     }
 
+    // Write the <c:crosses> or <c:crossesAt> element.
+    fn write_crossing(&mut self, crossing: ChartAxisCrossing) {
+        match crossing {
+            ChartAxisCrossing::AxisValue(value) => self.write_crosses_at(value),
+            _ => self.write_crosses(crossing),
+        }
+    }
+
     // Write the <c:crosses> element.
-    fn write_crosses(&mut self) {
-        let attributes = [("val", "autoZero")];
+    fn write_crosses(&mut self, crossing: ChartAxisCrossing) {
+        let attributes = [("val", crossing.to_string())];
 
         self.writer.xml_empty_tag("c:crosses", &attributes);
     }
 
+    // Write the <c:crossesAt> element.
+    fn write_crosses_at(&mut self, value: f64) {
+        let attributes = [("val", value.to_string())];
+
+        self.writer.xml_empty_tag("c:crossesAt", &attributes);
+    }
+
     // Write the <c:auto> element.
     fn write_auto(&mut self) {
         let attributes = [("val", "1")];
@@ -2517,6 +3275,27 @@ impl Chart {
         self.writer.xml_empty_tag("c:minorUnit", &attributes);
     }
 
+    // Write the <c:dispUnits> element.
+    fn write_disp_units(&mut self, axis: &ChartAxis) {
+        if axis.display_units == ChartAxisDisplayUnitType::None {
+            return;
+        }
+
+        let attributes = [("val", axis.display_units.to_string())];
+
+        self.writer.xml_start_tag_only("c:dispUnits");
+        self.writer.xml_empty_tag("c:builtInUnit", &attributes);
+
+        // Write the c:dispUnitsLbl element.
+        if axis.display_units_visible {
+            self.writer.xml_start_tag_only("c:dispUnitsLbl");
+            self.writer.xml_empty_tag_only("c:layout");
+            self.writer.xml_end_tag("c:dispUnitsLbl");
+        }
+
+        self.writer.xml_end_tag("c:dispUnits");
+    }
+
     // Write the <c:legend> element.
     fn write_legend(&mut self) {
         if self.legend.hidden {
@@ -2528,6 +3307,11 @@ impl Chart {
         // Write the c:legendPos element.
         self.write_legend_pos();
 
+        // Write the c:legendEntry elements.
+        for index in self.legend.deleted_entries.clone() {
+            self.write_legend_entry(index);
+        }
+
         // Write the c:layout element.
         self.write_layout();
 
@@ -2535,9 +3319,11 @@ impl Chart {
         self.write_sp_pr(&self.legend.format.clone());
 
         // Write the c:overlay element.
-        self.write_overlay();
+        if self.legend.has_overlay {
+            self.write_overlay();
+        }
 
-        if self.chart_type == ChartType::Pie || self.chart_type == ChartType::Doughnut {
+        if self.chart_group_type == ChartType::Pie || self.chart_group_type == ChartType::Doughnut {
             let font = match &self.legend.font {
                 Some(font) => font.clone(),
                 None => ChartFont::default(),
@@ -2560,12 +3346,21 @@ impl Chart {
         self.writer.xml_empty_tag("c:legendPos", &attributes);
     }
 
+    // Write the <c:legendEntry> element for a deleted legend entry.
+    fn write_legend_entry(&mut self, index: usize) {
+        self.writer.xml_start_tag_only("c:legendEntry");
+
+        // Write the c:idx element.
+        self.write_idx(index);
+
+        // Write the c:delete element.
+        self.write_delete();
+
+        self.writer.xml_end_tag("c:legendEntry");
+    }
+
     // Write the <c:overlay> element.
     fn write_overlay(&mut self) {
-        if !self.legend.has_overlay {
-            return;
-        }
-
         let attributes = [("val", "1")];
 
         self.writer.xml_empty_tag("c:overlay", &attributes);
@@ -2578,6 +3373,13 @@ impl Chart {
         self.writer.xml_empty_tag("c:plotVisOnly", &attributes);
     }
 
+    // Write the <c:dispBlanksAs> element.
+    fn write_disp_blanks_as(&mut self) {
+        let attributes = [("val", self.show_blanks_as.to_string())];
+
+        self.writer.xml_empty_tag("c:dispBlanksAs", &attributes);
+    }
+
     // Write the <c:printSettings> element.
     fn write_print_settings(&mut self) {
         self.writer.xml_start_tag_only("c:printSettings");
@@ -2661,9 +3463,32 @@ impl Chart {
         // Write the main elements of a data label.
         self.write_data_label(data_label);
 
+        // Write the c:extLst element for the "Value From Cells" option.
+        if data_label.label_range.has_data() {
+            self.write_data_labels_extension_list();
+        }
+
         self.writer.xml_end_tag("c:dLbls");
     }
 
+    // Write the <c:extLst> element to turn on the data label range.
+    fn write_data_labels_extension_list(&mut self) {
+        let attributes = [
+            ("uri", "{CE6537A1-D6FC-4f65-9D91-7224C49458BB}"),
+            (
+                "xmlns:c15",
+                "http://schemas.microsoft.com/office/drawing/2012/chart",
+            ),
+        ];
+
+        self.writer.xml_start_tag_only("c:extLst");
+        self.writer.xml_start_tag("c:ext", &attributes);
+        self.writer
+            .xml_empty_tag("c15:showDataLabelsRange", &[("val", "1")]);
+        self.writer.xml_end_tag("c:ext");
+        self.writer.xml_end_tag("c:extLst");
+    }
+
     // Write the <c:dLbl> element.
     fn write_custom_data_labels(&mut self, data_labels: &[ChartDataLabel], max_points: usize) {
         // Write the point formatting for the series.
@@ -2767,7 +3592,8 @@ impl Chart {
         if data_label.show_value
             || (!data_label.is_custom
                 && !data_label.show_category_name
-                && !data_label.show_percentage)
+                && !data_label.show_percentage
+                && !data_label.label_range.has_data())
         {
             // Write the c:showVal element.
             self.write_show_val();
@@ -3391,8 +4217,8 @@ impl Chart {
     }
 
     // Write the <c:smooth> element.
-    fn write_smooth(&mut self) {
-        let attributes = [("val", "1")];
+    fn write_smooth(&mut self, smooth: bool) {
+        let attributes = [("val", smooth.to_xml_bool())];
 
         self.writer.xml_empty_tag("c:smooth", &attributes);
     }
@@ -3421,10 +4247,19 @@ impl Chart {
         // Write the c:layout element.
         self.write_layout();
 
+        // Write the c:overlay element.
+        if title.has_overlay {
+            self.write_overlay();
+        }
+
         if title.format.has_formatting() {
             // Write the c:spPr formatting element.
             self.write_sp_pr(&title.format.clone());
-        } else {
+        }
+
+        // Excel omits the default c:txPr element when the title has
+        // formatting, but not if the font has also been set.
+        if !title.format.has_formatting() || title.has_font {
             // Write the c:txPr element.
             self.write_tx_pr(&title.font, title.is_horizontal);
         }
@@ -3452,6 +4287,11 @@ impl Chart {
         // Write the c:layout element.
         self.write_layout();
 
+        // Write the c:overlay element.
+        if title.has_overlay {
+            self.write_overlay();
+        }
+
         if title.format.has_formatting() {
             // Write the c:spPr element.
             self.write_sp_pr(&title.format.clone());
@@ -3467,6 +4307,11 @@ impl Chart {
         // Write the c:layout element.
         self.write_layout();
 
+        // Write the c:overlay element.
+        if title.has_overlay {
+            self.write_overlay();
+        }
+
         // Write the c:spPr element.
         self.write_sp_pr(&title.format.clone());
 
@@ -3800,6 +4645,7 @@ pub struct ChartSeries {
     pub(crate) overlap: i8,
     pub(crate) invert_if_negative: bool,
     pub(crate) inverted_color: Color,
+    pub(crate) smooth: Option<bool>,
 }
 
 #[allow(clippy::new_without_default)]
@@ -3910,6 +4756,7 @@ impl ChartSeries {
             overlap: 0,
             invert_if_negative: false,
             inverted_color: Color::Default,
+            smooth: None,
         }
     }
 
@@ -4076,31 +4923,28 @@ impl ChartSeries {
         self
     }
 
-    /// Add a name for a chart series.
+    /// Add literal values to a chart series.
     ///
-    /// Set the name for the series. The name is displayed in the formula bar.
-    /// For non-Pie/Doughnut charts it is also displayed in the legend. The name
-    /// property is optional and if it isn’t supplied it will default to `Series
-    /// 1..n`. The name can be a simple string, a formula such as `Sheet1!$A$1`
-    /// or a tuple with a sheet name, row and column such as `('Sheet1', 0, 0)`.
+    /// This method sets the chart series values from Rust data instead of a
+    /// worksheet range. The values are stored in the chart, like an Excel
+    /// `={1,2,3}` array, so they don't need to be written to a worksheet. This
+    /// is useful for charts of computed data that shouldn't be shown in the
+    /// worksheet.
+    ///
+    /// Non-finite values, such as `f64::NAN`, are treated as empty data
+    /// points.
     ///
     /// # Arguments
     ///
-    /// * `range` - The range property which can be one of the following generic
-    ///   types:
-    ///    - A simple string title.
-    ///    - A string with an Excel like range formula such as `"Sheet1!$A$1"`.
-    ///    - A tuple that can be used to create the range programmatically using
-    ///      a sheet name and zero indexed row and column values like:
-    ///      `("Sheet1", 0, 0)` (this gives the same range as the previous
-    ///      string value).
+    /// * `data` - A slice of numeric values.
     ///
     /// # Examples
     ///
-    /// A chart example demonstrating setting the chart series name.
+    /// A chart example demonstrating setting the chart series values and
+    /// categories from Rust data.
     ///
     /// ```
-    /// # // This code is available in examples/doc_chart_series_set_name.rs
+    /// # // This code is available in examples/doc_chart_series_set_values_from_data.rs
     /// #
     /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
     /// #
@@ -4108,12 +4952,112 @@ impl ChartSeries {
     /// #     let mut workbook = Workbook::new();
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add some data for the chart.
-    /// #     worksheet.write(0, 0, "Month")?;
-    /// #     worksheet.write(1, 0, "Jan")?;
-    /// #     worksheet.write(2, 0, "Feb")?;
-    /// #     worksheet.write(3, 0, "Mar")?;
-    /// #     worksheet.write(0, 1, "Total")?;
+    ///     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series from Rust data. The data isn't written to the
+    ///     // worksheet.
+    ///     chart
+    ///         .add_series()
+    ///         .set_categories_from_data(&["North", "South", "East", "West"])
+    ///         .set_values_from_data(&[10.5, 40.0, 50.25, 20.0]);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_values_from_data(&mut self, data: &[f64]) -> &mut ChartSeries {
+        self.value_range = ChartRange::new_from_range("", 0, 0, 0, 0);
+        self.value_cache_data = ChartSeriesCacheData {
+            is_numeric: true,
+            data: data
+                .iter()
+                .map(|value| {
+                    if value.is_finite() {
+                        value.to_string()
+                    } else {
+                        String::new()
+                    }
+                })
+                .collect(),
+        };
+        self
+    }
+
+    /// Add literal categories to a chart series.
+    ///
+    /// This method sets the chart series categories from Rust data instead of
+    /// a worksheet range. See
+    /// [`set_values_from_data()`](ChartSeries::set_values_from_data) for
+    /// details and an example.
+    ///
+    /// The categories are stored as numbers if all of the values can be
+    /// parsed as numbers, which is required for the X values of a Scatter
+    /// chart, and as strings otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - A slice of values that can be converted to strings, such as
+    ///   `&str` or numbers.
+    ///
+    pub fn set_categories_from_data<T>(&mut self, data: &[T]) -> &mut ChartSeries
+    where
+        T: ToString,
+    {
+        let data: Vec<String> = data.iter().map(ToString::to_string).collect();
+
+        self.category_range = ChartRange::new_from_range("", 0, 0, 0, 0);
+        self.category_cache_data = ChartSeriesCacheData {
+            is_numeric: data.iter().all(|value| value.parse::<f64>().is_ok()),
+            data,
+        };
+        self
+    }
+
+    /// Add a name for a chart series.
+    ///
+    /// Set the name for the series. The name is displayed in the formula bar.
+    /// For non-Pie/Doughnut charts it is also displayed in the legend. The name
+    /// property is optional and if it isn’t supplied it will default to `Series
+    /// 1..n`. The name can be a simple string, a formula such as `Sheet1!$A$1`
+    /// or a tuple with a sheet name, row and column such as `('Sheet1', 0, 0)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range property which can be one of the following generic
+    ///   types:
+    ///    - A simple string title.
+    ///    - A string with an Excel like range formula such as `"Sheet1!$A$1"`.
+    ///    - A tuple that can be used to create the range programmatically using
+    ///      a sheet name and zero indexed row and column values like:
+    ///      `("Sheet1", 0, 0)` (this gives the same range as the previous
+    ///      string value).
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating setting the chart series name.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_set_name.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, "Month")?;
+    /// #     worksheet.write(1, 0, "Jan")?;
+    /// #     worksheet.write(2, 0, "Feb")?;
+    /// #     worksheet.write(3, 0, "Mar")?;
+    /// #     worksheet.write(0, 1, "Total")?;
     /// #     worksheet.write(1, 1, 30)?;
     /// #     worksheet.write(2, 1, 20)?;
     /// #     worksheet.write(3, 1, 40)?;
@@ -4273,6 +5217,63 @@ impl ChartSeries {
         self
     }
 
+    /// Set a line type chart series to use smoothed lines.
+    ///
+    /// Line and Scatter chart series are drawn with straight lines between
+    /// the points by default, apart from the `ScatterSmooth` chart types. The
+    /// `set_smooth()` method can be used to turn smoothing on, or off, for an
+    /// individual series. It is ignored for other chart types.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default except for
+    ///   the `ScatterSmooth` chart types.
+    ///
+    /// # Examples
+    ///
+    /// An example of a line chart with a smoothed series.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_set_smooth.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(2, 0, 50)?;
+    /// #     worksheet.write(3, 0, 20)?;
+    /// #     worksheet.write(4, 0, 10)?;
+    /// #     worksheet.write(5, 0, 50)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Line);
+    ///
+    ///     // Add a data series with smoothed lines.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$A$1:$A$6")
+    ///         .set_smooth(true);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_smooth(&mut self, enable: bool) -> &mut ChartSeries {
+        self.smooth = Some(enable);
+        self
+    }
+
     /// Set the data labels for a chart series.
     ///
     /// Set the data labels and marker properties for a data series using a
@@ -4830,7 +5831,7 @@ impl ChartSeries {
     /// # Arguments
     ///
     /// * `overlap`: Overlap percentage of columns in Bar/Column charts. The
-    /// range is -100 <= overlap <= 100 and the default is 0.
+    ///   range is -100 <= overlap <= 100 and the default is 0.
     ///
     /// # Examples
     ///
@@ -4900,7 +5901,7 @@ impl ChartSeries {
     /// # Arguments
     ///
     /// * `gap`: Gap percentage of columns in Bar/Column charts. The range is 0
-    /// <= gap <= 500 and the default is 150.
+    ///   <= gap <= 500 and the default is 150.
     ///
     /// See the example for [`series.set_overlap()`](ChartSeries::set_overlap)
     /// above.
@@ -5053,6 +6054,15 @@ impl ChartSeries {
         self
     }
 
+    // Get the number of data points in the series values.
+    pub(crate) fn number_of_points(&self) -> usize {
+        if self.value_range.has_data() {
+            self.value_range.number_of_points()
+        } else {
+            self.value_cache_data.data.len()
+        }
+    }
+
     /// Add data to the chart values cache.
     ///
     /// This method is only used to populate the chart data caches in test code.
@@ -5286,6 +6296,15 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_bar_percent_stacked.png">
     BarPercentStacked,
 
+    /// A 3-D Bar chart type.
+    Bar3D,
+
+    /// A 3-D stacked Bar chart type.
+    Bar3DStacked,
+
+    /// A 3-D percent stacked Bar chart type.
+    Bar3DPercentStacked,
+
     /// A Column (vertical histogram) chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_column.png">
@@ -5301,6 +6320,15 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_column_percent_stacked.png">
     ColumnPercentStacked,
 
+    /// A 3-D Column chart type.
+    Column3D,
+
+    /// A 3-D stacked Column chart type.
+    Column3DStacked,
+
+    /// A 3-D percent stacked Column chart type.
+    Column3DPercentStacked,
+
     /// A Doughnut chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_doughnut.png">
@@ -5321,11 +6349,17 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_line_percent_stacked.png">
     LinePercentStacked,
 
+    /// A 3-D Line chart type.
+    Line3D,
+
     /// A Pie chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_pie.png">
     Pie,
 
+    /// A 3-D Pie chart type.
+    Pie3D,
+
     /// A Radar chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_radar.png">
@@ -5381,6 +6415,8 @@ pub struct ChartTitle {
     pub(crate) font: ChartFont,
     name: String,
     hidden: bool,
+    has_font: bool,
+    has_overlay: bool,
     is_horizontal: bool,
     ignore_rich_para: bool,
 }
@@ -5394,6 +6430,8 @@ impl ChartTitle {
             font: ChartFont::new(),
             name: String::new(),
             hidden: false,
+            has_font: false,
+            has_overlay: false,
             is_horizontal: false,
             ignore_rich_para: false,
         }
@@ -5407,6 +6445,9 @@ impl ChartTitle {
     /// The name can be a simple string, a formula such as `Sheet1!$A$1` or a
     /// tuple with a sheet name, row and column such as `('Sheet1', 0, 0)`.
     ///
+    /// A simple string title can be split over several lines by adding `\n`
+    /// newline characters to the string.
+    ///
     /// # Arguments
     ///
     /// * `range` - The range property which can be one of the following generic
@@ -5532,6 +6573,59 @@ impl ChartTitle {
         self
     }
 
+    /// Set the chart title to overlay the plot area.
+    ///
+    /// By default Excel reduces the size of the plot area to make space for
+    /// the chart title. The `set_overlay()` method allows the title to be
+    /// displayed on top of the plot area instead, in the same way as
+    /// [`ChartLegend::set_overlay()`].
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating setting the chart title to overlay the
+    /// plot area.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_title_set_overlay.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 50)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(2, 0, 40)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///
+    ///     // Set a two line chart title and display it over the plot area.
+    ///     chart
+    ///         .title()
+    ///         .set_name("Yearly results\nAll regions")
+    ///         .set_overlay();
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_overlay(&mut self) -> &mut ChartTitle {
+        self.has_overlay = true;
+        self
+    }
+
     /// Set the formatting properties for a chart title.
     ///
     /// Set the formatting properties for a chart title via a [`ChartFormat`]
@@ -5637,6 +6731,7 @@ impl ChartTitle {
         }
 
         self.font = font;
+        self.has_font = true;
         self
     }
 }
@@ -6029,6 +7124,8 @@ pub struct ChartDataLabel {
     pub(crate) is_custom: bool,
     pub(crate) font: Option<ChartFont>,
     pub(crate) num_format: String,
+    pub(crate) label_range: ChartRange,
+    pub(crate) label_cache_data: ChartSeriesCacheData,
 }
 
 impl Default for ChartDataLabel {
@@ -6057,6 +7154,8 @@ impl ChartDataLabel {
             is_custom: false,
             font: None,
             num_format: String::new(),
+            label_range: ChartRange::new_from_range("", 0, 0, 0, 0),
+            label_cache_data: ChartSeriesCacheData::new(),
         }
     }
 
@@ -6604,6 +7703,84 @@ impl ChartDataLabel {
         self.show_category_name()
     }
 
+    /// Display values from a worksheet range on the data labels.
+    ///
+    /// This is the equivalent of the Excel "Value From Cells" data label
+    /// option. The values in the range are displayed, in order, on the data
+    /// labels of the series points. It can be combined with the other display
+    /// options such as [`show_value()`](ChartDataLabel::show_value).
+    ///
+    /// This option requires Excel 2013 or later. It is ignored if used with a
+    /// custom data label.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - The range property which can be one of two generic types:
+    ///    - A string with an Excel like range formula such as
+    ///      `"Sheet1!$C$1:$C$3"`.
+    ///    - A tuple that can be used to create the range programmatically using
+    ///      a sheet name and zero indexed row and column values like:
+    ///      `("Sheet1", 0, 2, 2, 2)` (this gives the same range as the previous
+    ///      string value).
+    ///
+    /// # Examples
+    ///
+    /// An example of adding data labels with values from a worksheet range to
+    /// a chart series.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_data_labels_show_value_from_cells.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartDataLabel, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(2, 0, 50)?;
+    /// #     worksheet.write(3, 0, 20)?;
+    /// #     worksheet.write(4, 0, 10)?;
+    /// #     worksheet.write(5, 0, 50)?;
+    /// #
+    /// #     // Add the data label text.
+    /// #     worksheet.write(0, 1, "Jan")?;
+    /// #     worksheet.write(1, 1, "Feb")?;
+    /// #     worksheet.write(2, 1, "Mar")?;
+    /// #     worksheet.write(3, 1, "Apr")?;
+    /// #     worksheet.write(4, 1, "May")?;
+    /// #     worksheet.write(5, 1, "Jun")?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series with data labels from a worksheet range.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$A$1:$A$6")
+    ///         .set_data_label(ChartDataLabel::new().show_value_from_cells("Sheet1!$B$1:$B$6"));
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn show_value_from_cells<T>(&mut self, range: T) -> &mut ChartDataLabel
+    where
+        T: IntoChartRange,
+    {
+        self.label_range = range.new_chart_range();
+        self.is_default = false;
+        self
+    }
+
     /// Set the value for a custom data label.
     ///
     /// This method sets the value of a custom data label used with the
@@ -6988,6 +8165,7 @@ impl fmt::Display for ChartDataLabelPosition {
 #[derive(Clone)]
 pub struct ChartPoint {
     pub(crate) format: ChartFormat,
+    pub(crate) explosion: u16,
 }
 
 impl Default for ChartPoint {
@@ -7002,6 +8180,7 @@ impl ChartPoint {
     pub fn new() -> ChartPoint {
         ChartPoint {
             format: ChartFormat::new(),
+            explosion: 0,
         }
     }
 
@@ -7032,32 +8211,94 @@ impl ChartPoint {
         self
     }
 
-    pub(crate) fn is_not_default(&self) -> bool {
-        self.format.has_formatting()
-    }
-}
-
-/// A struct to represent a Chart axis.
-///
-/// Used in conjunction with the [`Chart::x_axis()`](Chart::x_axis) and
-/// [`Chart::y_axis()`](Chart::y_axis).
-///
-/// # Examples
-///
-/// A chart example demonstrating setting properties of the axes.
-///
-/// ```
-/// # // This code is available in examples/doc_chart_axis_set_name.rs
-/// #
-/// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
-/// #
-/// # fn main() -> Result<(), XlsxError> {
-/// #     let mut workbook = Workbook::new();
-/// #     let worksheet = workbook.add_worksheet();
-/// #
-/// #     // Add some data for the chart.
-/// #     worksheet.write(0, 0, 50)?;
-/// #     worksheet.write(1, 0, 30)?;
+    /// Set the explosion distance for a Pie/Doughnut chart point.
+    ///
+    /// The `set_explosion()` method is used to pull a segment of a Pie or
+    /// Doughnut chart out from the center of the chart to highlight it. It is
+    /// ignored for other chart types.
+    ///
+    /// # Arguments
+    ///
+    /// * `explosion`: The distance of the segment from the center of the chart
+    ///   as a percentage of the radius. The range is 0 <= explosion <= 400 and
+    ///   the default is 0.
+    ///
+    /// # Examples
+    ///
+    /// An example of exploding a segment of a pie chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_point_set_explosion.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartPoint, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(2, 0, 50)?;
+    /// #
+    ///     // Pull out the second segment of the pie.
+    ///     let points = vec![
+    ///         ChartPoint::new(),
+    ///         ChartPoint::new().set_explosion(20),
+    ///     ];
+    ///
+    ///     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Pie);
+    ///
+    ///     // Add a data series with the exploded point.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$A$1:$A$3")
+    ///         .set_points(&points);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_explosion(mut self, explosion: u16) -> ChartPoint {
+        if explosion <= 400 {
+            self.explosion = explosion;
+        }
+        self
+    }
+
+    pub(crate) fn is_not_default(&self) -> bool {
+        self.format.has_formatting() || self.explosion > 0
+    }
+}
+
+/// A struct to represent a Chart axis.
+///
+/// Used in conjunction with the [`Chart::x_axis()`](Chart::x_axis) and
+/// [`Chart::y_axis()`](Chart::y_axis).
+///
+/// # Examples
+///
+/// A chart example demonstrating setting properties of the axes.
+///
+/// ```
+/// # // This code is available in examples/doc_chart_axis_set_name.rs
+/// #
+/// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+/// #     // Add some data for the chart.
+/// #     worksheet.write(0, 0, 50)?;
+/// #     worksheet.write(1, 0, 30)?;
 /// #     worksheet.write(2, 0, 40)?;
 /// #
 /// #     // Create a new chart.
@@ -7109,6 +8350,9 @@ pub struct ChartAxis {
     pub(crate) tick_interval: u16,
     pub(crate) major_tick_type: Option<ChartAxisTickType>,
     pub(crate) minor_tick_type: Option<ChartAxisTickType>,
+    pub(crate) display_units: ChartAxisDisplayUnitType,
+    pub(crate) display_units_visible: bool,
+    pub(crate) crossing: ChartAxisCrossing,
 }
 
 impl ChartAxis {
@@ -7137,6 +8381,9 @@ impl ChartAxis {
             tick_interval: 0,
             major_tick_type: None,
             minor_tick_type: None,
+            display_units: ChartAxisDisplayUnitType::None,
+            display_units_visible: false,
+            crossing: ChartAxisCrossing::Automatic,
         }
     }
 
@@ -8224,6 +9471,71 @@ impl ChartAxis {
         self
     }
 
+    /// Set the position where the opposite axis will cross this axis.
+    ///
+    /// By default Excel sets the crossing of the opposite axis automatically,
+    /// which is usually at zero for Value axes and at the first category for
+    /// Category axes. This method can be used to position the crossing at the
+    /// minimum or maximum of the axis or at a specific value.
+    ///
+    /// For Category axes the [`ChartAxisCrossing::AxisValue`] value is the
+    /// category number, starting from 1, where the crossing occurs.
+    ///
+    /// # Arguments
+    ///
+    /// * `crossing` - A [`ChartAxisCrossing`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating setting the point where the axes cross.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_axis_set_crossing.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartAxisCrossing, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 5)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(2, 0, 40)?;
+    /// #     worksheet.write(3, 0, 30)?;
+    /// #     worksheet.write(4, 0, 5)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///
+    ///     // Set the value axis to cross at the 3rd category and the category
+    ///     // axis to cross at the maximum value.
+    ///     chart
+    ///         .x_axis()
+    ///         .set_crossing(ChartAxisCrossing::AxisValue(3.0));
+    ///     chart.y_axis().set_crossing(ChartAxisCrossing::Max);
+    ///
+    ///     // Hide legend for clarity.
+    ///     chart.legend().set_hidden();
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_crossing(&mut self, crossing: ChartAxisCrossing) -> &mut ChartAxis {
+        self.crossing = crossing;
+        self
+    }
+
     /// Set the type of major tick for the axis.
     ///
     /// Excel supports 4 types of tick position:
@@ -8375,6 +9687,80 @@ impl ChartAxis {
         self
     }
 
+    /// Set the display units for the axis.
+    ///
+    /// Display units scale the numbers shown on a value axis so that, for
+    /// example, 1,200,000 is displayed as 1.2 with an optional "Millions"
+    /// label on the axis. The underlying data isn't changed.
+    ///
+    /// This property is only applicable to value axes, see [Chart Value and
+    /// Category Axes] for an explanation of the difference between Value and
+    /// Category axes in Excel.
+    ///
+    /// [Chart Value and Category Axes]:
+    ///     struct.Chart.html#chart-value-and-category-axes
+    ///
+    /// # Arguments
+    ///
+    /// * `units` - A [`ChartAxisDisplayUnitType`] enum value.
+    /// * `show_label` - Display the units label, such as "Millions", on the
+    ///   axis.
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating setting the display units for a chart
+    /// axis.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_axis_set_display_units.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartAxisDisplayUnitType, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 1_200_000)?;
+    /// #     worksheet.write(1, 0, 3_500_000)?;
+    /// #     worksheet.write(2, 0, 2_800_000)?;
+    /// #     worksheet.write(3, 0, 4_100_000)?;
+    /// #     worksheet.write(4, 0, 5_600_000)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///
+    ///     // Show the Y-axis values in millions, with a label.
+    ///     chart
+    ///         .y_axis()
+    ///         .set_display_units(ChartAxisDisplayUnitType::Millions, true);
+    ///
+    ///     // Hide legend for clarity.
+    ///     chart.legend().set_hidden();
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_display_units(
+        &mut self,
+        units: ChartAxisDisplayUnitType,
+        show_label: bool,
+    ) -> &mut ChartAxis {
+        self.display_units = units;
+        self.display_units_visible = show_label;
+        self
+    }
+
     /// Hide the chart axis.
     ///
     /// Hide the number or label section of the chart axis.
@@ -8642,6 +10028,94 @@ impl fmt::Display for ChartAxisTickType {
     }
 }
 
+/// Enum to define the position where a chart axis crosses the opposite axis.
+///
+/// Used in conjunction with [`set_crossing()`](ChartAxis::set_crossing).
+///
+#[derive(Clone, Copy, PartialEq)]
+pub enum ChartAxisCrossing {
+    /// The axis crossing is set automatically by Excel. The default.
+    Automatic,
+
+    /// The opposite axis crosses at the minimum value of the axis.
+    Min,
+
+    /// The opposite axis crosses at the maximum value of the axis.
+    Max,
+
+    /// The opposite axis crosses at the specified value. For Category axes
+    /// this is the category number, starting from 1.
+    AxisValue(f64),
+}
+
+impl fmt::Display for ChartAxisCrossing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartAxisCrossing::Automatic => write!(f, "autoZero"),
+            ChartAxisCrossing::Min => write!(f, "min"),
+            ChartAxisCrossing::Max => write!(f, "max"),
+            ChartAxisCrossing::AxisValue(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The `ChartAxisDisplayUnitType` enum defines the [`ChartAxis`] display
+/// units.
+///
+/// The display units scale the numbers shown on a value axis, for example to
+/// show 1,200,000 as 1.2 with a "Millions" label. Used in conjunction with
+/// [`set_display_units()`](ChartAxis::set_display_units).
+///
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ChartAxisDisplayUnitType {
+    /// No display units. This is the default.
+    None,
+
+    /// Display units of hundreds.
+    Hundreds,
+
+    /// Display units of thousands.
+    Thousands,
+
+    /// Display units of ten thousands.
+    TenThousands,
+
+    /// Display units of hundred thousands.
+    HundredThousands,
+
+    /// Display units of millions.
+    Millions,
+
+    /// Display units of ten millions.
+    TenMillions,
+
+    /// Display units of hundred millions.
+    HundredMillions,
+
+    /// Display units of billions.
+    Billions,
+
+    /// Display units of trillions.
+    Trillions,
+}
+
+impl fmt::Display for ChartAxisDisplayUnitType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartAxisDisplayUnitType::None => write!(f, "none"),
+            ChartAxisDisplayUnitType::Hundreds => write!(f, "hundreds"),
+            ChartAxisDisplayUnitType::Thousands => write!(f, "thousands"),
+            ChartAxisDisplayUnitType::TenThousands => write!(f, "tenThousands"),
+            ChartAxisDisplayUnitType::HundredThousands => write!(f, "hundredThousands"),
+            ChartAxisDisplayUnitType::Millions => write!(f, "millions"),
+            ChartAxisDisplayUnitType::TenMillions => write!(f, "tenMillions"),
+            ChartAxisDisplayUnitType::HundredMillions => write!(f, "hundredMillions"),
+            ChartAxisDisplayUnitType::Billions => write!(f, "billions"),
+            ChartAxisDisplayUnitType::Trillions => write!(f, "trillions"),
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(crate) enum ChartGrouping {
     Stacked,
@@ -8723,6 +10197,7 @@ pub struct ChartLegend {
     has_overlay: bool,
     pub(crate) format: ChartFormat,
     pub(crate) font: Option<ChartFont>,
+    deleted_entries: Vec<usize>,
 }
 
 impl ChartLegend {
@@ -8733,6 +10208,7 @@ impl ChartLegend {
             has_overlay: false,
             format: ChartFormat::new(),
             font: None,
+            deleted_entries: vec![],
         }
     }
 
@@ -9002,6 +10478,68 @@ impl ChartLegend {
         self.font = Some(font.clone());
         self
     }
+
+    /// Delete entries from the chart legend.
+    ///
+    /// The `set_delete_entries()` method removes the legend entries at the
+    /// given zero based indices, without removing the data from the chart.
+    /// This is useful for hiding series such as trend or target lines from
+    /// the legend. For Pie and Doughnut charts the legend entries are the
+    /// points of the series rather than the series.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries`: A slice of zero based legend entry indices.
+    ///
+    /// # Examples
+    ///
+    /// An example of deleting an entry from a chart legend.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_legend_set_delete_entries.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 50)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(2, 0, 40)?;
+    /// #     worksheet.write(0, 1, 45)?;
+    /// #     worksheet.write(1, 1, 45)?;
+    /// #     worksheet.write(2, 1, 45)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add the data series and a target line series.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///     chart.add_series().set_values("Sheet1!$B$1:$B$3");
+    ///
+    ///     // Remove the target series from the legend.
+    ///     chart.legend().set_delete_entries(&[1]);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 3, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_delete_entries(&mut self, entries: &[usize]) -> &mut ChartLegend {
+        let mut entries = entries.to_vec();
+        entries.sort_unstable();
+        entries.dedup();
+
+        self.deleted_entries = entries;
+        self
+    }
 }
 
 /// Enum used to specify the position of the Chart legend.
@@ -9044,18 +10582,45 @@ impl fmt::Display for ChartLegendPosition {
     }
 }
 
-#[derive(Clone)]
-/// A struct to represent formatting for various Chart objects.
-///
-/// Excel uses a standard formatting dialog for the elements of a chart such as
-/// data series, the plot area, the chart area, the legend or individual points.
-/// It looks like this:
-///
-/// <img src="https://rustxlsxwriter.github.io/images/chart_format_dialog.png">
+/// Enum used to specify how empty cells are displayed in a chart.
 ///
-/// The [`ChartFormat`] struct represents many of these format options and just
-/// like Excel it offers a similar formatting interface for a number of the
-/// chart sub-elements supported by `rust_xlsxwriter`.
+/// Used in conjunction with
+/// [`chart.show_blanks_as()`](Chart::show_blanks_as).
+///
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ChartEmptyCells {
+    /// Show empty cells as gaps in the chart. The default.
+    Gap,
+
+    /// Show empty cells as zero values.
+    Zero,
+
+    /// Connect the data points on either side of an empty cell with a line.
+    Span,
+}
+
+impl fmt::Display for ChartEmptyCells {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartEmptyCells::Gap => write!(f, "gap"),
+            ChartEmptyCells::Zero => write!(f, "zero"),
+            ChartEmptyCells::Span => write!(f, "span"),
+        }
+    }
+}
+
+#[derive(Clone)]
+/// A struct to represent formatting for various Chart objects.
+///
+/// Excel uses a standard formatting dialog for the elements of a chart such as
+/// data series, the plot area, the chart area, the legend or individual points.
+/// It looks like this:
+///
+/// <img src="https://rustxlsxwriter.github.io/images/chart_format_dialog.png">
+///
+/// The [`ChartFormat`] struct represents many of these format options and just
+/// like Excel it offers a similar formatting interface for a number of the
+/// chart sub-elements supported by `rust_xlsxwriter`.
 ///
 /// The [`ChartFormat`] struct is accessed by using the `set_format()` method of a
 /// chart element to obtain a reference to the formatting struct for that
@@ -9730,8 +11295,8 @@ impl ChartLine {
     /// # Arguments
     ///
     /// * `width` - The width should be specified in increments of 0.25 of a
-    /// point as in Excel. The width can be an number type that convert [`Into`]
-    /// [`f64`].
+    ///   point as in Excel. The width can be an number type that convert [`Into`]
+    ///   [`f64`].
     ///
     /// # Examples
     ///
@@ -11347,7 +12912,11 @@ impl ChartFont {
 #[cfg(test)]
 mod tests {
 
-    use crate::chart::{Chart, ChartRange, ChartSeries, ChartType, XlsxError};
+    use crate::chart::{
+        Chart, ChartAxisCrossing, ChartAxisDisplayUnitType, ChartDataLabel, ChartEmptyCells,
+        ChartFont, ChartFormat, ChartLine, ChartPoint, ChartRange, ChartSeries,
+        ChartSeriesCacheData, ChartType, XlsxError,
+    };
     use crate::test_functions::xml_to_vec;
     use pretty_assertions::assert_eq;
 
@@ -11608,6 +13177,689 @@ mod tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn test_display_units() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart
+            .y_axis()
+            .set_display_units(ChartAxisDisplayUnitType::Millions, true);
+        chart
+            .x_axis()
+            .set_display_units(ChartAxisDisplayUnitType::Thousands, true);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        // The category axis doesn't have display units.
+        assert!(!got.contains(r#"<c:builtInUnit val="thousands"/>"#));
+        assert!(got.contains(r#"<c:crossBetween val="between"/><c:dispUnits><c:builtInUnit val="millions"/><c:dispUnitsLbl><c:layout/></c:dispUnitsLbl></c:dispUnits></c:valAx>"#));
+
+        let mut chart = Chart::new(ChartType::Scatter);
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$5")
+            .set_values("Sheet1!$B$1:$B$5");
+        chart
+            .x_axis()
+            .set_display_units(ChartAxisDisplayUnitType::Thousands, false);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:dispUnits><c:builtInUnit val="thousands"/></c:dispUnits>"#));
+    }
+
+    #[test]
+    fn test_point_explosion() {
+        let points = [ChartPoint::new(), ChartPoint::new().set_explosion(20)];
+
+        let mut chart = Chart::new(ChartType::Pie);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_points(&points);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:dPt><c:idx val="1"/><c:explosion val="20"/></c:dPt>"#));
+        assert!(!got.contains(r#"<c:idx val="0"/><c:explosion"#));
+
+        // The explosion is ignored for other chart types.
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_points(&points);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(!got.contains("c:explosion"));
+    }
+
+    #[test]
+    fn test_legend_delete_entries() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.add_series().set_values("Sheet1!$B$1:$B$3");
+        chart.add_series().set_values("Sheet1!$C$1:$C$3");
+        chart.legend().set_delete_entries(&[2, 0, 2]);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:legend><c:legendPos val="r"/><c:legendEntry><c:idx val="0"/><c:delete val="1"/></c:legendEntry><c:legendEntry><c:idx val="2"/><c:delete val="1"/></c:legendEntry><c:layout/>"#));
+    }
+
+    #[test]
+    fn test_series_smooth() {
+        let mut chart = Chart::new(ChartType::Line);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_smooth(true);
+        chart.add_series().set_values("Sheet1!$B$1:$B$3");
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert_eq!(got.matches(r#"<c:smooth val="1"/>"#).count(), 1);
+
+        let mut chart = Chart::new(ChartType::ScatterSmooth);
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$3")
+            .set_values("Sheet1!$B$1:$B$3");
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$3")
+            .set_values("Sheet1!$C$1:$C$3")
+            .set_smooth(false);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert_eq!(got.matches(r#"<c:smooth val="1"/>"#).count(), 1);
+        assert_eq!(got.matches(r#"<c:smooth val="0"/>"#).count(), 1);
+    }
+
+    #[test]
+    fn test_drop_and_high_low_lines() {
+        let mut chart = Chart::new(ChartType::Line);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.set_drop_lines(true);
+        chart.set_high_low_lines_line(ChartLine::new().set_color("#FF0000"));
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"</c:ser><c:dropLines/><c:hiLowLines><c:spPr><a:ln><a:solidFill><a:srgbClr val="FF0000"/></a:solidFill></a:ln></c:spPr></c:hiLowLines><c:marker val="1"/>"#));
+
+        // Area charts only support drop lines.
+        let mut chart = Chart::new(ChartType::Area);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.set_drop_lines(true).set_high_low_lines(true);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains("</c:ser><c:dropLines/><c:axId"));
+        assert!(!got.contains("c:hiLowLines"));
+    }
+
+    #[test]
+    fn test_title_overlay() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.title().set_name("Line 1\nLine 2").set_overlay();
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains("<a:t>Line 1\nLine 2</a:t>"));
+        assert!(got.contains(r#"</c:tx><c:layout/><c:overlay val="1"/></c:title>"#));
+
+        // Formula titles can have both a format and a font.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart
+            .title()
+            .set_name("Sheet1!$B$1")
+            .set_overlay()
+            .set_format(ChartFormat::new().set_no_fill())
+            .set_font(ChartFont::new().set_bold());
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(
+            got.contains(r#"<c:layout/><c:overlay val="1"/><c:spPr><a:noFill/></c:spPr><c:txPr>"#)
+        );
+        assert!(got.contains(r#"<a:defRPr b="1"/>"#));
+    }
+
+    #[test]
+    fn test_data_labels_value_from_cells() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$2")
+            .set_invert_if_negative_color("#FF0000")
+            .set_data_label(ChartDataLabel::new().show_value_from_cells("Sheet1!$B$1:$B$2"));
+
+        chart.series[0]
+            .data_label
+            .as_mut()
+            .unwrap()
+            .label_cache_data = ChartSeriesCacheData {
+            is_numeric: false,
+            data: vec!["Jan".to_string(), "Feb".to_string()],
+        };
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        // The value isn't displayed by default when the range is shown.
+        assert!(!got.contains("<c:showVal"));
+        assert!(got.contains(r#"<c:extLst><c:ext uri="{CE6537A1-D6FC-4f65-9D91-7224C49458BB}" xmlns:c15="http://schemas.microsoft.com/office/drawing/2012/chart"><c15:showDataLabelsRange val="1"/></c:ext></c:extLst></c:dLbls>"#));
+        assert!(got.contains(r#"</c14:invertSolidFillFmt></c:ext><c:ext uri="{02D57815-91ED-43cb-92C2-25804820EDAC}" xmlns:c15="http://schemas.microsoft.com/office/drawing/2012/chart"><c15:datalabelsRange><c15:f>Sheet1!$B$1:$B$2</c15:f><c15:dlblRangeCache><c:ptCount val="2"/><c:pt idx="0"><c:v>Jan</c:v></c:pt><c:pt idx="1"><c:v>Feb</c:v></c:pt></c15:dlblRangeCache></c15:datalabelsRange></c:ext></c:extLst></c:ser>"#));
+    }
+
+    #[test]
+    fn test_series_from_data() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_categories_from_data(&["A", "B"])
+            .set_values_from_data(&[1.5, f64::NAN]);
+
+        chart.validate().unwrap();
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:cat><c:strLit><c:ptCount val="2"/><c:pt idx="0"><c:v>A</c:v></c:pt><c:pt idx="1"><c:v>B</c:v></c:pt></c:strLit></c:cat>"#));
+        assert!(got.contains(r#"<c:val><c:numLit><c:formatCode>General</c:formatCode><c:ptCount val="2"/><c:pt idx="0"><c:v>1.5</c:v></c:pt></c:numLit></c:val>"#));
+
+        // Scatter charts need numeric X values.
+        let mut chart = Chart::new(ChartType::Scatter);
+        chart
+            .add_series()
+            .set_categories_from_data(&[1, 2])
+            .set_values_from_data(&[3.0, 4.0]);
+
+        chart.validate().unwrap();
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<c:xVal><c:numLit><c:formatCode>General</c:formatCode><c:ptCount val="2"/>"#
+        ));
+    }
+
+    #[test]
+    fn test_series_from_index() {
+        let mut template = Chart::new(ChartType::Column);
+        template.add_series().set_values("Sheet1!$A$1:$A$3");
+
+        let mut chart = template.clone();
+        chart.series(0).unwrap().set_values("Sheet1!$B$1:$B$3");
+
+        assert_eq!("Sheet1!$A$1:$A$3", template.series[0].value_range.formula());
+        assert_eq!("Sheet1!$B$1:$B$3", chart.series[0].value_range.formula());
+        assert!(matches!(chart.series(1), Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_remove_series() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.add_series().set_values("Sheet1!$B$1:$B$3");
+
+        chart.remove_series(0).unwrap();
+
+        assert_eq!(1, chart.series.len());
+        assert_eq!("Sheet1!$B$1:$B$3", chart.series[0].value_range.formula());
+        assert!(matches!(
+            chart.remove_series(1),
+            Err(XlsxError::ChartError(_))
+        ));
+    }
+
+    #[test]
+    fn test_assemble_column_3d() {
+        let mut chart = Chart::new(ChartType::Column3D);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_value_cache(&["1", "2", "3"], true);
+        chart.set_axis_ids(100, 101);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                  <c:lang val="en-US"/>
+                  <c:chart>
+                    <c:view3D>
+                      <c:rotX val="15"/>
+                      <c:rotY val="20"/>
+                      <c:rAngAx val="1"/>
+                    </c:view3D>
+                    <c:plotArea>
+                      <c:layout/>
+                      <c:bar3DChart>
+                        <c:barDir val="col"/>
+                        <c:grouping val="clustered"/>
+                        <c:ser>
+                          <c:idx val="0"/>
+                          <c:order val="0"/>
+                          <c:val>
+                            <c:numRef>
+                              <c:f>Sheet1!$A$1:$A$3</c:f>
+                              <c:numCache>
+                                <c:formatCode>General</c:formatCode>
+                                <c:ptCount val="3"/>
+                                <c:pt idx="0">
+                                  <c:v>1</c:v>
+                                </c:pt>
+                                <c:pt idx="1">
+                                  <c:v>2</c:v>
+                                </c:pt>
+                                <c:pt idx="2">
+                                  <c:v>3</c:v>
+                                </c:pt>
+                              </c:numCache>
+                            </c:numRef>
+                          </c:val>
+                        </c:ser>
+                        <c:axId val="100"/>
+                        <c:axId val="101"/>
+                        <c:axId val="0"/>
+                      </c:bar3DChart>
+                      <c:catAx>
+                        <c:axId val="100"/>
+                        <c:scaling>
+                          <c:orientation val="minMax"/>
+                        </c:scaling>
+                        <c:axPos val="b"/>
+                        <c:tickLblPos val="nextTo"/>
+                        <c:crossAx val="101"/>
+                        <c:crosses val="autoZero"/>
+                        <c:auto val="1"/>
+                        <c:lblAlgn val="ctr"/>
+                        <c:lblOffset val="100"/>
+                      </c:catAx>
+                      <c:valAx>
+                        <c:axId val="101"/>
+                        <c:scaling>
+                          <c:orientation val="minMax"/>
+                        </c:scaling>
+                        <c:axPos val="l"/>
+                        <c:majorGridlines/>
+                        <c:numFmt formatCode="General" sourceLinked="1"/>
+                        <c:tickLblPos val="nextTo"/>
+                        <c:crossAx val="100"/>
+                        <c:crosses val="autoZero"/>
+                        <c:crossBetween val="between"/>
+                      </c:valAx>
+                    </c:plotArea>
+                    <c:legend>
+                      <c:legendPos val="r"/>
+                      <c:layout/>
+                    </c:legend>
+                    <c:plotVisOnly val="1"/>
+                  </c:chart>
+                  <c:printSettings>
+                    <c:headerFooter/>
+                    <c:pageMargins b="0.75" l="0.7" r="0.7" t="0.75" header="0.3" footer="0.3"/>
+                    <c:pageSetup/>
+                  </c:printSettings>
+                </c:chartSpace>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_line_3d() {
+        let mut chart = Chart::new(ChartType::Line3D);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_value_cache(&["1", "2", "3"], true);
+        chart.set_axis_ids(100, 101);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                  <c:lang val="en-US"/>
+                  <c:chart>
+                    <c:view3D>
+                      <c:rotX val="15"/>
+                      <c:rotY val="20"/>
+                      <c:rAngAx val="0"/>
+                      <c:perspective val="30"/>
+                    </c:view3D>
+                    <c:plotArea>
+                      <c:layout/>
+                      <c:line3DChart>
+                        <c:grouping val="standard"/>
+                        <c:ser>
+                          <c:idx val="0"/>
+                          <c:order val="0"/>
+                          <c:marker>
+                            <c:symbol val="none"/>
+                          </c:marker>
+                          <c:val>
+                            <c:numRef>
+                              <c:f>Sheet1!$A$1:$A$3</c:f>
+                              <c:numCache>
+                                <c:formatCode>General</c:formatCode>
+                                <c:ptCount val="3"/>
+                                <c:pt idx="0">
+                                  <c:v>1</c:v>
+                                </c:pt>
+                                <c:pt idx="1">
+                                  <c:v>2</c:v>
+                                </c:pt>
+                                <c:pt idx="2">
+                                  <c:v>3</c:v>
+                                </c:pt>
+                              </c:numCache>
+                            </c:numRef>
+                          </c:val>
+                        </c:ser>
+                        <c:axId val="100"/>
+                        <c:axId val="101"/>
+                        <c:axId val="102"/>
+                      </c:line3DChart>
+                      <c:catAx>
+                        <c:axId val="100"/>
+                        <c:scaling>
+                          <c:orientation val="minMax"/>
+                        </c:scaling>
+                        <c:axPos val="b"/>
+                        <c:tickLblPos val="nextTo"/>
+                        <c:crossAx val="101"/>
+                        <c:crosses val="autoZero"/>
+                        <c:auto val="1"/>
+                        <c:lblAlgn val="ctr"/>
+                        <c:lblOffset val="100"/>
+                      </c:catAx>
+                      <c:valAx>
+                        <c:axId val="101"/>
+                        <c:scaling>
+                          <c:orientation val="minMax"/>
+                        </c:scaling>
+                        <c:axPos val="l"/>
+                        <c:majorGridlines/>
+                        <c:numFmt formatCode="General" sourceLinked="1"/>
+                        <c:tickLblPos val="nextTo"/>
+                        <c:crossAx val="100"/>
+                        <c:crosses val="autoZero"/>
+                        <c:crossBetween val="between"/>
+                      </c:valAx>
+                      <c:serAx>
+                        <c:axId val="102"/>
+                        <c:scaling>
+                          <c:orientation val="minMax"/>
+                        </c:scaling>
+                        <c:axPos val="b"/>
+                        <c:tickLblPos val="nextTo"/>
+                        <c:crossAx val="101"/>
+                        <c:crosses val="autoZero"/>
+                      </c:serAx>
+                    </c:plotArea>
+                    <c:legend>
+                      <c:legendPos val="r"/>
+                      <c:layout/>
+                    </c:legend>
+                    <c:plotVisOnly val="1"/>
+                  </c:chart>
+                  <c:printSettings>
+                    <c:headerFooter/>
+                    <c:pageMargins b="0.75" l="0.7" r="0.7" t="0.75" header="0.3" footer="0.3"/>
+                    <c:pageSetup/>
+                  </c:printSettings>
+                </c:chartSpace>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_pie_3d() {
+        let mut chart = Chart::new(ChartType::Pie3D);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_value_cache(&["1", "2", "3"], true);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                  <c:lang val="en-US"/>
+                  <c:chart>
+                    <c:view3D>
+                      <c:rotX val="30"/>
+                      <c:rotY val="0"/>
+                      <c:rAngAx val="0"/>
+                    </c:view3D>
+                    <c:plotArea>
+                      <c:layout/>
+                      <c:pie3DChart>
+                        <c:varyColors val="1"/>
+                        <c:ser>
+                          <c:idx val="0"/>
+                          <c:order val="0"/>
+                          <c:val>
+                            <c:numRef>
+                              <c:f>Sheet1!$A$1:$A$3</c:f>
+                              <c:numCache>
+                                <c:formatCode>General</c:formatCode>
+                                <c:ptCount val="3"/>
+                                <c:pt idx="0">
+                                  <c:v>1</c:v>
+                                </c:pt>
+                                <c:pt idx="1">
+                                  <c:v>2</c:v>
+                                </c:pt>
+                                <c:pt idx="2">
+                                  <c:v>3</c:v>
+                                </c:pt>
+                              </c:numCache>
+                            </c:numRef>
+                          </c:val>
+                        </c:ser>
+                      </c:pie3DChart>
+                    </c:plotArea>
+                    <c:legend>
+                      <c:legendPos val="r"/>
+                      <c:layout/>
+                      <c:txPr>
+                        <a:bodyPr/>
+                        <a:lstStyle/>
+                        <a:p>
+                          <a:pPr rtl="0">
+                            <a:defRPr/>
+                          </a:pPr>
+                          <a:endParaRPr lang="en-US"/>
+                        </a:p>
+                      </c:txPr>
+                    </c:legend>
+                    <c:plotVisOnly val="1"/>
+                  </c:chart>
+                  <c:printSettings>
+                    <c:headerFooter/>
+                    <c:pageMargins b="0.75" l="0.7" r="0.7" t="0.75" header="0.3" footer="0.3"/>
+                    <c:pageSetup/>
+                  </c:printSettings>
+                </c:chartSpace>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_3d_charts() {
+        let mut chart = Chart::new(ChartType::Column3D);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.set_axis_ids(100, 101);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<c:view3D><c:rotX val="15"/><c:rotY val="20"/><c:rAngAx val="1"/></c:view3D>"#
+        ));
+        assert!(got.contains(r#"<c:bar3DChart><c:barDir val="col"/><c:grouping val="clustered"/>"#));
+        assert!(got
+            .contains(r#"<c:axId val="100"/><c:axId val="101"/><c:axId val="0"/></c:bar3DChart>"#));
+
+        let mut chart = Chart::new(ChartType::Bar3DStacked);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.set_3d_rotation(-20, 300);
+        chart.set_3d_perspective(40);
+        chart.set_3d_depth(250);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<c:view3D><c:rotX val="-20"/><c:rotY val="300"/><c:depthPercent val="250"/><c:rAngAx val="0"/><c:perspective val="80"/></c:view3D>"#
+        ));
+        assert!(got.contains(r#"<c:bar3DChart><c:barDir val="bar"/><c:grouping val="stacked"/>"#));
+        assert!(!got.contains("<c:overlap"));
+
+        let mut chart = Chart::new(ChartType::Line3D);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.set_axis_ids(100, 101);
+        chart.set_3d_rotation(100, 20);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<c:view3D><c:rotX val="15"/><c:rotY val="20"/><c:rAngAx val="0"/><c:perspective val="30"/></c:view3D>"#
+        ));
+        assert!(got.contains(
+            r#"<c:axId val="100"/><c:axId val="101"/><c:axId val="102"/></c:line3DChart>"#
+        ));
+        assert!(got.contains(r#"<c:serAx><c:axId val="102"/>"#));
+        assert!(got.contains(r#"<c:crossAx val="101"/><c:crosses val="autoZero"/></c:serAx>"#));
+
+        let mut chart = Chart::new(ChartType::Pie3D);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.set_3d_perspective(40);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<c:view3D><c:rotX val="30"/><c:rotY val="0"/><c:rAngAx val="0"/></c:view3D>"#
+        ));
+        assert!(got.contains(r#"<c:pie3DChart><c:varyColors val="1"/>"#));
+        assert!(!got.contains("<c:catAx>"));
+
+        // 2-D charts don't have a 3-D view.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        chart.set_3d_rotation(30, 40);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(!got.contains("<c:view3D>"));
+    }
+
+    #[test]
+    fn test_show_blanks_as() {
+        let mut chart = Chart::new(ChartType::Line);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:plotVisOnly val="1"/></c:chart>"#));
+        assert!(!got.contains("<c:dispBlanksAs"));
+
+        let mut chart = Chart::new(ChartType::Line);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.show_blanks_as(ChartEmptyCells::Span);
+        chart.show_hidden_data(true);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"</c:legend><c:dispBlanksAs val="span"/></c:chart>"#));
+        assert!(!got.contains("<c:plotVisOnly"));
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.show_blanks_as(ChartEmptyCells::Zero);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:plotVisOnly val="1"/><c:dispBlanksAs val="zero"/></c:chart>"#));
+    }
+
+    #[test]
+    fn test_axis_crossing() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart.set_axis_ids(100, 101);
+        chart
+            .x_axis()
+            .set_crossing(ChartAxisCrossing::AxisValue(3.0));
+        chart.y_axis().set_crossing(ChartAxisCrossing::Max);
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:crossAx val="101"/><c:crosses val="max"/><c:auto val="1"/>"#));
+        assert!(got.contains(r#"<c:crossAx val="100"/><c:crossesAt val="3"/><c:crossBetween"#));
+
+        let mut chart = Chart::new(ChartType::Scatter);
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$5")
+            .set_values("Sheet1!$B$1:$B$5");
+        chart.set_axis_ids(100, 101);
+        chart.x_axis().set_crossing(ChartAxisCrossing::Min);
+        chart
+            .y_axis()
+            .set_crossing(ChartAxisCrossing::AxisValue(-1.5));
+
+        chart.assemble_xml_file();
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(r#"<c:crossAx val="101"/><c:crossesAt val="-1.5"/><c:crossBetween"#));
+        assert!(got.contains(r#"<c:crossAx val="100"/><c:crosses val="min"/><c:crossBetween"#));
+    }
+
     #[test]
     fn test_range_from_string() {
         let range_string = "=Sheet1!$A$1:$A$5";
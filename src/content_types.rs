@@ -120,6 +120,25 @@ impl ContentTypes {
         );
     }
 
+    // Add the featurePropertyBag file to the ContentTypes overrides.
+    pub(crate) fn add_feature_property_bag(&mut self) {
+        self.add_override(
+            "/xl/featurePropertyBag/featurePropertyBag.xml",
+            "application/vnd.ms-excel.featurepropertybag+xml",
+        );
+    }
+
+    // Change the workbook content type from a workbook to a template.
+    pub(crate) fn set_template(&mut self) {
+        for (part_name, content_type) in &mut self.overrides {
+            if part_name == "/xl/workbook.xml" {
+                *content_type =
+                    "application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml"
+                        .to_string();
+            }
+        }
+    }
+
     // Add the custom properties to the ContentTypes overrides.
     pub(crate) fn add_custom_properties(&mut self) {
         self.add_override(
@@ -229,4 +248,69 @@ mod tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_template() {
+        let mut content_types = ContentTypes::new();
+
+        content_types.add_worksheet_name(1);
+        content_types.set_template();
+        content_types.assemble_xml_file();
+
+        let got = content_types.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+
+              <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+              <Default Extension="xml" ContentType="application/xml"/>
+
+              <Override PartName="/docProps/app.xml" ContentType="application/vnd.openxmlformats-officedocument.extended-properties+xml"/>
+              <Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>
+              <Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>
+              <Override PartName="/xl/theme/theme1.xml" ContentType="application/vnd.openxmlformats-officedocument.theme+xml"/>
+              <Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml"/>
+              <Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>
+             </Types>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_feature_property_bag() {
+        let mut content_types = ContentTypes::new();
+
+        content_types.add_worksheet_name(1);
+        content_types.add_feature_property_bag();
+        content_types.assemble_xml_file();
+
+        let got = content_types.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+
+              <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+              <Default Extension="xml" ContentType="application/xml"/>
+
+              <Override PartName="/docProps/app.xml" ContentType="application/vnd.openxmlformats-officedocument.extended-properties+xml"/>
+              <Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>
+              <Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>
+              <Override PartName="/xl/theme/theme1.xml" ContentType="application/vnd.openxmlformats-officedocument.theme+xml"/>
+              <Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>
+              <Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>
+              <Override PartName="/xl/featurePropertyBag/featurePropertyBag.xml" ContentType="application/vnd.ms-excel.featurepropertybag+xml"/>
+             </Types>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
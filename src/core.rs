@@ -4,6 +4,8 @@
 //
 // Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
 
+use chrono::Utc;
+
 use crate::{xmlwriter::XMLWriter, DocProperties};
 
 pub struct Core {
@@ -54,11 +56,18 @@ impl Core {
         // Write the cp:lastModifiedBy element.
         self.write_cp_last_modified_by();
 
+        // Use the same time for the created and modified dates.
+        let datetime = self
+            .properties
+            .creation_time
+            .unwrap_or_else(Utc::now)
+            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+
         // Write the dcterms:created element.
-        self.write_dcterms_created();
+        self.write_dcterms_created(&datetime);
 
         // Write the dcterms:modified element.
-        self.write_dcterms_modified();
+        self.write_dcterms_modified(&datetime);
 
         // Write the cp:category element.
         self.write_cp_category();
@@ -66,6 +75,9 @@ impl Core {
         // Write the cp:contentStatus element.
         self.write_cp_content_status();
 
+        // Write the dc:language element.
+        self.write_dc_language();
+
         // Close the coreProperties tag.
         self.writer.xml_end_tag("cp:coreProperties");
     }
@@ -135,28 +147,19 @@ impl Core {
     }
 
     // Write the <dcterms:created> element.
-    fn write_dcterms_created(&mut self) {
+    fn write_dcterms_created(&mut self, datetime: &str) {
         let attributes = [("xsi:type", "dcterms:W3CDTF")];
-        let datetime = self
-            .properties
-            .creation_time
-            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
 
         self.writer
-            .xml_data_element("dcterms:created", &datetime, &attributes);
+            .xml_data_element("dcterms:created", datetime, &attributes);
     }
 
     // Write the <dcterms:modified> element.
-    fn write_dcterms_modified(&mut self) {
+    fn write_dcterms_modified(&mut self, datetime: &str) {
         let attributes = [("xsi:type", "dcterms:W3CDTF")];
 
-        let datetime = self
-            .properties
-            .creation_time
-            .to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
-
         self.writer
-            .xml_data_element("dcterms:modified", &datetime, &attributes);
+            .xml_data_element("dcterms:modified", datetime, &attributes);
     }
 
     // Write the <cp:category> element.
@@ -174,6 +177,14 @@ impl Core {
                 .xml_data_element_only("cp:contentStatus", &self.properties.status);
         }
     }
+
+    // Write the <dc:language> element.
+    fn write_dc_language(&mut self) {
+        if !self.properties.language.is_empty() {
+            self.writer
+                .xml_data_element_only("dc:language", &self.properties.language);
+        }
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -217,4 +228,38 @@ mod tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_with_language() {
+        let date = Utc.with_ymd_and_hms(2010, 1, 1, 0, 0, 0).unwrap();
+        let properties = DocProperties::new()
+            .set_author("A User")
+            .set_status("Final")
+            .set_language("en-US")
+            .set_creation_datetime(&date);
+
+        let mut core = Core::new();
+        core.properties = properties;
+
+        core.assemble_xml_file();
+
+        let got = core.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:dcmitype="http://purl.org/dc/dcmitype/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+              <dc:creator>A User</dc:creator>
+              <cp:lastModifiedBy>A User</cp:lastModifiedBy>
+              <dcterms:created xsi:type="dcterms:W3CDTF">2010-01-01T00:00:00Z</dcterms:created>
+              <dcterms:modified xsi:type="dcterms:W3CDTF">2010-01-01T00:00:00Z</dcterms:modified>
+              <cp:contentStatus>Final</cp:contentStatus>
+              <dc:language>en-US</dc:language>
+            </cp:coreProperties>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -38,8 +38,13 @@ impl Drawing {
         self.write_ws_dr();
 
         for (index, drawing) in self.drawings.clone().iter().enumerate() {
-            // Write the xdr:twoCellAnchor element.
-            self.write_two_cell_anchor((index + 1) as u32, drawing);
+            if drawing.is_absolute {
+                // Write the xdr:absoluteAnchor element.
+                self.write_absolute_anchor((index + 1) as u32, drawing);
+            } else {
+                // Write the xdr:twoCellAnchor element.
+                self.write_two_cell_anchor((index + 1) as u32, drawing);
+            }
         }
 
         // Close the end tag.
@@ -91,6 +96,33 @@ impl Drawing {
         self.writer.xml_end_tag("xdr:twoCellAnchor");
     }
 
+    // Write the <xdr:absoluteAnchor> element.
+    fn write_absolute_anchor(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        self.writer.xml_start_tag_only("xdr:absoluteAnchor");
+
+        // Write the xdr:pos element.
+        let attributes = [
+            ("x", drawing_info.col_absolute.to_string()),
+            ("y", drawing_info.row_absolute.to_string()),
+        ];
+        self.writer.xml_empty_tag("xdr:pos", &attributes);
+
+        // Write the xdr:ext element.
+        let attributes = [
+            ("cx", drawing_info.width.to_string()),
+            ("cy", drawing_info.height.to_string()),
+        ];
+        self.writer.xml_empty_tag("xdr:ext", &attributes);
+
+        match drawing_info.drawing_type {
+            DrawingType::Image => self.write_pic(index, drawing_info),
+            DrawingType::Chart => self.write_graphic_frame(index, drawing_info),
+        }
+
+        self.writer.xml_empty_tag_only("xdr:clientData");
+        self.writer.xml_end_tag("xdr:absoluteAnchor");
+    }
+
     // Write the <xdr:from> element.
     fn write_from(&mut self, coords: &DrawingCoordinates) {
         self.writer.xml_start_tag_only("xdr:from");
@@ -131,7 +163,7 @@ impl Drawing {
         self.write_nv_pic_pr(index, drawing_info);
 
         // Write the xdr:blipFill element.
-        self.write_blip_fill(drawing_info.rel_id);
+        self.write_blip_fill(drawing_info);
 
         // Write the xdr:spPr element.
         self.write_sp_pr(drawing_info);
@@ -165,15 +197,41 @@ impl Drawing {
             attributes.push(("descr", drawing_info.description.clone()));
         }
 
-        if drawing_info.decorative {
+        if drawing_info.decorative || drawing_info.url_rel_id > 0 {
             self.writer.xml_start_tag("xdr:cNvPr", &attributes);
-            self.write_decorative();
+
+            if drawing_info.url_rel_id > 0 {
+                // Write the a:hlinkClick element.
+                self.write_hlink_click(drawing_info);
+            }
+
+            if drawing_info.decorative {
+                self.write_decorative();
+            }
+
             self.writer.xml_end_tag("xdr:cNvPr");
         } else {
             self.writer.xml_empty_tag("xdr:cNvPr", &attributes);
         }
     }
 
+    // Write the <a:hlinkClick> element.
+    fn write_hlink_click(&mut self, drawing_info: &DrawingInfo) {
+        let mut attributes = vec![
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships".to_string(),
+            ),
+            ("r:id", format!("rId{}", drawing_info.url_rel_id)),
+        ];
+
+        if !drawing_info.url_tip.is_empty() {
+            attributes.push(("tooltip", drawing_info.url_tip.clone()));
+        }
+
+        self.writer.xml_empty_tag("a:hlinkClick", &attributes);
+    }
+
     // Write the decorative sub elements.
     fn write_decorative(&mut self) {
         self.writer.xml_start_tag_only("a:extLst");
@@ -216,11 +274,16 @@ impl Drawing {
     }
 
     // Write the <xdr:blipFill> element.
-    fn write_blip_fill(&mut self, index: u32) {
+    fn write_blip_fill(&mut self, drawing_info: &DrawingInfo) {
         self.writer.xml_start_tag_only("xdr:blipFill");
 
         // Write the a:blip element.
-        self.write_a_blip(index);
+        self.write_a_blip(drawing_info.rel_id);
+
+        // Write the a:srcRect element, if the image is cropped.
+        if drawing_info.crop.iter().any(|value| *value > 0.0) {
+            self.write_a_src_rect(&drawing_info.crop);
+        }
 
         self.writer.xml_start_tag_only("a:stretch");
         self.writer.xml_empty_tag_only("a:fillRect");
@@ -242,6 +305,20 @@ impl Drawing {
         self.writer.xml_empty_tag("a:blip", &attributes);
     }
 
+    // Write the <a:srcRect> element. The crop percentages are stored in
+    // thousandths of a percent and zero values are omitted.
+    fn write_a_src_rect(&mut self, crop: &[f64; 4]) {
+        let mut attributes = vec![];
+
+        for (name, value) in ["l", "t", "r", "b"].into_iter().zip(crop) {
+            if *value > 0.0 {
+                attributes.push((name, ((value * 1000.0).round() as u32).to_string()));
+            }
+        }
+
+        self.writer.xml_empty_tag("a:srcRect", &attributes);
+    }
+
     // Write the <xdr:spPr> element.
     fn write_sp_pr(&mut self, drawing_info: &DrawingInfo) {
         self.writer.xml_start_tag_only("xdr:spPr");
@@ -303,7 +380,7 @@ impl Drawing {
         self.write_xfrm();
 
         // Write the a:graphic element.
-        self.write_a_graphic(index);
+        self.write_a_graphic(drawing_info.rel_id);
 
         self.writer.xml_end_tag("xdr:graphicFrame");
     }
@@ -354,17 +431,17 @@ impl Drawing {
     }
 
     // Write the <a:graphic> element.
-    fn write_a_graphic(&mut self, index: u32) {
+    fn write_a_graphic(&mut self, rel_id: u32) {
         self.writer.xml_start_tag_only("a:graphic");
 
         // Write the a:graphicData element.
-        self.write_a_graphic_data(index);
+        self.write_a_graphic_data(rel_id);
 
         self.writer.xml_end_tag("a:graphic");
     }
 
     // Write the <a:graphicData> element.
-    fn write_a_graphic_data(&mut self, index: u32) {
+    fn write_a_graphic_data(&mut self, rel_id: u32) {
         let attributes = [(
             "uri",
             "http://schemas.openxmlformats.org/drawingml/2006/chart",
@@ -373,13 +450,13 @@ impl Drawing {
         self.writer.xml_start_tag("a:graphicData", &attributes);
 
         // Write the c:chart element.
-        self.write_chart(index);
+        self.write_chart(rel_id);
 
         self.writer.xml_end_tag("a:graphicData");
     }
 
     // Write the <c:chart> element.
-    fn write_chart(&mut self, index: u32) {
+    fn write_chart(&mut self, rel_id: u32) {
         let attributes = [
             (
                 "xmlns:c",
@@ -389,7 +466,7 @@ impl Drawing {
                 "xmlns:r",
                 "http://schemas.openxmlformats.org/officeDocument/2006/relationships".to_string(),
             ),
-            ("r:id", format!("rId{index}")),
+            ("r:id", format!("rId{rel_id}")),
         ];
 
         self.writer.xml_empty_tag("c:chart", &attributes);
@@ -419,6 +496,10 @@ pub(crate) struct DrawingInfo {
     pub(crate) decorative: bool,
     pub(crate) object_movement: ObjectMovement,
     pub(crate) rel_id: u32,
+    pub(crate) url_rel_id: u32,
+    pub(crate) url_tip: String,
+    pub(crate) crop: [f64; 4],
+    pub(crate) is_absolute: bool,
     pub(crate) drawing_type: DrawingType,
 }
 
@@ -478,6 +559,400 @@ mod tests {
             description: "rust.png".to_string(),
             decorative: false,
             rel_id: 1,
+            url_rel_id: 0,
+            url_tip: String::new(),
+            crop: [0.0; 4],
+            is_absolute: false,
+            object_movement: ObjectMovement::MoveButDontSizeWithCells,
+            drawing_type: DrawingType::Image,
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor editAs="oneCell">
+                    <xdr:from>
+                    <xdr:col>2</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>1</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>3</xdr:col>
+                    <xdr:colOff>533257</xdr:colOff>
+                    <xdr:row>6</xdr:row>
+                    <xdr:rowOff>190357</xdr:rowOff>
+                    </xdr:to>
+                    <xdr:pic>
+                    <xdr:nvPicPr>
+                        <xdr:cNvPr id="2" name="Picture 1" descr="rust.png"/>
+                        <xdr:cNvPicPr>
+                        <a:picLocks noChangeAspect="1"/>
+                        </xdr:cNvPicPr>
+                    </xdr:nvPicPr>
+                    <xdr:blipFill>
+                        <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                        <a:stretch>
+                        <a:fillRect/>
+                        </a:stretch>
+                    </xdr:blipFill>
+                    <xdr:spPr>
+                        <a:xfrm>
+                        <a:off x="1219200" y="190500"/>
+                        <a:ext cx="1142857" cy="1142857"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                        <a:avLst/>
+                        </a:prstGeom>
+                    </xdr:spPr>
+                    </xdr:pic>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_absolute() {
+        let mut drawing = Drawing::new();
+
+        let coordinates = DrawingCoordinates {
+            col: 0,
+            row: 0,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let drawing_info = DrawingInfo {
+            from: coordinates.clone(),
+            to: coordinates,
+            col_absolute: 952500,
+            row_absolute: 476250,
+            width: 1009650.0,
+            height: 1009650.0,
+            description: "rust.png".to_string(),
+            decorative: false,
+            rel_id: 1,
+            url_rel_id: 0,
+            url_tip: String::new(),
+            crop: [0.0; 4],
+            is_absolute: true,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            drawing_type: DrawingType::Image,
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:absoluteAnchor>
+                    <xdr:pos x="952500" y="476250"/>
+                    <xdr:ext cx="1009650" cy="1009650"/>
+                    <xdr:pic>
+                    <xdr:nvPicPr>
+                        <xdr:cNvPr id="2" name="Picture 1" descr="rust.png"/>
+                        <xdr:cNvPicPr>
+                        <a:picLocks noChangeAspect="1"/>
+                        </xdr:cNvPicPr>
+                    </xdr:nvPicPr>
+                    <xdr:blipFill>
+                        <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                        <a:stretch>
+                        <a:fillRect/>
+                        </a:stretch>
+                    </xdr:blipFill>
+                    <xdr:spPr>
+                        <a:xfrm>
+                        <a:off x="952500" y="476250"/>
+                        <a:ext cx="1009650" cy="1009650"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                        <a:avLst/>
+                        </a:prstGeom>
+                    </xdr:spPr>
+                    </xdr:pic>
+                    <xdr:clientData/>
+                </xdr:absoluteAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_url() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 2,
+            row: 1,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 3,
+            row: 6,
+            col_offset: 533257.0,
+            row_offset: 190357.0,
+        };
+
+        let drawing_info = DrawingInfo {
+            from,
+            to,
+            col_absolute: 1219200,
+            row_absolute: 190500,
+            width: 1142857.0,
+            height: 1142857.0,
+            description: "rust.png".to_string(),
+            decorative: false,
+            rel_id: 2,
+            url_rel_id: 1,
+            url_tip: "Rust".to_string(),
+            crop: [0.0; 4],
+            is_absolute: false,
+            object_movement: ObjectMovement::MoveButDontSizeWithCells,
+            drawing_type: DrawingType::Image,
+        };
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor editAs="oneCell">
+                    <xdr:from>
+                    <xdr:col>2</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>1</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>3</xdr:col>
+                    <xdr:colOff>533257</xdr:colOff>
+                    <xdr:row>6</xdr:row>
+                    <xdr:rowOff>190357</xdr:rowOff>
+                    </xdr:to>
+                    <xdr:pic>
+                    <xdr:nvPicPr>
+                        <xdr:cNvPr id="2" name="Picture 1" descr="rust.png">
+                        <a:hlinkClick xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="rId1" tooltip="Rust"/>
+                        </xdr:cNvPr>
+                        <xdr:cNvPicPr>
+                        <a:picLocks noChangeAspect="1"/>
+                        </xdr:cNvPicPr>
+                    </xdr:nvPicPr>
+                    <xdr:blipFill>
+                        <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId2"/>
+                        <a:stretch>
+                        <a:fillRect/>
+                        </a:stretch>
+                    </xdr:blipFill>
+                    <xdr:spPr>
+                        <a:xfrm>
+                        <a:off x="1219200" y="190500"/>
+                        <a:ext cx="1142857" cy="1142857"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                        <a:avLst/>
+                        </a:prstGeom>
+                    </xdr:spPr>
+                    </xdr:pic>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_mixed_anchors() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 1,
+            row: 1,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 1,
+            row: 2,
+            col_offset: 304800.0,
+            row_offset: 114300.0,
+        };
+
+        let mut drawing_info = DrawingInfo {
+            from: from.clone(),
+            to,
+            col_absolute: 609600,
+            row_absolute: 190500,
+            width: 304800.0,
+            height: 304800.0,
+            description: String::new(),
+            decorative: false,
+            rel_id: 1,
+            url_rel_id: 0,
+            url_tip: String::new(),
+            crop: [0.0; 4],
+            is_absolute: false,
+            object_movement: ObjectMovement::MoveButDontSizeWithCells,
+            drawing_type: DrawingType::Image,
+        };
+
+        drawing.drawings.push(drawing_info.clone());
+
+        // The same image at an absolute position, using the same rel id.
+        drawing_info.from = from.clone();
+        drawing_info.to = from;
+        drawing_info.col_absolute = 952500;
+        drawing_info.row_absolute = 476250;
+        drawing_info.is_absolute = true;
+
+        drawing.drawings.push(drawing_info);
+
+        drawing.assemble_xml_file();
+
+        let got = drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor editAs="oneCell">
+                    <xdr:from>
+                    <xdr:col>1</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>1</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>1</xdr:col>
+                    <xdr:colOff>304800</xdr:colOff>
+                    <xdr:row>2</xdr:row>
+                    <xdr:rowOff>114300</xdr:rowOff>
+                    </xdr:to>
+                    <xdr:pic>
+                    <xdr:nvPicPr>
+                        <xdr:cNvPr id="2" name="Picture 1"/>
+                        <xdr:cNvPicPr>
+                        <a:picLocks noChangeAspect="1"/>
+                        </xdr:cNvPicPr>
+                    </xdr:nvPicPr>
+                    <xdr:blipFill>
+                        <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                        <a:stretch>
+                        <a:fillRect/>
+                        </a:stretch>
+                    </xdr:blipFill>
+                    <xdr:spPr>
+                        <a:xfrm>
+                        <a:off x="609600" y="190500"/>
+                        <a:ext cx="304800" cy="304800"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                        <a:avLst/>
+                        </a:prstGeom>
+                    </xdr:spPr>
+                    </xdr:pic>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                <xdr:absoluteAnchor>
+                    <xdr:pos x="952500" y="476250"/>
+                    <xdr:ext cx="304800" cy="304800"/>
+                    <xdr:pic>
+                    <xdr:nvPicPr>
+                        <xdr:cNvPr id="3" name="Picture 2"/>
+                        <xdr:cNvPicPr>
+                        <a:picLocks noChangeAspect="1"/>
+                        </xdr:cNvPicPr>
+                    </xdr:nvPicPr>
+                    <xdr:blipFill>
+                        <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                        <a:stretch>
+                        <a:fillRect/>
+                        </a:stretch>
+                    </xdr:blipFill>
+                    <xdr:spPr>
+                        <a:xfrm>
+                        <a:off x="952500" y="476250"/>
+                        <a:ext cx="304800" cy="304800"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                        <a:avLst/>
+                        </a:prstGeom>
+                    </xdr:spPr>
+                    </xdr:pic>
+                    <xdr:clientData/>
+                </xdr:absoluteAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_crop() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 2,
+            row: 1,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 3,
+            row: 6,
+            col_offset: 533257.0,
+            row_offset: 190357.0,
+        };
+
+        let drawing_info = DrawingInfo {
+            from,
+            to,
+            col_absolute: 1219200,
+            row_absolute: 190500,
+            width: 1142857.0,
+            height: 1142857.0,
+            description: "rust.png".to_string(),
+            decorative: false,
+            rel_id: 1,
+            url_rel_id: 0,
+            url_tip: String::new(),
+            crop: [0.0, 12.5, 50.0, 0.0],
+            is_absolute: false,
             object_movement: ObjectMovement::MoveButDontSizeWithCells,
             drawing_type: DrawingType::Image,
         };
@@ -515,6 +990,7 @@ mod tests {
                     </xdr:nvPicPr>
                     <xdr:blipFill>
                         <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                        <a:srcRect t="12500" r="50000"/>
                         <a:stretch>
                         <a:fillRect/>
                         </a:stretch>
@@ -41,8 +41,10 @@ pub enum XlsxError {
     /// Worksheet name cannot be "History"
     SheetnameReserved,
 
-    /// String exceeds Excel's limit of 32,767 characters.
-    MaxStringLengthExceeded,
+    /// String exceeds Excel's limit of 32,767 characters. The error contains
+    /// the location of the cell that the string was written to, such as
+    /// `Sheet1!C12345`.
+    MaxStringLengthExceeded(String),
 
     /// Error when trying to retrieve a worksheet reference by index or by name.
     UnknownWorksheetNameOrIndex(String),
@@ -56,20 +58,31 @@ pub enum XlsxError {
     /// The table range overlaps a previous table range.
     TableRangeOverlaps(String, String),
 
-    /// URL string exceeds Excel's url of 2080 characters.
-    MaxUrlLengthExceeded,
+    /// URL string exceeds Excel's url of 2080 characters. The error contains
+    /// the location of the cell that the url was written to.
+    MaxUrlLengthExceeded(String),
+
+    /// The worksheet exceeds Excel's limit of 65,530 hyperlinks. The error
+    /// contains the location of the cell that the url was written to.
+    MaxHyperlinksExceeded(String),
 
     /// Unknown url type. The URL/URIs supported by Excel are `http://`,
     /// `https://`, `ftp://`, `ftps://`, `mailto:`, `file://` and the
     /// pseudo-uri `internal:`:
     UnknownUrlType(String),
 
-    /// Unknown image type. The supported image formats are PNG, JPG, GIF and BMP.
+    /// Unknown image type. The supported image formats are PNG, JPG, GIF, BMP
+    /// and TIFF.
     UnknownImageType,
 
     /// Image has 0 width or height, or the dimensions couldn't be read.
     ImageDimensionError,
 
+    /// The image couldn't be decoded or re-encoded when it was recompressed.
+    /// Requires the `image-compression` feature.
+    #[cfg(feature = "image-compression")]
+    ImageCompressionError(String),
+
     /// A general error that is raised when a chart parameter is incorrect or a
     /// chart is configured incorrectly.
     ChartError(String),
@@ -145,8 +158,11 @@ impl fmt::Display for XlsxError {
                 )
             }
 
-            XlsxError::MaxStringLengthExceeded => {
-                write!(f, "String exceeds Excel's limit of 32,767 characters.")
+            XlsxError::MaxStringLengthExceeded(location) => {
+                write!(
+                    f,
+                    "String at {location} exceeds Excel's limit of 32,767 characters."
+                )
             }
 
             XlsxError::UnknownWorksheetNameOrIndex(name) => {
@@ -171,8 +187,18 @@ impl fmt::Display for XlsxError {
                 )
             }
 
-            XlsxError::MaxUrlLengthExceeded => {
-                write!(f, "URL string exceeds Excel's limit of 2083 characters.")
+            XlsxError::MaxUrlLengthExceeded(location) => {
+                write!(
+                    f,
+                    "URL string at {location} exceeds Excel's limit of 2083 characters."
+                )
+            }
+
+            XlsxError::MaxHyperlinksExceeded(location) => {
+                write!(
+                    f,
+                    "URL at {location} exceeds Excel's limit of 65,530 hyperlinks per worksheet."
+                )
             }
 
             XlsxError::UnknownUrlType(url) => {
@@ -187,6 +213,11 @@ impl fmt::Display for XlsxError {
                 write!(f, "Image with or height couldn't be read from file.")
             }
 
+            #[cfg(feature = "image-compression")]
+            XlsxError::ImageCompressionError(error) => {
+                write!(f, "Image compression error: \"{error}\".")
+            }
+
             XlsxError::ChartError(error) => {
                 write!(f, "Chart error: \"{error}\".")
             }
@@ -231,7 +262,7 @@ impl From<std::io::Error> for XlsxError {
 #[cfg(test)]
 mod tests {
 
-    use std::io::{Error, ErrorKind};
+    use std::io::Error;
 
     use crate::XlsxError;
     use pretty_assertions::assert_eq;
@@ -270,8 +301,16 @@ mod tests {
             "Worksheet name \"ERROR\" cannot start or end with an apostrophe."
         );
         assert_eq!(
-            XlsxError::MaxStringLengthExceeded.to_string(),
-            "String exceeds Excel's limit of 32,767 characters."
+            XlsxError::MaxStringLengthExceeded("Sheet1!A1".to_string()).to_string(),
+            "String at Sheet1!A1 exceeds Excel's limit of 32,767 characters."
+        );
+        assert_eq!(
+            XlsxError::MaxUrlLengthExceeded("Sheet1!A1".to_string()).to_string(),
+            "URL string at Sheet1!A1 exceeds Excel's limit of 2083 characters."
+        );
+        assert_eq!(
+            XlsxError::MaxHyperlinksExceeded("Sheet1!A1".to_string()).to_string(),
+            "URL at Sheet1!A1 exceeds Excel's limit of 65,530 hyperlinks per worksheet."
         );
         assert_eq!(
             XlsxError::UnknownWorksheetNameOrIndex(name.to_string()).to_string(),
@@ -287,7 +326,7 @@ mod tests {
         );
 
         assert_eq!(
-            XlsxError::IoError(Error::new(ErrorKind::Other, "ERROR")).to_string(),
+            XlsxError::IoError(Error::other("ERROR")).to_string(),
             "ERROR"
         );
         assert_eq!(
@@ -322,6 +361,6 @@ mod tests {
     }
 
     fn throw_io_error() -> Result<(), std::io::Error> {
-        Err(Error::new(ErrorKind::Other, "ERROR"))
+        Err(Error::other("ERROR"))
     }
 }
@@ -0,0 +1,151 @@
+// feature_property_bag - A module for creating the Excel featurePropertyBag.xml
+// file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+use crate::xmlwriter::XMLWriter;
+
+pub struct FeaturePropertyBag {
+    pub(crate) writer: XMLWriter,
+}
+
+impl FeaturePropertyBag {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new FeaturePropertyBag struct.
+    pub(crate) fn new() -> FeaturePropertyBag {
+        let writer = XMLWriter::new();
+
+        FeaturePropertyBag { writer }
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    //  Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the FeaturePropertyBags element.
+        self.write_feature_property_bags();
+
+        // Write the Checkbox bag element.
+        self.write_checkbox_bag();
+
+        // Write the XFControls bag element.
+        self.write_xf_control_bag();
+
+        // Write the XFComplement bag element.
+        self.write_xf_complement_bag();
+
+        // Write the XFComplements bag element.
+        self.write_xf_complements_bag();
+
+        // Close the FeaturePropertyBags tag.
+        self.writer.xml_end_tag("FeaturePropertyBags");
+    }
+
+    // Write the <FeaturePropertyBags> element.
+    fn write_feature_property_bags(&mut self) {
+        let attributes = [(
+            "xmlns",
+            "http://schemas.microsoft.com/office/spreadsheetml/2022/featurepropertybag",
+        )];
+
+        self.writer
+            .xml_start_tag("FeaturePropertyBags", &attributes);
+    }
+
+    // Write the Checkbox <bag> element. This is bag 0.
+    fn write_checkbox_bag(&mut self) {
+        let attributes = [("type", "Checkbox")];
+
+        self.writer.xml_empty_tag("bag", &attributes);
+    }
+
+    // Write the XFControls <bag> element. This is bag 1 and refers to the
+    // Checkbox bag.
+    fn write_xf_control_bag(&mut self) {
+        let attributes = [("type", "XFControls")];
+
+        self.writer.xml_start_tag("bag", &attributes);
+        self.writer
+            .xml_data_element("bagId", "0", &[("k", "CellControl")]);
+        self.writer.xml_end_tag("bag");
+    }
+
+    // Write the XFComplement <bag> element. This is bag 2 and refers to the
+    // XFControls bag.
+    fn write_xf_complement_bag(&mut self) {
+        let attributes = [("type", "XFComplement")];
+
+        self.writer.xml_start_tag("bag", &attributes);
+        self.writer
+            .xml_data_element("bagId", "1", &[("k", "XFControls")]);
+        self.writer.xml_end_tag("bag");
+    }
+
+    // Write the XFComplements <bag> element. This maps the styles.xml
+    // xfComplement index to the XFComplement bag.
+    fn write_xf_complements_bag(&mut self) {
+        let attributes = [
+            ("type", "XFComplements"),
+            ("extRef", "XFComplementsMapperExtRef"),
+        ];
+
+        self.writer.xml_start_tag("bag", &attributes);
+        self.writer
+            .xml_start_tag("a", &[("k", "MappedFeaturePropertyBags")]);
+        self.writer.xml_data_element_only("bagId", "2");
+        self.writer.xml_end_tag("a");
+        self.writer.xml_end_tag("bag");
+    }
+}
+
+// -----------------------------------------------------------------------
+// Tests.
+// -----------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+
+    use crate::feature_property_bag::FeaturePropertyBag;
+    use crate::test_functions::xml_to_vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble() {
+        let mut feature_property_bag = FeaturePropertyBag::new();
+
+        feature_property_bag.assemble_xml_file();
+
+        let got = feature_property_bag.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <FeaturePropertyBags xmlns="http://schemas.microsoft.com/office/spreadsheetml/2022/featurepropertybag">
+              <bag type="Checkbox"/>
+              <bag type="XFControls">
+                <bagId k="CellControl">0</bagId>
+              </bag>
+              <bag type="XFComplement">
+                <bagId k="XFControls">1</bagId>
+              </bag>
+              <bag type="XFComplements" extRef="XFComplementsMapperExtRef">
+                <a k="MappedFeaturePropertyBags">
+                  <bagId>2</bagId>
+                </a>
+              </bag>
+            </FeaturePropertyBags>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+}
@@ -504,8 +504,9 @@ impl FilterCondition {
     /// Add a filter condition to a list filter to show Blank cells. For
     /// autofilters Excel treats empty or whitespace only cells as "Blank".
     ///
-    /// Filtering non-blanks can be done in two ways. See the second example
-    /// below.
+    /// To filter non-blank cells use
+    /// [`add_list_non_blanks_filter()`](FilterCondition::add_list_non_blanks_filter)
+    /// instead.
     ///
     /// # Examples
     ///
@@ -627,6 +628,72 @@ impl FilterCondition {
         self
     }
 
+    /// Add a filter to filter on Non-blanks.
+    ///
+    /// Add a filter condition to show all cells that aren't "Blank", which is
+    /// equivalent to unchecking "(Blanks)" in an Excel autofilter list. As with
+    /// [`add_list_blanks_filter()`](FilterCondition::add_list_blanks_filter)
+    /// empty or whitespace only cells are treated as "Blank".
+    ///
+    /// Excel stores this condition as a custom filter of `!= " "` so this
+    /// method is equivalent to `add_custom_filter(FilterCriteria::NotEqualTo,
+    /// " ")` and it replaces any other conditions in the filter.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting an autofilter to show all
+    /// the non-blank cells in a column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_filter_column8.rs
+    /// #
+    /// # use rust_xlsxwriter::{FilterCondition, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet with some sample data to filter.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_string(0, 0, "Region")?;
+    /// #     worksheet.write_string(1, 0, "")?;
+    /// #     worksheet.write_string(2, 0, "West")?;
+    /// #     worksheet.write_string(3, 0, "East")?;
+    /// #     worksheet.write_string(4, 0, "")?;
+    /// #     worksheet.write_string(5, 0, "")?;
+    /// #     worksheet.write_string(6, 0, "West")?;
+    /// #
+    /// #     worksheet.write_string(0, 1, "Sales")?;
+    /// #     worksheet.write_number(1, 1, 3000)?;
+    /// #     worksheet.write_number(2, 1, 8000)?;
+    /// #     worksheet.write_number(3, 1, 5000)?;
+    /// #     worksheet.write_number(4, 1, 4000)?;
+    /// #     worksheet.write_number(5, 1, 7000)?;
+    /// #     worksheet.write_number(6, 1, 9000)?;
+    /// #
+    /// #     // Set the autofilter.
+    /// #     worksheet.autofilter(0, 0, 6, 1)?;
+    /// #
+    ///     // Set a filter condition to only show non-blank cells.
+    ///     let filter_condition = FilterCondition::new().add_list_non_blanks_filter();
+    ///
+    ///     worksheet.filter_column(0, &filter_condition)?;
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_list_non_blanks_filter(mut self) -> FilterCondition {
+        self.list.clear();
+        self.should_match_blanks = false;
+        self.custom1 = Some(" ".new_filter_data(FilterCriteria::NotEqualTo));
+        self.custom2 = None;
+        self.apply_logical_or = true;
+        self.is_list_filter = false;
+        self
+    }
+
     /// Add a custom filter condition.
     ///
     /// Add a custom filter to a column in an autofilter range. Excel only
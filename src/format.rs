@@ -443,6 +443,7 @@ pub struct Format {
 
     // Non-UI properties.
     pub(crate) quote_prefix: bool,
+    pub(crate) checkbox: bool,
 }
 
 impl Hash for Format {
@@ -457,6 +458,7 @@ impl Hash for Format {
         self.hidden.hash(state);
         self.locked.hash(state);
         self.quote_prefix.hash(state);
+        self.checkbox.hash(state);
     }
 }
 
@@ -471,6 +473,7 @@ impl PartialEq for Format {
             && self.hidden == other.hidden
             && self.locked == other.locked
             && self.quote_prefix == other.quote_prefix
+            && self.checkbox == other.checkbox
     }
 }
 
@@ -522,6 +525,7 @@ impl Format {
             num_format: String::new(),
             num_format_index: 0,
             quote_prefix: false,
+            checkbox: false,
         }
     }
 
@@ -2147,6 +2151,48 @@ impl Format {
         self
     }
 
+    /// Set the checkbox property for a Format.
+    ///
+    /// Display boolean values in cells with this format as checkboxes. This
+    /// uses the checkbox cell control that was added to Excel 365 in 2024.
+    /// Clicking the checkbox in Excel toggles the `TRUE`/`FALSE` value of the
+    /// cell. Older versions of Excel ignore the property and show the boolean
+    /// value.
+    ///
+    /// See also
+    /// [`worksheet.insert_checkbox()`](crate::Worksheet::insert_checkbox).
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing boolean values as
+    /// checkboxes.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_format_set_checkbox.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format = Format::new().set_checkbox();
+    ///
+    ///     worksheet.write_boolean_with_format(0, 0, true, &format)?;
+    ///     worksheet.write_boolean_with_format(1, 0, false, &format)?;
+    ///
+    /// #     workbook.save("formats.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_checkbox(mut self) -> Format {
+        self.checkbox = true;
+        self
+    }
+
     /// Unset the bold Format property back to its default "off" state.
     /// The opposite of [`set_bold()`](Format::set_bold()).
     pub fn unset_bold(mut self) -> Format {
@@ -2209,6 +2255,13 @@ impl Format {
         self.quote_prefix = false;
         self
     }
+
+    /// Unset the checkbox Format property back to its default "off" state.
+    /// The opposite of [`set_checkbox()`](Format::set_checkbox()).
+    pub fn unset_checkbox(mut self) -> Format {
+        self.checkbox = false;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
@@ -550,8 +550,6 @@ use std::borrow::Cow;
 ///
 /// [MS XLSX extensions documentation on future functions]:
 ///     http://msdn.microsoft.com/en-us/library/dd907480%28v=office.12%29.aspx
-///
-
 ///
 /// | Future Functions                 |
 /// | -------------------------------- |
@@ -685,12 +683,10 @@ use std::borrow::Cow;
 /// | `_xlfn.XOR`                      |
 /// | `_xlfn.Z.TEST`                   |
 ///
-
 /// The dynamic array functions shown in the [Dynamic Array
 /// support](dynamic_arrays.md) section are also future functions, however the
 /// `rust_xlsxwriter` library automatically adds the required prefixes on the
 /// fly so you don't have to add them explicitly.
-
 ///
 /// | Dynamic Array Functions          |
 /// | -------------------------------- |
@@ -724,7 +720,6 @@ use std::borrow::Cow;
 /// | `_xlfn.WRAPROWS`                 |
 /// | `_xlfn.XLOOKUP`                  |
 ///
-
 /// # Dealing with formula errors
 ///
 /// If there is an error in the syntax of a formula it is usually displayed in
@@ -984,7 +979,7 @@ impl Formula {
     }
 
     // Escape/expand the dynamic formula _xlfn functions.
-    fn escape_dynamic_formulas1(formula: &str) -> Cow<str> {
+    fn escape_dynamic_formulas1(formula: &str) -> Cow<'_, str> {
         lazy_static! {
             static ref XLFN: Regex = Regex::new(
                 r"\b(ANCHORARRAY|BYCOL|BYROW|CHOOSECOLS|CHOOSEROWS|DROP|EXPAND|HSTACK|LAMBDA|MAKEARRAY|MAP|RANDARRAY|REDUCE|SCAN|SEQUENCE|SINGLE|SORTBY|SWITCH|TAKE|TEXTSPLIT|TOCOL|TOROW|UNIQUE|VSTACK|WRAPCOLS|WRAPROWS|XLOOKUP)\("
@@ -995,7 +990,7 @@ impl Formula {
     }
 
     // Escape/expand the dynamic formula _xlfn._xlws. functions.
-    fn escape_dynamic_formulas2(formula: &str) -> Cow<str> {
+    fn escape_dynamic_formulas2(formula: &str) -> Cow<'_, str> {
         lazy_static! {
             static ref XLWS: Regex = Regex::new(r"\b(FILTER|SORT)\(").unwrap();
         }
@@ -1003,7 +998,7 @@ impl Formula {
     }
 
     // Escape/expand future/_xlfn functions.
-    fn escape_future_functions(formula: &str) -> Cow<str> {
+    fn escape_future_functions(formula: &str) -> Cow<'_, str> {
         lazy_static! {
             static ref FUTURE: Regex = Regex::new(
                 r"\b(ACOTH|ACOT|AGGREGATE|ARABIC|ARRAYTOTEXT|BASE|BETA.DIST|BETA.INV|BINOM.DIST.RANGE|BINOM.DIST|BINOM.INV|BITAND|BITLSHIFT|BITOR|BITRSHIFT|BITXOR|CEILING.MATH|CEILING.PRECISE|CHISQ.DIST.RT|CHISQ.DIST|CHISQ.INV.RT|CHISQ.INV|CHISQ.TEST|COMBINA|CONCAT|CONFIDENCE.NORM|CONFIDENCE.T|COTH|COT|COVARIANCE.P|COVARIANCE.S|CSCH|CSC|DAYS|DECIMAL|ERF.PRECISE|ERFC.PRECISE|EXPON.DIST|F.DIST.RT|F.DIST|F.INV.RT|F.INV|F.TEST|FILTERXML|FLOOR.MATH|FLOOR.PRECISE|FORECAST.ETS.CONFINT|FORECAST.ETS.SEASONALITY|FORECAST.ETS.STAT|FORECAST.ETS|FORECAST.LINEAR|FORMULATEXT|GAMMA.DIST|GAMMA.INV|GAMMALN.PRECISE|GAMMA|GAUSS|HYPGEOM.DIST|IFNA|IFS|IMAGE|IMCOSH|IMCOT|IMCSCH|IMCSC|IMSECH|IMSEC|IMSINH|IMTAN|ISFORMULA|ISOMITTED|ISOWEEKNUM|LET|LOGNORM.DIST|LOGNORM.INV|MAXIFS|MINIFS|MODE.MULT|MODE.SNGL|MUNIT|NEGBINOM.DIST|NORM.DIST|NORM.INV|NORM.S.DIST|NORM.S.INV|NUMBERVALUE|PDURATION|PERCENTILE.EXC|PERCENTILE.INC|PERCENTRANK.EXC|PERCENTRANK.INC|PERMUTATIONA|PHI|POISSON.DIST|QUARTILE.EXC|QUARTILE.INC|QUERYSTRING|RANK.AVG|RANK.EQ|RRI|SECH|SEC|SHEETS|SHEET|SKEW.P|STDEV.P|STDEV.S|T.DIST.2T|T.DIST.RT|T.DIST|T.INV.2T|T.INV|T.TEST|TEXTAFTER|TEXTBEFORE|TEXTJOIN|UNICHAR|UNICODE|VALUETOTEXT|VAR.P|VAR.S|WEBSERVICE|WEIBULL.DIST|XMATCH|XOR|Z.TEST)\("
@@ -1014,7 +1009,7 @@ impl Formula {
     }
 
     // Escape/expand table functions.
-    fn escape_table_functions(formula: &str) -> Cow<str> {
+    fn escape_table_functions(formula: &str) -> Cow<'_, str> {
         // Convert Excel 2010 "@" table ref to 2007 "#This Row".
         lazy_static! {
             static ref TABLE: Regex = Regex::new(r"@").unwrap();
@@ -0,0 +1,505 @@
+// header_footer - A module for building worksheet header/footer strings.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+// The default font size for headers and footers, which is the size of the
+// default workbook font.
+const DEFAULT_FONT_SIZE: u8 = 11;
+
+/// The `HeaderFooter` struct is used to build a worksheet header or footer
+/// string.
+///
+/// Excel headers and footers are stored as strings with embedded `&` control
+/// codes, see [`worksheet.set_header()`](crate::Worksheet::set_header) for
+/// details. These can be fiddly to get right by hand, so the `HeaderFooter`
+/// struct provides a type-safe way to build them from text and
+/// [`HeaderFooterField`] values, which are then converted to the control
+/// string when passed to [`set_header()`](crate::Worksheet::set_header) or
+/// [`set_footer()`](crate::Worksheet::set_footer).
+///
+/// Elements are added to the left, center or right sections of the header or
+/// footer. Literal ampersands in the text are escaped automatically.
+///
+/// # Examples
+///
+/// The following example demonstrates setting a worksheet header and footer
+/// using the `HeaderFooter` builder.
+///
+/// ```
+/// # // This code is available in examples/doc_header_footer_intro.rs
+/// #
+/// # use rust_xlsxwriter::{
+/// #     HeaderFooter, HeaderFooterField, HeaderFooterText, Workbook, XlsxError,
+/// # };
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #
+/// #     // Add a worksheet to the workbook.
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // Equivalent to "&L&BConfidential&B&CPage &P of &N".
+///     let header = HeaderFooter::new()
+///         .add_left(HeaderFooterText::new("Confidential").set_bold())
+///         .add_center("Page ")
+///         .add_center(HeaderFooterField::PageNumber)
+///         .add_center(" of ")
+///         .add_center(HeaderFooterField::TotalPages);
+///
+///     // Equivalent to "&RR&&D Department".
+///     let footer = HeaderFooter::new().add_right("R&D Department");
+///
+///     worksheet.set_header(&header);
+///     worksheet.set_footer(&footer);
+///
+/// #     worksheet.write_string(0, 0, "Hello")?;
+/// #     worksheet.write_string(200, 0, "Hello")?;
+/// #     worksheet.set_view_page_layout();
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct HeaderFooter {
+    left: Vec<HeaderFooterText>,
+    center: Vec<HeaderFooterText>,
+    right: Vec<HeaderFooterText>,
+}
+
+impl HeaderFooter {
+    /// Create a new, empty, `HeaderFooter` struct.
+    pub fn new() -> HeaderFooter {
+        HeaderFooter::default()
+    }
+
+    /// Add an element to the left section of the header or footer.
+    ///
+    /// This method can be called multiple times to add text and fields to the
+    /// section in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - A string like type, a [`HeaderFooterField`] or a
+    ///   formatted [`HeaderFooterText`].
+    ///
+    pub fn add_left(mut self, element: impl Into<HeaderFooterText>) -> HeaderFooter {
+        self.left.push(element.into());
+        self
+    }
+
+    /// Add an element to the center section of the header or footer.
+    ///
+    /// See [`add_left()`](HeaderFooter::add_left) for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - A string like type, a [`HeaderFooterField`] or a
+    ///   formatted [`HeaderFooterText`].
+    ///
+    pub fn add_center(mut self, element: impl Into<HeaderFooterText>) -> HeaderFooter {
+        self.center.push(element.into());
+        self
+    }
+
+    /// Add an element to the right section of the header or footer.
+    ///
+    /// See [`add_left()`](HeaderFooter::add_left) for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `element` - A string like type, a [`HeaderFooterField`] or a
+    ///   formatted [`HeaderFooterText`].
+    ///
+    pub fn add_right(mut self, element: impl Into<HeaderFooterText>) -> HeaderFooter {
+        self.right.push(element.into());
+        self
+    }
+
+    // Convert the sections to an Excel header/footer control string.
+    pub(crate) fn to_control_string(&self) -> String {
+        let mut string = String::new();
+
+        for (code, section) in [
+            ("&L", &self.left),
+            ("&C", &self.center),
+            ("&R", &self.right),
+        ] {
+            if section.is_empty() {
+                continue;
+            }
+
+            string.push_str(code);
+            for element in section {
+                string.push_str(&element.to_control_string());
+            }
+        }
+
+        string
+    }
+}
+
+impl From<&HeaderFooter> for String {
+    fn from(value: &HeaderFooter) -> String {
+        value.to_control_string()
+    }
+}
+
+impl From<HeaderFooter> for String {
+    fn from(value: HeaderFooter) -> String {
+        value.to_control_string()
+    }
+}
+
+/// The `HeaderFooterText` struct represents a formatted element of a
+/// [`HeaderFooter`] section.
+///
+/// An element is either literal text or a [`HeaderFooterField`] such as the
+/// page number. Plain strings and fields can be passed directly to the
+/// [`HeaderFooter`] methods. `HeaderFooterText` is only needed to apply font
+/// properties to them:
+///
+/// ```
+/// # use rust_xlsxwriter::{HeaderFooter, HeaderFooterField, HeaderFooterText};
+/// #
+/// let footer = HeaderFooter::new()
+///     .add_center(HeaderFooterText::from(HeaderFooterField::SheetName).set_italic());
+///
+/// assert_eq!(String::from(&footer), "&C&I&A&I");
+/// ```
+///
+/// The font properties only apply to the element. Any following elements in
+/// the same section use the default header/footer font.
+///
+#[derive(Clone)]
+pub struct HeaderFooterText {
+    content: HeaderFooterContent,
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+    font_name: String,
+    font_size: u8,
+}
+
+impl HeaderFooterText {
+    /// Create a new `HeaderFooterText` struct from a literal string.
+    pub fn new(text: impl Into<String>) -> HeaderFooterText {
+        HeaderFooterText::from_content(HeaderFooterContent::Text(text.into()))
+    }
+
+    /// Set the element to bold.
+    pub fn set_bold(mut self) -> HeaderFooterText {
+        self.bold = true;
+        self
+    }
+
+    /// Set the element to italic.
+    pub fn set_italic(mut self) -> HeaderFooterText {
+        self.italic = true;
+        self
+    }
+
+    /// Set the element to underlined.
+    pub fn set_underline(mut self) -> HeaderFooterText {
+        self.underline = true;
+        self
+    }
+
+    /// Set the element to strikethrough.
+    pub fn set_strikethrough(mut self) -> HeaderFooterText {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Set the font name for the element.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_name` - The font name, such as "Courier New".
+    ///
+    pub fn set_font_name(mut self, font_name: impl Into<String>) -> HeaderFooterText {
+        self.font_name = font_name.into();
+        self
+    }
+
+    /// Set the font size for the element.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_size` - The font size in points. A value of 0 is ignored.
+    ///
+    pub fn set_font_size(mut self, font_size: u8) -> HeaderFooterText {
+        self.font_size = font_size;
+        self
+    }
+
+    // Create an unformatted element.
+    fn from_content(content: HeaderFooterContent) -> HeaderFooterText {
+        HeaderFooterText {
+            content,
+            bold: false,
+            italic: false,
+            underline: false,
+            strikethrough: false,
+            font_name: String::new(),
+            font_size: 0,
+        }
+    }
+
+    // Convert the element to an Excel header/footer control string.
+    fn to_control_string(&self) -> String {
+        let mut string = String::new();
+
+        // The bold and italic properties are part of the font style when there
+        // is a font name. Otherwise they are written as toggles.
+        let font_style = match (self.bold, self.italic) {
+            (true, true) => "Bold Italic",
+            (true, false) => "Bold",
+            (false, true) => "Italic",
+            (false, false) => "Regular",
+        };
+
+        if !self.font_name.is_empty() {
+            string.push_str(&format!("&\"{},{font_style}\"", self.font_name));
+        }
+
+        if self.font_size > 0 {
+            string.push_str(&format!("&{}", self.font_size));
+        }
+
+        // The on/off toggles, applied before and after the element.
+        let mut toggles = String::new();
+        if self.bold && self.font_name.is_empty() {
+            toggles.push_str("&B");
+        }
+        if self.italic && self.font_name.is_empty() {
+            toggles.push_str("&I");
+        }
+        if self.underline {
+            toggles.push_str("&U");
+        }
+        if self.strikethrough {
+            toggles.push_str("&S");
+        }
+
+        let content = match &self.content {
+            HeaderFooterContent::Text(text) => text.replace('&', "&&"),
+            HeaderFooterContent::Field(field) => field.code().to_string(),
+        };
+
+        // Excel would read digits following a font size as part of the size,
+        // so separate them with a space, as Excel does.
+        if self.font_size > 0
+            && toggles.is_empty()
+            && content.starts_with(|c: char| c.is_ascii_digit())
+        {
+            string.push(' ');
+        }
+
+        string.push_str(&toggles);
+        string.push_str(&content);
+        string.push_str(&toggles);
+
+        // Restore the default font after the element so that it doesn't carry
+        // over to the following elements. The size is written first so that
+        // it can't run into any digits in the next element.
+        if self.font_size > 0 {
+            string.push_str(&format!("&{DEFAULT_FONT_SIZE}"));
+        }
+
+        if !self.font_name.is_empty() || self.font_size > 0 {
+            string.push_str("&\"-,Regular\"");
+        }
+
+        string
+    }
+}
+
+impl From<&str> for HeaderFooterText {
+    fn from(value: &str) -> HeaderFooterText {
+        HeaderFooterText::new(value)
+    }
+}
+
+impl From<String> for HeaderFooterText {
+    fn from(value: String) -> HeaderFooterText {
+        HeaderFooterText::new(value)
+    }
+}
+
+impl From<HeaderFooterField> for HeaderFooterText {
+    fn from(value: HeaderFooterField) -> HeaderFooterText {
+        HeaderFooterText::from_content(HeaderFooterContent::Field(value))
+    }
+}
+
+/// The `HeaderFooterField` enum defines the variable fields that can be added
+/// to a [`HeaderFooter`].
+///
+/// Excel updates the fields when the worksheet is printed or displayed in Page
+/// Layout view.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderFooterField {
+    /// The current page number, `&[Page]`.
+    PageNumber,
+
+    /// The total number of pages, `&[Pages]`.
+    TotalPages,
+
+    /// The current date, `&[Date]`.
+    Date,
+
+    /// The current time, `&[Time]`.
+    Time,
+
+    /// The workbook file name, `&[File]`.
+    FileName,
+
+    /// The workbook file path, `&[Path]`.
+    FilePath,
+
+    /// The worksheet name, `&[Tab]`.
+    SheetName,
+
+    /// An image placeholder, `&[Picture]`. The image itself is added with
+    /// [`set_header_image()`](crate::Worksheet::set_header_image) or
+    /// [`set_footer_image()`](crate::Worksheet::set_footer_image) using the
+    /// position of the section that contains the field.
+    Picture,
+}
+
+impl HeaderFooterField {
+    // Get the Excel control code for the field.
+    fn code(self) -> &'static str {
+        match self {
+            HeaderFooterField::PageNumber => "&P",
+            HeaderFooterField::TotalPages => "&N",
+            HeaderFooterField::Date => "&D",
+            HeaderFooterField::Time => "&T",
+            HeaderFooterField::FileName => "&F",
+            HeaderFooterField::FilePath => "&Z",
+            HeaderFooterField::SheetName => "&A",
+            HeaderFooterField::Picture => "&G",
+        }
+    }
+}
+
+// The content of a header/footer element.
+#[derive(Clone)]
+enum HeaderFooterContent {
+    Text(String),
+    Field(HeaderFooterField),
+}
+
+// -----------------------------------------------------------------------
+// Tests.
+// -----------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+
+    use crate::{HeaderFooter, HeaderFooterField, HeaderFooterText};
+
+    #[test]
+    fn sections() {
+        let header = HeaderFooter::new();
+        assert_eq!(String::from(&header), "");
+
+        let header = HeaderFooter::new()
+            .add_left("Ciao")
+            .add_center("Bello")
+            .add_right("Cielo");
+        assert_eq!(String::from(&header), "&LCiao&CBello&RCielo");
+
+        // Sections are written in order regardless of the order they are added.
+        let header = HeaderFooter::new().add_right("Cielo").add_left("Ciao");
+        assert_eq!(String::from(header), "&LCiao&RCielo");
+    }
+
+    #[test]
+    fn fields_and_escaping() {
+        let footer = HeaderFooter::new()
+            .add_center("Page ")
+            .add_center(HeaderFooterField::PageNumber)
+            .add_center(" of ")
+            .add_center(HeaderFooterField::TotalPages);
+        assert_eq!(String::from(&footer), "&CPage &P of &N");
+
+        let footer = HeaderFooter::new()
+            .add_left("Curiouser & Curiouser")
+            .add_right(HeaderFooterField::Picture);
+        assert_eq!(String::from(&footer), "&LCuriouser && Curiouser&R&G");
+    }
+
+    #[test]
+    fn formatting() {
+        let header = HeaderFooter::new()
+            .add_left(HeaderFooterText::new("Confidential").set_bold())
+            .add_center(HeaderFooterText::new("Report").set_bold().set_italic());
+        assert_eq!(String::from(&header), "&L&BConfidential&B&C&B&IReport&B&I");
+
+        let header = HeaderFooter::new().add_center(
+            HeaderFooterText::new("Hello")
+                .set_font_name("Courier New")
+                .set_font_size(30)
+                .set_underline()
+                .set_strikethrough(),
+        );
+        assert_eq!(
+            String::from(&header),
+            "&C&\"Courier New,Regular\"&30&U&SHello&U&S&11&\"-,Regular\""
+        );
+
+        // Bold and italic are part of the font style when there is a font.
+        let header = HeaderFooter::new()
+            .add_left(
+                HeaderFooterText::new("Hello")
+                    .set_font_name("Arial")
+                    .set_bold(),
+            )
+            .add_center(
+                HeaderFooterText::new("Hello")
+                    .set_font_name("Arial")
+                    .set_bold()
+                    .set_italic(),
+            )
+            .add_right(
+                HeaderFooterText::new("Hello")
+                    .set_font_name("Arial")
+                    .set_italic(),
+            );
+        assert_eq!(
+            String::from(&header),
+            "&L&\"Arial,Bold\"Hello&\"-,Regular\"&C&\"Arial,Bold Italic\"Hello&\"-,Regular\"&R&\"Arial,Italic\"Hello&\"-,Regular\""
+        );
+
+        // Digits after a font size need a separator.
+        let header =
+            HeaderFooter::new().add_center(HeaderFooterText::new("2023").set_font_size(12));
+        assert_eq!(String::from(&header), "&C&12 2023&11&\"-,Regular\"");
+    }
+
+    #[test]
+    fn font_applies_to_element_only() {
+        // The font of the first element doesn't carry over to the second.
+        let header = HeaderFooter::new()
+            .add_center(
+                HeaderFooterText::new("Sales")
+                    .set_font_name("Courier New")
+                    .set_font_size(20),
+            )
+            .add_center(HeaderFooterField::PageNumber)
+            .add_center("2023");
+        assert_eq!(
+            String::from(&header),
+            "&C&\"Courier New,Regular\"&20Sales&11&\"-,Regular\"&P2023"
+        );
+    }
+}
@@ -13,9 +13,10 @@ use std::io::BufReader;
 use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::drawing::{DrawingObject, DrawingType};
-use crate::XlsxError;
+use crate::{Url, XlsxError};
 
 #[derive(Clone, Debug)]
 /// The Image struct is used to create an object to represent an image that can
@@ -67,8 +68,10 @@ pub struct Image {
     pub(crate) object_movement: ObjectMovement,
     pub(crate) is_header: bool,
     pub(crate) decorative: bool,
+    pub(crate) url: Option<Url>,
+    pub(crate) crop: [f64; 4],
     pub(crate) hash: u64,
-    pub(crate) data: Vec<u8>,
+    pub(crate) data: Arc<[u8]>,
     pub(crate) drawing_type: DrawingType,
 }
 
@@ -91,6 +94,7 @@ impl Image {
     /// - BMP: BMP images are only supported for backward compatibility. In
     ///   general it is best to avoid BMP images since they are not compressed.
     ///   If used, BMP images must be 24 bit, true color, bitmaps.
+    /// - TIFF: Only the first image of a multi-page TIFF file is displayed.
     ///
     /// EMF and WMF file formats will be supported in an upcoming version of the
     /// library.
@@ -112,7 +116,7 @@ impl Image {
     /// # Errors
     ///
     /// * [`XlsxError::UnknownImageType`] - Unknown image type. The supported
-    ///   image formats are PNG, JPG, GIF and BMP.
+    ///   image formats are PNG, JPG, GIF, BMP and TIFF.
     /// * [`XlsxError::ImageDimensionError`] - Image has 0 width or height, or
     ///   the dimensions couldn't be read.
     ///
@@ -184,7 +188,7 @@ impl Image {
     /// # Errors
     ///
     /// * [`XlsxError::UnknownImageType`] - Unknown image type. The supported
-    ///   image formats are PNG, JPG, GIF and BMP.
+    ///   image formats are PNG, JPG, GIF, BMP and TIFF.
     /// * [`XlsxError::ImageDimensionError`] - Image has 0 width or height, or
     ///   the dimensions couldn't be read.
     ///
@@ -258,8 +262,10 @@ impl Image {
             object_movement: ObjectMovement::MoveButDontSizeWithCells,
             is_header: true,
             decorative: false,
+            url: None,
+            crop: [0.0; 4],
             hash: 0,
-            data: buffer.to_vec(),
+            data: Arc::from(buffer),
             drawing_type: DrawingType::Image,
         };
 
@@ -348,6 +354,333 @@ impl Image {
         self
     }
 
+    /// Set the DPI of the image used for the size calculations in Excel.
+    ///
+    /// The displayed size of an image in Excel depends on its DPI, relative to
+    /// a default of 96.0. The DPI is read from the image file but it is often
+    /// missing or incorrect, which can make the image appear at an unexpected
+    /// size. The `set_dpi()` method overrides the DPI read from the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `width_dpi` - The width/horizontal DPI. Must be greater than 0.0.
+    /// * `height_dpi` - The height/vertical DPI. Must be greater than 0.0.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates overriding the DPI of an image so
+    /// that it is displayed at its pixel size.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_image_set_dpi.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image object. This image has a DPI of 330.
+    ///     let mut image = Image::new("examples/watermark.png")?;
+    ///
+    ///     // Display the image at its pixel size by using the default DPI.
+    ///     image.set_dpi(96.0, 96.0);
+    ///
+    ///     // Insert the image.
+    ///     worksheet.insert_image(1, 2, &image)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_dpi(&mut self, width_dpi: f64, height_dpi: f64) -> &mut Image {
+        if width_dpi <= 0.0 || height_dpi <= 0.0 {
+            return self;
+        }
+
+        self.width_dpi = width_dpi;
+        self.height_dpi = height_dpi;
+        self.has_default_dpi = false;
+        self
+    }
+
+    /// Crop the image when it is displayed in the worksheet.
+    ///
+    /// Crop the edges of the image by a percentage of its width or height. As
+    /// with Excel this is a display property: the image data isn't changed and
+    /// the cropping can be adjusted or removed by the user in Excel.
+    ///
+    /// The displayed size of the image is reduced by the cropped amount, so
+    /// the remaining part of the image keeps its scale. Values outside the
+    /// range 0-100, or where the left and right or top and bottom values
+    /// would crop the entire image, are ignored.
+    ///
+    /// Cropping only applies to worksheet images and not to header/footer
+    /// images.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - The percentage of the image width to crop from the left.
+    /// * `top` - The percentage of the image height to crop from the top.
+    /// * `right` - The percentage of the image width to crop from the right.
+    /// * `bottom` - The percentage of the image height to crop from the
+    ///   bottom.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates cropping an image in a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_image_set_crop.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image object.
+    ///     let mut image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     // Crop the right half of the image.
+    ///     image.set_crop(0.0, 0.0, 50.0, 0.0);
+    ///
+    ///     // Insert the image.
+    ///     worksheet.insert_image(1, 2, &image)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_crop(&mut self, left: f64, top: f64, right: f64, bottom: f64) -> &mut Image {
+        let crop = [left, top, right, bottom];
+
+        if crop.iter().any(|value| !(0.0..=100.0).contains(value))
+            || left + right >= 100.0
+            || top + bottom >= 100.0
+        {
+            return self;
+        }
+
+        self.crop = crop;
+        self
+    }
+
+    /// Set the displayed width of the image in pixels.
+    ///
+    /// Set the width scale of the image so that it is displayed at a specific
+    /// width in the worksheet. This is often more convenient than
+    /// [`set_scale_width()`](Image::set_scale_width) since the target size is
+    /// usually known rather than the scale relative to the image resolution.
+    /// The calculation takes into account the DPI and any cropping of the
+    /// image, so those properties should be set first.
+    ///
+    /// The height isn't changed. See
+    /// [`set_scale_to_size()`](Image::set_scale_to_size) to set the width and
+    /// height while maintaining the aspect ratio.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The target width in pixels. Zero values are ignored.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the displayed size of an
+    /// image in pixels.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_image_set_width_pixels.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image object.
+    ///     let mut image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     // Display the image at 200 x 100 pixels.
+    ///     image.set_width_pixels(200).set_height_pixels(100);
+    ///
+    ///     // Insert the image.
+    ///     worksheet.insert_image(1, 2, &image)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_width_pixels(&mut self, width: u32) -> &mut Image {
+        if width == 0 {
+            return self;
+        }
+
+        let scale = f64::from(width) / self.cropped_width() * (self.width_dpi / 96.0);
+        self.set_scale_width(scale)
+    }
+
+    /// Set the displayed height of the image in pixels.
+    ///
+    /// Set the height scale of the image so that it is displayed at a specific
+    /// height in the worksheet. See
+    /// [`set_width_pixels()`](Image::set_width_pixels) for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The target height in pixels. Zero values are ignored.
+    ///
+    pub fn set_height_pixels(&mut self, height: u32) -> &mut Image {
+        if height == 0 {
+            return self;
+        }
+
+        let scale = f64::from(height) / self.cropped_height() * (self.height_dpi / 96.0);
+        self.set_scale_height(scale)
+    }
+
+    /// Resize and re-encode an oversized PNG or JPEG image.
+    ///
+    /// Images such as screenshots or photos are often stored at a much higher
+    /// resolution than is needed for display in a worksheet, which can make
+    /// the xlsx file very large. This method reduces the image so that its
+    /// largest dimension is at most `max_dimension` pixels and re-encodes it.
+    /// PNG images are re-encoded with the best lossless compression and JPEG
+    /// images are re-encoded with the given quality.
+    ///
+    /// The displayed size of the image in the worksheet isn't changed, only
+    /// the stored resolution. If the image isn't resized and re-encoding
+    /// doesn't make it smaller then the original image data is kept. Other
+    /// image types are left unchanged.
+    ///
+    /// This method requires the `image-compression` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_dimension` - The maximum width or height of the image in pixels.
+    ///   A value of 0 re-encodes the image without resizing it.
+    /// * `jpeg_quality` - The JPEG quality in the range 1-100. It is ignored
+    ///   for PNG images.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ImageCompressionError`] - The image couldn't be decoded
+    ///   or re-encoded.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates reducing the stored size of an image.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_image_recompress.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image object.
+    ///     let mut image = Image::new("examples/watermark.png")?;
+    ///
+    ///     // Store the image at a maximum of 200 pixels wide or high.
+    ///     image.recompress(200, 85)?;
+    ///
+    ///     // Insert the image.
+    ///     worksheet.insert_image(1, 2, &image)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "image-compression")]
+    pub fn recompress(
+        &mut self,
+        max_dimension: u32,
+        jpeg_quality: u8,
+    ) -> Result<&mut Image, XlsxError> {
+        use image::codecs::jpeg::JpegEncoder;
+        use image::codecs::png::{CompressionType, FilterType, PngEncoder};
+        use image::ImageFormat;
+
+        let format = match self.image_type {
+            XlsxImageType::Png => ImageFormat::Png,
+            XlsxImageType::Jpg => ImageFormat::Jpeg,
+            _ => return Ok(self),
+        };
+
+        let mut decoded = image::load_from_memory_with_format(&self.data, format)
+            .map_err(|error| XlsxError::ImageCompressionError(error.to_string()))?;
+
+        let is_resized = max_dimension > 0 && decoded.width().max(decoded.height()) > max_dimension;
+
+        if is_resized {
+            decoded = decoded.resize(
+                max_dimension,
+                max_dimension,
+                image::imageops::FilterType::Triangle,
+            );
+        }
+
+        let mut buffer = vec![];
+        let result = match format {
+            ImageFormat::Png => decoded.write_with_encoder(PngEncoder::new_with_quality(
+                &mut buffer,
+                CompressionType::Best,
+                FilterType::Adaptive,
+            )),
+            _ => decoded.write_with_encoder(JpegEncoder::new_with_quality(
+                &mut buffer,
+                jpeg_quality.clamp(1, 100),
+            )),
+        };
+        result.map_err(|error| XlsxError::ImageCompressionError(error.to_string()))?;
+
+        if !is_resized && buffer.len() >= self.data.len() {
+            return Ok(self);
+        }
+
+        // Scale the DPI with the pixel dimensions so that the displayed size of
+        // the image doesn't change.
+        let width = f64::from(decoded.width());
+        let height = f64::from(decoded.height());
+        self.width_dpi *= width / self.width;
+        self.height_dpi *= height / self.height;
+        self.width = width;
+        self.height = height;
+
+        // Update the hash for the new image data.
+        let mut hasher = DefaultHasher::new();
+        buffer.hash(&mut hasher);
+        self.hash = hasher.finish();
+        self.data = Arc::from(buffer);
+
+        Ok(self)
+    }
+
     /// Set the width and height scale to achieve a specific size.
     ///
     /// Calculate and set the horizontal and vertical scales for an image in
@@ -450,8 +783,8 @@ impl Image {
             return self;
         }
 
-        let mut scale_width = (width.into() / self.width()) * (self.width_dpi() / 96.0);
-        let mut scale_height = (height.into() / self.height()) * (self.height_dpi() / 96.0);
+        let mut scale_width = (width.into() / self.cropped_width()) * (self.width_dpi() / 96.0);
+        let mut scale_height = (height.into() / self.cropped_height()) * (self.height_dpi() / 96.0);
 
         if keep_aspect_ratio {
             if scale_width < scale_height {
@@ -649,6 +982,59 @@ impl Image {
         self
     }
 
+    /// Set a hyperlink for the image.
+    ///
+    /// Set a hyperlink that is opened when the user clicks on the image. The
+    /// url can be any of the link types supported by
+    /// [`worksheet.write_url()`](crate::Worksheet::write_url) such as
+    /// `https://`, `mailto:`, `file://` or `internal:` links to a location in
+    /// the workbook. A screen tip can be added using a [`Url`] struct with
+    /// [`Url::set_tip()`]. The url text isn't used for images.
+    ///
+    /// The length of the url is checked when the image is inserted into a
+    /// worksheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - The url as a string or a [`Url`] struct.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a hyperlink to an image.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_image_set_url.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Url, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image object.
+    ///     let mut image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     // Add a hyperlink with a screen tip to the image.
+    ///     image.set_url(Url::new("https://www.rust-lang.org").set_tip("Learn Rust"));
+    ///
+    ///     // Insert the image.
+    ///     worksheet.insert_image(1, 2, &image)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_url(&mut self, link: impl Into<Url>) -> &mut Image {
+        self.url = Some(link.into());
+        self
+    }
+
     /// Get the width of the image used for the size calculations in Excel.
     ///
     /// # Examples
@@ -703,6 +1089,16 @@ impl Image {
         self.height_dpi
     }
 
+    // Get the image width after cropping.
+    fn cropped_width(&self) -> f64 {
+        self.width * (1.0 - (self.crop[0] + self.crop[2]) / 100.0)
+    }
+
+    // Get the image height after cropping.
+    fn cropped_height(&self) -> f64 {
+        self.height * (1.0 - (self.crop[1] + self.crop[3]) / 100.0)
+    }
+
     // Get the image width as used by header/footer VML.
     pub(crate) fn vml_width(&self) -> f64 {
         // Scale the height/width by the resolution, relative to 72dpi.
@@ -772,6 +1168,7 @@ impl Image {
         let jpg_marker = unpack_u16_from_be_bytes(&data, 0);
         let bmp_marker = &data[0..2];
         let gif_marker = &data[0..4];
+        let tiff_marker = &data[0..4];
 
         if png_marker == "PNG".as_bytes() {
             self.process_png(&data);
@@ -781,6 +1178,8 @@ impl Image {
             self.process_bmp(&data);
         } else if gif_marker == "GIF8".as_bytes() {
             self.process_gif(&data);
+        } else if tiff_marker == b"II*\0" || tiff_marker == b"MM\0*" {
+            self.process_tiff(&data);
         }
 
         // Check that we read a valid image.
@@ -941,6 +1340,107 @@ impl Image {
         self.height_dpi = 96.0;
         self.image_type = XlsxImageType::Gif;
     }
+
+    // Extract width and height information from a TIFF file. Only the first
+    // image file directory (IFD) is read.
+    fn process_tiff(&mut self, data: &[u8]) {
+        let is_big_endian = data[0] == b'M';
+        let data_length = data.len();
+        let mut width: u32 = 0;
+        let mut height: u32 = 0;
+        let mut x_resolution: f64 = 0.0;
+        let mut y_resolution: f64 = 0.0;
+        let mut resolution_unit: u32 = 2;
+
+        let unpack_u16 = |offset: usize| {
+            if is_big_endian {
+                unpack_u16_from_be_bytes(data, offset)
+            } else {
+                unpack_u16_from_le_bytes(data, offset)
+            }
+        };
+
+        let unpack_u32 = |offset: usize| {
+            if is_big_endian {
+                unpack_u32_from_be_bytes(data, offset)
+            } else {
+                unpack_u32_from_le_bytes(data, offset)
+            }
+        };
+
+        // Read a rational value, stored as two u32s, from the IFD value offset.
+        let unpack_rational = |offset: usize| {
+            let offset = unpack_u32(offset) as usize;
+            if offset + 8 > data_length {
+                return 0.0;
+            }
+
+            let numerator = unpack_u32(offset);
+            let denominator = unpack_u32(offset + 4);
+            if denominator == 0 {
+                0.0
+            } else {
+                f64::from(numerator) / f64::from(denominator)
+            }
+        };
+
+        let ifd_offset = unpack_u32(4) as usize;
+        if ifd_offset + 2 > data_length {
+            return;
+        }
+
+        // Search through the IFD entries to read the height, width and
+        // resolution tags. Each entry is 12 bytes long.
+        let num_entries = unpack_u16(ifd_offset) as usize;
+        for index in 0..num_entries {
+            let offset = ifd_offset + 2 + index * 12;
+            if offset + 12 > data_length {
+                break;
+            }
+
+            let tag = unpack_u16(offset);
+            let field_type = unpack_u16(offset + 2);
+
+            // Short values are stored in the first 2 bytes of the value field.
+            let value = if field_type == 3 {
+                u32::from(unpack_u16(offset + 8))
+            } else {
+                unpack_u32(offset + 8)
+            };
+
+            match tag {
+                256 => width = value,
+                257 => height = value,
+                282 => x_resolution = unpack_rational(offset + 8),
+                283 => y_resolution = unpack_rational(offset + 8),
+                296 => resolution_unit = value,
+                _ => {}
+            }
+        }
+
+        // Convert the resolution to DPI. A unit of 2 is inches and 3 is
+        // centimeters. Other units or missing values use the default DPI.
+        let mut width_dpi = 96.0;
+        let mut height_dpi = 96.0;
+
+        if x_resolution > 0.0 && y_resolution > 0.0 {
+            if resolution_unit == 2 {
+                width_dpi = x_resolution;
+                height_dpi = y_resolution;
+                self.has_default_dpi = false;
+            } else if resolution_unit == 3 {
+                width_dpi = x_resolution * 2.54;
+                height_dpi = y_resolution * 2.54;
+                self.has_default_dpi = false;
+            }
+        }
+
+        self.width = f64::from(width);
+        self.height = f64::from(height);
+        self.width_dpi = width_dpi;
+        self.height_dpi = height_dpi;
+        self.image_type = XlsxImageType::Tiff;
+    }
 }
 
 // Trait for objects that have a component stored in the drawing.xml file.
@@ -954,11 +1454,11 @@ impl DrawingObject for Image {
     }
 
     fn width_scaled(&self) -> f64 {
-        self.width * self.scale_width * 96.0 / self.width_dpi
+        self.cropped_width() * self.scale_width * 96.0 / self.width_dpi
     }
 
     fn height_scaled(&self) -> f64 {
-        self.height * self.scale_height * 96.0 / self.height_dpi
+        self.cropped_height() * self.scale_height * 96.0 / self.height_dpi
     }
 
     fn object_movement(&self) -> ObjectMovement {
@@ -1037,6 +1537,7 @@ pub(crate) enum XlsxImageType {
     Jpg,
     Gif,
     Bmp,
+    Tiff,
 }
 
 impl XlsxImageType {
@@ -1047,6 +1548,7 @@ impl XlsxImageType {
             XlsxImageType::Jpg => "jpeg".to_string(),
             XlsxImageType::Gif => "gif".to_string(),
             XlsxImageType::Bmp => "bmp".to_string(),
+            XlsxImageType::Tiff => "tiff".to_string(),
         }
     }
 }
@@ -1074,6 +1576,7 @@ fn unpack_u32_from_le_bytes(data: &[u8], offset: usize) -> u32 {
 #[cfg(test)]
 mod tests {
 
+    use crate::drawing::DrawingObject;
     use crate::XlsxError;
 
     use crate::Image;
@@ -1102,6 +1605,8 @@ mod tests {
             ("logo.png", 200, 80, 96.0, 96.0, "png"),
             ("mylogo.png", 215, 36, 95.9866, 95.9866, "png"),
             ("red.bmp", 32, 32, 96.0, 96.0, "bmp"),
+            ("red.tiff", 32, 32, 96.0, 96.0, "tiff"),
+            ("black_150.tiff", 64, 64, 150.0, 150.0, "tiff"),
             ("red.gif", 32, 32, 96.0, 96.0, "gif"),
             ("red.jpg", 32, 32, 96.0, 96.0, "jpeg"),
             ("red.png", 32, 32, 96.0, 96.0, "png"),
@@ -1145,6 +1650,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_dpi() {
+        let mut image = Image::new("tests/input/images/black_300.png").unwrap();
+
+        image.set_dpi(96.0, 72.0);
+        assert_eq!(96.0, image.width_dpi());
+        assert_eq!(72.0, image.height_dpi());
+        assert_eq!(64.0, image.width_scaled());
+        assert_eq!(64.0 * 96.0 / 72.0, image.height_scaled());
+
+        // Invalid values are ignored.
+        image.set_dpi(0.0, 150.0);
+        assert_eq!(96.0, image.width_dpi());
+        assert_eq!(72.0, image.height_dpi());
+    }
+
+    #[test]
+    fn set_crop() {
+        let mut image = Image::new("tests/input/images/red.png").unwrap();
+
+        image.set_crop(10.0, 25.0, 40.0, 0.0);
+        assert_eq!(32.0 * 0.5, image.width_scaled());
+        assert_eq!(32.0 * 0.75, image.height_scaled());
+
+        // Invalid values are ignored.
+        image.set_crop(60.0, 0.0, 40.0, 0.0);
+        image.set_crop(-1.0, 0.0, 0.0, 0.0);
+        assert_eq!([10.0, 25.0, 40.0, 0.0], image.crop);
+
+        // The scale to size calculation uses the cropped size.
+        image.set_scale_to_size(32, 48, false);
+        assert_eq!(32.0, image.width_scaled());
+        assert_eq!(48.0, image.height_scaled());
+    }
+
+    #[test]
+    fn set_size_in_pixels() {
+        let mut image = Image::new("tests/input/images/black_150.png").unwrap();
+
+        image.set_width_pixels(200).set_height_pixels(50);
+        assert_eq!(200.0, image.width_scaled().round());
+        assert_eq!(50.0, image.height_scaled().round());
+
+        // Cropping is taken into account.
+        image.set_crop(0.0, 0.0, 50.0, 0.0).set_width_pixels(100);
+        assert_eq!(100.0, image.width_scaled().round());
+
+        // Zero values are ignored.
+        image.set_height_pixels(0);
+        assert_eq!(50.0, image.height_scaled().round());
+    }
+
+    #[cfg(feature = "image-compression")]
+    #[test]
+    fn recompress() {
+        for filename in ["black_150.png", "black_150.jpg"] {
+            let filename = format!("tests/input/images/{filename}");
+            let mut image = Image::new(&filename).unwrap();
+            let width_scaled = image.width_scaled();
+            let height_scaled = image.height_scaled();
+            let hash = image.hash;
+
+            image.recompress(16, 80).unwrap();
+            assert_eq!(16.0, image.width());
+            assert_eq!(16.0, image.height());
+            assert_ne!(hash, image.hash);
+
+            // The displayed size isn't changed.
+            assert_eq!(width_scaled, image.width_scaled());
+            assert_eq!(height_scaled, image.height_scaled());
+
+            // The new data is a valid image of the same type.
+            let reloaded = Image::new_from_buffer(&image.data).unwrap();
+            assert_eq!(
+                image.image_type.extension(),
+                reloaded.image_type.extension()
+            );
+            assert_eq!(16.0, reloaded.width());
+        }
+    }
+
     #[test]
     fn unknown_file_format() {
         let filename = "tests/input/images/unknown.img".to_string();
@@ -106,9 +106,17 @@
 //! ## Features
 //!
 //! - `default`: Includes all the standard functionality. Has dependencies on
-//! `zip` and `chrono` and on `regex`, `itertools` and `lazy_static`.
+//!   `zip` and `chrono` and on `regex`, `itertools` and `lazy_static`. The zip
+//!   compression uses the `zlib-rs` crate, a pure Rust deflate implementation
+//!   with SIMD optimizations.
 //! - `zlib`: Adds dependency on zlib and a C compiler. This includes the same
-//! features as `default` but is 1.5x faster for large files.
+//!   features as `default` but uses the system zlib for compression.
+//! - `zlib-ng`: Adds dependency on zlib-ng, a C compiler and cmake. This
+//!   includes the same features as `default` but uses the zlib-ng deflate
+//!   implementation which is the fastest option for large files.
+//! - `image-compression`: Adds a dependency on the `image` crate to allow
+//!   oversized PNG and JPEG images to be resized and re-encoded with
+//!   `Image::recompress()`.
 //! - `test-resave`: Developer only testing feature.
 //!
 //! # See also
@@ -117,7 +125,7 @@
 //!     - [Getting started]: A simple getting started guide on how to use
 //!       `rust_xlsxwriter` in a project and write a Hello World example.
 //!     - [Tutorial]: A larger example of using `rust_xlsxwriter` to write some
-//!        expense data to a spreadsheet.
+//!       expense data to a spreadsheet.
 //!     - [Cookbook Examples].
 //! - [Release Notes].
 //! - [Roadmap of planned features].
@@ -137,12 +145,15 @@ mod core;
 mod custom;
 mod drawing;
 mod error;
+mod feature_property_bag;
 mod filter;
 mod format;
 mod formula;
+mod header_footer;
 mod image;
 mod metadata;
 mod packager;
+mod page_setup;
 mod properties;
 mod protection;
 mod relationship;
@@ -152,10 +163,12 @@ mod styles;
 mod table;
 mod theme;
 mod url;
-mod utility;
+pub mod utility;
 mod vml;
 mod workbook;
 mod worksheet;
+mod worksheet_group;
+mod worksheet_options;
 mod xmlwriter;
 
 #[cfg(test)]
@@ -167,13 +180,17 @@ pub use error::*;
 pub use filter::*;
 pub use format::*;
 pub use formula::*;
+pub use header_footer::*;
 pub use image::*;
+pub use page_setup::*;
 pub use properties::*;
 pub use protection::*;
 pub use table::*;
 pub use url::*;
 pub use workbook::*;
 pub use worksheet::*;
+pub use worksheet_group::*;
+pub use worksheet_options::*;
 
 #[macro_use]
 extern crate lazy_static;
@@ -40,10 +40,17 @@
 // The Packager struct coordinates the classes that represent the elements of
 // the package and writes them into the xlsx file.
 
+use std::cmp;
 use std::collections::HashSet;
-use std::io::{Seek, Write};
-
-use zip::write::FileOptions;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::mem;
+use std::panic;
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+
+use chrono::{Datelike, TimeZone, Timelike, Utc};
+use zip::write::SimpleFileOptions;
 use zip::{DateTime, ZipWriter};
 
 use crate::app::App;
@@ -51,6 +58,7 @@ use crate::content_types::ContentTypes;
 use crate::core::Core;
 use crate::custom::Custom;
 use crate::error::XlsxError;
+use crate::feature_property_bag::FeaturePropertyBag;
 use crate::metadata::Metadata;
 use crate::relationship::Relationship;
 use crate::shared_strings::SharedStrings;
@@ -60,12 +68,18 @@ use crate::theme::Theme;
 use crate::vml::Vml;
 use crate::workbook::Workbook;
 use crate::worksheet::Worksheet;
-use crate::{DocProperties, NUM_IMAGE_FORMATS};
+use crate::xmlwriter::XmlFlush;
+use crate::{CompressionLevel, DocProperties, NUM_IMAGE_FORMATS};
+
+// The maximum number of xml buffers that are kept for reuse by the following
+// worksheets and by the next save.
+pub(crate) const MAX_XML_BUFFERS: usize = 2;
 
 // Packager struct to assembler the xlsx file.
 pub struct Packager<W: Write + Seek> {
     zip: ZipWriter<W>,
-    zip_options: FileOptions,
+    zip_options: SimpleFileOptions,
+    output_error: Arc<OnceLock<io::ErrorKind>>,
 }
 
 impl<W: Write + Seek> Packager<W> {
@@ -73,26 +87,367 @@ impl<W: Write + Seek> Packager<W> {
     // Crate public methods.
     // -----------------------------------------------------------------------
 
-    // Create a new Packager struct.
-    pub(crate) fn new(writer: W) -> Packager<W> {
-        let zip = zip::ZipWriter::new(writer);
+    // Create a new Packager struct. The zip writer is created by the caller
+    // since writers that can't seek need to use the zip streaming mode.
+    pub(crate) fn new<X: Write>(
+        writer: X,
+        new_zip: impl FnOnce(ZipOutput<X>) -> ZipWriter<W>,
+        options: &PackagerOptions,
+    ) -> Packager<W> {
+        let output_error = Arc::new(OnceLock::new());
+        let zip = new_zip(ZipOutput {
+            writer,
+            error: Arc::clone(&output_error),
+        });
+
+        // The zip file timestamps are fixed at the earliest zip date. In
+        // deterministic mode they are set to the document creation time,
+        // clamped to the 1980-2107 range that can be stored in a zip file.
+        let mut last_modified_time = DateTime::default();
+        if options.deterministic {
+            let min_datetime = Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap();
+            let max_datetime = Utc.with_ymd_and_hms(2107, 12, 31, 23, 59, 58).unwrap();
+            let datetime = options.creation_time.clamp(min_datetime, max_datetime);
+
+            last_modified_time = DateTime::from_date_and_time(
+                datetime.year() as u16,
+                datetime.month() as u8,
+                datetime.day() as u8,
+                datetime.hour() as u8,
+                datetime.minute() as u8,
+                datetime.second() as u8,
+            )
+            .unwrap_or_default();
+        }
 
-        let zip_options = FileOptions::default()
-            .compression_method(zip::CompressionMethod::Deflated)
+        // Large file (zip64) extra fields are turned off since some strict
+        // xlsx readers reject zip entries with extra fields.
+        let zip_options = SimpleFileOptions::default()
             .unix_permissions(0o600)
-            .last_modified_time(DateTime::default())
+            .last_modified_time(last_modified_time)
             .large_file(false);
 
-        Packager { zip, zip_options }
+        let zip_options = match options.compression {
+            CompressionLevel::Default => {
+                zip_options.compression_method(zip::CompressionMethod::Deflated)
+            }
+            CompressionLevel::Fast => zip_options
+                .compression_method(zip::CompressionMethod::Deflated)
+                .compression_level(Some(1)),
+            CompressionLevel::Best => zip_options
+                .compression_method(zip::CompressionMethod::Deflated)
+                .compression_level(Some(9)),
+            CompressionLevel::Stored => {
+                zip_options.compression_method(zip::CompressionMethod::Stored)
+            }
+        };
+
+        Packager {
+            zip,
+            zip_options,
+            output_error,
+        }
     }
 
-    // Write the xml files that make up the xlsx OPC package.
+    // Write the xml files that make up the xlsx OPC package. The xml files are
+    // generated on a worker thread and passed back to the calling thread which
+    // compresses them into the zip file. This allows the xml serialization of
+    // one file to overlap with the deflate compression of the previous one.
+    // The zip file is written on the calling thread so that the writer doesn't
+    // need to be sent to another thread.
+    //
+    // If a progress callback is supplied it is called with the name of each
+    // file as it is added to the package and the fraction of files completed.
     pub(crate) fn assemble_file(
+        self,
+        workbook: &mut Workbook,
+        options: &PackagerOptions,
+        progress: Option<ProgressCallback>,
+    ) -> Result<(), XlsxError> {
+        let num_files = PackageWriter::count_files(workbook, options);
+        let buffers = mem::take(&mut workbook.xml_buffers);
+
+        let (result, mut buffers) = thread::scope(|scope| {
+            // The channel is bounded so that only the file being compressed and
+            // the next generated file are held in memory at the same time.
+            let (sender, receiver) = mpsc::sync_channel(1);
+
+            // The xml buffers are returned once they have been compressed so
+            // that they can be reused for the following worksheets.
+            let (recycler, recycled) = mpsc::channel();
+
+            let xml_thread = scope.spawn(|| {
+                let mut package = PackageWriter {
+                    sender,
+                    buffers,
+                    recycled,
+                };
+                let result = package.write_files(workbook, options);
+
+                // Keep the largest buffers to reuse in the next save. The
+                // channel is closed when the package writer is dropped so that
+                // the zip file can be finished.
+                let PackageWriter {
+                    mut buffers,
+                    recycled,
+                    ..
+                } = package;
+                buffers.extend(recycled.try_iter());
+
+                (result, buffers)
+            });
+
+            let zip_result = self.write_zip_file(receiver, recycler, progress, num_files);
+
+            // An error in the zip file takes precedence since it will also
+            // cause a send error in the package writer.
+            match xml_thread.join() {
+                Ok((result, buffers)) => (zip_result.and(result), buffers),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        });
+
+        PackageWriter::trim_buffers(&mut buffers);
+        workbook.xml_buffers = buffers;
+
+        result
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal function/methods.
+    // -----------------------------------------------------------------------
+
+    // Compress the xml and image files received from the package writer into
+    // the zip file.
+    fn write_zip_file(
+        self,
+        receiver: Receiver<PackageMessage>,
+        recycler: Sender<Vec<u8>>,
+        mut progress: Option<ProgressCallback>,
+        num_files: usize,
+    ) -> Result<(), XlsxError> {
+        let Packager {
+            mut zip,
+            zip_options,
+            output_error,
+        } = self;
+        let mut files_written = 0;
+
+        for message in receiver {
+            let data = match message {
+                PackageMessage::File(file) => {
+                    if let Some(progress) = &mut progress {
+                        files_written += 1;
+                        let fraction = files_written as f64 / num_files as f64;
+                        progress(&file.filename, fraction.min(1.0));
+                    }
+
+                    zip.start_file(file.filename, zip_options)?;
+                    file.data
+                }
+                PackageMessage::Chunk(data) => PackageData::Xml(data),
+            };
+
+            zip.write_all(data.as_ref())?;
+
+            if let PackageData::Xml(mut data) = data {
+                data.clear();
+
+                // The receiver may already have been dropped at the end of
+                // the save, in which case the buffer is freed.
+                let _ = recycler.send(data);
+            }
+        }
+
+        // Close the zip file.
+        zip.finish()?;
+
+        // Check for an output error that the zip writer didn't return.
+        if let Some(kind) = output_error.get() {
+            return Err(io::Error::from(*kind).into());
+        }
+
+        Ok(())
+    }
+}
+
+// A wrapper for the writer that the zip file is written to. After the first
+// write error any remaining output is discarded. This allows the zip writer,
+// which tries to finalize the file when it is dropped, to be dropped on the
+// error path without reporting a second failure to stderr.
+pub(crate) struct ZipOutput<W: Write> {
+    writer: W,
+    error: Arc<OnceLock<io::ErrorKind>>,
+}
+
+impl<W: Write> ZipOutput<W> {
+    // Store the kind of the first write error, apart from interrupted writes
+    // which are retried.
+    fn check<T>(&self, result: io::Result<T>) -> io::Result<T> {
+        if let Err(error) = &result {
+            if error.kind() != io::ErrorKind::Interrupted {
+                let _ = self.error.set(error.kind());
+            }
+        }
+
+        result
+    }
+}
+
+impl<W: Write> Write for ZipOutput<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.error.get().is_some() {
+            return Ok(buf.len());
+        }
+
+        let result = self.writer.write(buf);
+        self.check(result)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.error.get().is_some() {
+            return Ok(());
+        }
+
+        let result = self.writer.flush();
+        self.check(result)
+    }
+}
+
+impl<W: Write + Seek> Seek for ZipOutput<W> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        if self.error.get().is_some() {
+            return Ok(0);
+        }
+
+        let result = self.writer.seek(pos);
+        self.check(result)
+    }
+}
+
+// A message to the zip writer. Large xml files are sent as a file followed by
+// chunks of data that are appended to it.
+enum PackageMessage {
+    File(PackageFile),
+    Chunk(Vec<u8>),
+}
+
+// A file, and its path within the zip container, to be written to the xlsx
+// package.
+struct PackageFile {
+    filename: String,
+    data: PackageData,
+}
+
+// The data for a file in the xlsx package. Image data is shared with the
+// worksheet images rather than being copied.
+enum PackageData {
+    Xml(Vec<u8>),
+    Image(Arc<[u8]>),
+}
+
+impl AsRef<[u8]> for PackageData {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            PackageData::Xml(data) => data,
+            PackageData::Image(data) => data,
+        }
+    }
+}
+
+impl From<Vec<u8>> for PackageData {
+    fn from(data: Vec<u8>) -> PackageData {
+        PackageData::Xml(data)
+    }
+}
+
+impl From<Arc<[u8]>> for PackageData {
+    fn from(data: Arc<[u8]>) -> PackageData {
+        PackageData::Image(data)
+    }
+}
+
+// Struct to generate the xml files that make up the xlsx package and send them
+// to the Packager zip writer.
+struct PackageWriter {
+    sender: SyncSender<PackageMessage>,
+    buffers: Vec<Vec<u8>>,
+    recycled: Receiver<Vec<u8>>,
+}
+
+impl PackageWriter {
+    // Get the largest available xml buffer, including any buffers that have
+    // been returned by the zip writer.
+    fn take_buffer(&mut self) -> Option<Vec<u8>> {
+        self.buffers.extend(self.recycled.try_iter());
+        Self::trim_buffers(&mut self.buffers);
+
+        if self.buffers.is_empty() {
+            None
+        } else {
+            Some(self.buffers.remove(0))
+        }
+    }
+
+    // Sort the xml buffers by size and limit the number of buffers retained.
+    fn trim_buffers(buffers: &mut Vec<Vec<u8>>) {
+        buffers.sort_by_key(|buffer| cmp::Reverse(buffer.capacity()));
+        buffers.truncate(MAX_XML_BUFFERS);
+    }
+
+    // Count the files that will be written to the package, to calculate the
+    // save progress. This must match the conditions in write_files().
+    fn count_files(workbook: &Workbook, options: &PackagerOptions) -> usize {
+        // The content types, root rels, workbook rels, theme, styles,
+        // workbook, core and app files are always written.
+        let mut num_files = 8;
+
+        num_files += usize::from(options.has_sst_table);
+        num_files += usize::from(options.has_dynamic_arrays);
+        num_files += usize::from(options.has_checkboxes);
+        num_files += usize::from(!options.properties.custom_properties.is_empty());
+        num_files += usize::from(options.properties.thumbnail.is_some());
+
+        let mut unique_worksheet_images = HashSet::new();
+        let mut unique_header_footer_images = HashSet::new();
+
+        for worksheet in &workbook.worksheets {
+            num_files += 1;
+            num_files +=
+                usize::from(worksheet.has_relationships() || worksheet.has_url_hyperlinks());
+            num_files += usize::from(!worksheet.drawing.drawings.is_empty());
+            num_files += usize::from(worksheet.has_header_footer_images());
+            num_files += usize::from(!worksheet.drawing_relationships.is_empty());
+            num_files += usize::from(!worksheet.vml_drawing_relationships.is_empty());
+            num_files += worksheet.charts.len();
+            num_files += worksheet.tables.len();
+
+            for image in worksheet.drawing_images() {
+                if unique_worksheet_images.insert(image.hash) {
+                    num_files += 1;
+                }
+            }
+
+            if worksheet.has_header_footer_images() {
+                for image in worksheet.header_footer_images.iter().flatten() {
+                    if unique_header_footer_images.insert(image.hash) {
+                        num_files += 1;
+                    }
+                }
+            }
+        }
+
+        num_files
+    }
+
+    // Write the xml files that make up the xlsx OPC package.
+    fn write_files(
         &mut self,
         workbook: &mut Workbook,
         options: &PackagerOptions,
     ) -> Result<(), XlsxError> {
-        // Write the sub-component files.
+        // Write the sub-component files. The content types file must be the
+        // first file in the zip container for some strict xlsx readers.
         self.write_content_types_file(options)?;
         self.write_root_rels_file(options)?;
         self.write_workbook_rels_file(options)?;
@@ -116,6 +471,7 @@ impl<W: Write + Seek> Packager<W> {
         self.write_core_file(options)?;
         self.write_app_file(options)?;
         self.write_custom_file(options)?;
+        self.write_thumbnail_file(options)?;
 
         self.write_drawing_files(workbook)?;
         self.write_vml_files(workbook)?;
@@ -141,15 +497,35 @@ impl<W: Write + Seek> Packager<W> {
             self.write_metadata_file()?;
         }
 
-        // Close the zip file.
-        self.zip.finish()?;
+        if options.has_checkboxes {
+            self.write_feature_property_bag_file()?;
+        }
 
         Ok(())
     }
 
-    // -----------------------------------------------------------------------
-    // Internal function/methods.
-    // -----------------------------------------------------------------------
+    // Send a file to the zip writer to be added to the xlsx package.
+    fn add_file(
+        &mut self,
+        filename: impl Into<String>,
+        data: impl Into<PackageData>,
+    ) -> Result<(), XlsxError> {
+        let file = PackageFile {
+            filename: filename.into(),
+            data: data.into(),
+        };
+
+        self.send(PackageMessage::File(file))
+    }
+
+    // Send a message to the zip writer.
+    fn send(&mut self, message: PackageMessage) -> Result<(), XlsxError> {
+        // The receiver is only dropped if the zip writer stops early due to
+        // an error. That error is returned in place of this one.
+        self.sender
+            .send(message)
+            .map_err(|_| XlsxError::IoError(io::ErrorKind::BrokenPipe.into()))
+    }
 
     // Write the [ContentTypes].xml file.
     fn write_content_types_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
@@ -179,6 +555,10 @@ impl<W: Write + Seek> Packager<W> {
             content_types.add_metadata();
         }
 
+        if options.has_checkboxes {
+            content_types.add_feature_property_bag();
+        }
+
         if options.has_vml {
             content_types.add_default(
                 "vml",
@@ -199,16 +579,23 @@ impl<W: Write + Seek> Packager<W> {
         if options.image_types[4] {
             content_types.add_default("bmp", "image/bmp");
         }
+        if options.image_types[5] {
+            content_types.add_default("tiff", "image/tiff");
+        }
 
         if !options.properties.custom_properties.is_empty() {
             content_types.add_custom_properties();
         }
 
-        self.zip
-            .start_file("[Content_Types].xml", self.zip_options)?;
+        if options.is_template {
+            content_types.set_template();
+        }
 
         content_types.assemble_xml_file();
-        self.zip.write_all(content_types.writer.xmlfile.get_ref())?;
+        self.add_file(
+            "[Content_Types].xml",
+            content_types.writer.xmlfile.into_inner(),
+        )?;
 
         Ok(())
     }
@@ -225,10 +612,15 @@ impl<W: Write + Seek> Packager<W> {
             rels.add_document_relationship("custom-properties", "docProps/custom.xml", "");
         }
 
-        self.zip.start_file("_rels/.rels", self.zip_options)?;
+        if let Some(thumbnail) = &options.properties.thumbnail {
+            rels.add_package_relationship(
+                "metadata/thumbnail",
+                &format!("docProps/thumbnail.{}", thumbnail.image_type.extension()),
+            );
+        }
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.add_file("_rels/.rels", rels.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
@@ -256,11 +648,19 @@ impl<W: Write + Seek> Packager<W> {
             rels.add_document_relationship("sheetMetadata", "metadata.xml", "");
         }
 
-        self.zip
-            .start_file("xl/_rels/workbook.xml.rels", self.zip_options)?;
+        if options.has_checkboxes {
+            rels.add_office_relationship(
+                "2022/11",
+                "FeaturePropertyBag",
+                "featurePropertyBag/featurePropertyBag.xml",
+            );
+        }
 
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.add_file(
+            "xl/_rels/workbook.xml.rels",
+            rels.writer.xmlfile.into_inner(),
+        )?;
 
         Ok(())
     }
@@ -274,12 +674,56 @@ impl<W: Write + Seek> Packager<W> {
     ) -> Result<(), XlsxError> {
         let filename = format!("xl/worksheets/sheet{index}.xml");
 
-        self.zip.start_file(filename, self.zip_options)?;
+        // Reuse a previously allocated buffer, if it is larger than the
+        // current one, to avoid growing the buffer as the xml is written.
+        if let Some(buffer) = self.take_buffer() {
+            let xmlfile = &mut worksheet.writer.xmlfile;
+            if buffer.capacity() > xmlfile.get_ref().capacity() {
+                *xmlfile.get_mut() = buffer;
+                xmlfile.set_position(0);
+            } else {
+                self.buffers.push(buffer);
+            }
+        }
 
-        worksheet.assemble_xml_file(string_table);
-        self.zip.write_all(worksheet.writer.xmlfile.get_ref())?;
+        // The cell data xml is sent to the zip writer in chunks as it is
+        // written.
+        self.add_file_in_chunks(filename, |flush| {
+            worksheet.assemble_xml_file_in_chunks(string_table, Some(flush));
+            mem::take(worksheet.writer.xmlfile.get_mut())
+        })
+    }
 
-        Ok(())
+    // Assemble a large xml file and send it to the zip writer in chunks as it
+    // is written. The assemble function passes the filled buffers to the flush
+    // callback and returns the final buffer. A send error stops any further
+    // chunks from being sent and is returned once the file has been assembled.
+    fn add_file_in_chunks(
+        &mut self,
+        filename: String,
+        assemble: impl FnOnce(XmlFlush) -> Vec<u8>,
+    ) -> Result<(), XlsxError> {
+        let mut filename = Some(filename);
+        let mut result = Ok(());
+
+        let buffer = assemble(&mut |buffer: Vec<u8>| {
+            if result.is_ok() {
+                result = self.add_chunk(&mut filename, buffer);
+            }
+            self.take_buffer().unwrap_or_default()
+        });
+        result?;
+
+        self.add_chunk(&mut filename, buffer)
+    }
+
+    // Send a chunk of a file to the zip writer. The first chunk starts the
+    // file in the package and the following chunks are appended to it.
+    fn add_chunk(&mut self, filename: &mut Option<String>, data: Vec<u8>) -> Result<(), XlsxError> {
+        match filename.take() {
+            Some(filename) => self.add_file(filename, data),
+            None => self.send(PackageMessage::Chunk(data)),
+        }
     }
 
     // Write a worksheet rels file.
@@ -304,10 +748,8 @@ impl<W: Write + Seek> Packager<W> {
 
         let filename = format!("xl/worksheets/_rels/sheet{index}.xml.rels");
 
-        self.zip.start_file(filename, self.zip_options)?;
-
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.add_file(filename, rels.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
@@ -326,10 +768,8 @@ impl<W: Write + Seek> Packager<W> {
 
         let filename = format!("xl/drawings/_rels/drawing{index}.xml.rels");
 
-        self.zip.start_file(filename, self.zip_options)?;
-
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.add_file(filename, rels.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
@@ -348,20 +788,19 @@ impl<W: Write + Seek> Packager<W> {
 
         let filename = format!("xl/drawings/_rels/vmlDrawing{index}.vml.rels");
 
-        self.zip.start_file(filename, self.zip_options)?;
-
         rels.assemble_xml_file();
-        self.zip.write_all(rels.writer.xmlfile.get_ref())?;
+        self.add_file(filename, rels.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
 
     // Write the workbook.xml file.
     pub(crate) fn write_workbook_file(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
-        self.zip.start_file("xl/workbook.xml", self.zip_options)?;
-
         workbook.assemble_xml_file();
-        self.zip.write_all(workbook.writer.xmlfile.get_ref())?;
+        self.add_file(
+            "xl/workbook.xml",
+            mem::take(workbook.writer.xmlfile.get_mut()),
+        )?;
 
         Ok(())
     }
@@ -373,14 +812,12 @@ impl<W: Write + Seek> Packager<W> {
     ) -> Result<(), XlsxError> {
         let mut shared_strings = SharedStrings::new();
 
-        self.zip
-            .start_file("xl/sharedStrings.xml", self.zip_options)?;
-
-        shared_strings.assemble_xml_file(string_table);
-        self.zip
-            .write_all(shared_strings.writer.xmlfile.get_ref())?;
-
-        Ok(())
+        // The shared strings are sent to the zip writer in chunks since the
+        // table can be large for string heavy workbooks.
+        self.add_file_in_chunks("xl/sharedStrings.xml".to_string(), |flush| {
+            shared_strings.assemble_xml_file_in_chunks(string_table, Some(flush));
+            shared_strings.writer.xmlfile.into_inner()
+        })
     }
 
     // Write the styles.xml file.
@@ -395,10 +832,8 @@ impl<W: Write + Seek> Packager<W> {
             false,
         );
 
-        self.zip.start_file("xl/styles.xml", self.zip_options)?;
-
         styles.assemble_xml_file();
-        self.zip.write_all(styles.writer.xmlfile.get_ref())?;
+        self.add_file("xl/styles.xml", styles.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
@@ -407,11 +842,8 @@ impl<W: Write + Seek> Packager<W> {
     fn write_theme_file(&mut self) -> Result<(), XlsxError> {
         let mut theme = Theme::new();
 
-        self.zip
-            .start_file("xl/theme/theme1.xml", self.zip_options)?;
-
         theme.assemble_xml_file();
-        self.zip.write_all(theme.writer.xmlfile.get_ref())?;
+        self.add_file("xl/theme/theme1.xml", theme.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
@@ -420,11 +852,10 @@ impl<W: Write + Seek> Packager<W> {
     fn write_core_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
         let mut core = Core::new();
         core.properties = options.properties.clone();
-
-        self.zip.start_file("docProps/core.xml", self.zip_options)?;
+        core.properties.creation_time = Some(options.creation_time);
 
         core.assemble_xml_file();
-        self.zip.write_all(core.writer.xmlfile.get_ref())?;
+        self.add_file("docProps/core.xml", core.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
@@ -438,11 +869,18 @@ impl<W: Write + Seek> Packager<W> {
         let mut custom = Custom::new();
         custom.properties = options.properties.clone();
 
-        self.zip
-            .start_file("docProps/custom.xml", self.zip_options)?;
-
         custom.assemble_xml_file();
-        self.zip.write_all(custom.writer.xmlfile.get_ref())?;
+        self.add_file("docProps/custom.xml", custom.writer.xmlfile.into_inner())?;
+
+        Ok(())
+    }
+
+    // Write the document thumbnail image file.
+    fn write_thumbnail_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
+        if let Some(thumbnail) = &options.properties.thumbnail {
+            let filename = format!("docProps/thumbnail.{}", thumbnail.image_type.extension());
+            self.add_file(filename, Arc::clone(&thumbnail.data))?;
+        }
 
         Ok(())
     }
@@ -467,10 +905,10 @@ impl<W: Write + Seek> Packager<W> {
             }
         }
 
-        self.zip.start_file("docProps/app.xml", self.zip_options)?;
+        app.add_property_heading_pairs();
 
         app.assemble_xml_file();
-        self.zip.write_all(app.writer.xmlfile.get_ref())?;
+        self.add_file("docProps/app.xml", app.writer.xmlfile.into_inner())?;
 
         Ok(())
     }
@@ -479,10 +917,21 @@ impl<W: Write + Seek> Packager<W> {
     fn write_metadata_file(&mut self) -> Result<(), XlsxError> {
         let mut metadata = Metadata::new();
 
-        self.zip.start_file("xl/metadata.xml", self.zip_options)?;
-
         metadata.assemble_xml_file();
-        self.zip.write_all(metadata.writer.xmlfile.get_ref())?;
+        self.add_file("xl/metadata.xml", metadata.writer.xmlfile.into_inner())?;
+
+        Ok(())
+    }
+
+    // Write the featurePropertyBag.xml file.
+    fn write_feature_property_bag_file(&mut self) -> Result<(), XlsxError> {
+        let mut feature_property_bag = FeaturePropertyBag::new();
+
+        feature_property_bag.assemble_xml_file();
+        self.add_file(
+            "xl/featurePropertyBag/featurePropertyBag.xml",
+            feature_property_bag.writer.xmlfile.into_inner(),
+        )?;
 
         Ok(())
     }
@@ -493,11 +942,12 @@ impl<W: Write + Seek> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             if !worksheet.drawing.drawings.is_empty() {
                 let filename = format!("xl/drawings/drawing{index}.xml");
-                self.zip.start_file(filename, self.zip_options)?;
 
                 worksheet.drawing.assemble_xml_file();
-                self.zip
-                    .write_all(worksheet.drawing.writer.xmlfile.get_ref())?;
+                self.add_file(
+                    filename,
+                    mem::take(worksheet.drawing.writer.xmlfile.get_mut()),
+                )?;
                 index += 1;
             }
         }
@@ -511,7 +961,6 @@ impl<W: Write + Seek> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             if worksheet.has_header_footer_images() {
                 let filename = format!("xl/drawings/vmlDrawing{index}.vml");
-                self.zip.start_file(filename, self.zip_options)?;
 
                 let mut vml = Vml::new();
                 vml.header_images
@@ -519,8 +968,7 @@ impl<W: Write + Seek> Packager<W> {
                 vml.data_id = index;
                 vml.shape_id = 1024 * index;
                 vml.assemble_xml_file();
-
-                self.zip.write_all(vml.writer.xmlfile.get_ref())?;
+                self.add_file(filename, vml.writer.xmlfile.into_inner())?;
                 index += 1;
             }
         }
@@ -535,25 +983,21 @@ impl<W: Write + Seek> Packager<W> {
         let mut unique_header_footer_images = HashSet::new();
 
         for worksheet in &mut workbook.worksheets {
-            for image in worksheet.images.values() {
+            for image in worksheet.drawing_images() {
                 if !unique_worksheet_images.contains(&image.hash) {
                     let filename =
                         format!("xl/media/image{index}.{}", image.image_type.extension());
-                    self.zip.start_file(filename, self.zip_options)?;
-
-                    self.zip.write_all(&image.data)?;
+                    self.add_file(filename, Arc::clone(&image.data))?;
                     unique_worksheet_images.insert(image.hash);
                     index += 1;
                 }
             }
             if worksheet.has_header_footer_images() {
-                for image in worksheet.header_footer_images.clone().into_iter().flatten() {
+                for image in worksheet.header_footer_images.iter().flatten() {
                     if !unique_header_footer_images.contains(&image.hash) {
                         let filename =
                             format!("xl/media/image{index}.{}", image.image_type.extension());
-                        self.zip.start_file(filename, self.zip_options)?;
-
-                        self.zip.write_all(&image.data)?;
+                        self.add_file(filename, Arc::clone(&image.data))?;
                         unique_header_footer_images.insert(image.hash);
                         index += 1;
                     }
@@ -571,9 +1015,9 @@ impl<W: Write + Seek> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             for chart in worksheet.charts.values_mut() {
                 let filename = format!("xl/charts/chart{index}.xml");
-                self.zip.start_file(filename, self.zip_options)?;
+
                 chart.assemble_xml_file();
-                self.zip.write_all(chart.writer.xmlfile.get_ref())?;
+                self.add_file(filename, mem::take(chart.writer.xmlfile.get_mut()))?;
                 index += 1;
             }
         }
@@ -588,9 +1032,9 @@ impl<W: Write + Seek> Packager<W> {
         for worksheet in &mut workbook.worksheets {
             for table in &mut worksheet.tables {
                 let filename = format!("xl/tables/table{index}.xml");
-                self.zip.start_file(filename, self.zip_options)?;
+
                 table.assemble_xml_file();
-                self.zip.write_all(table.writer.xmlfile.get_ref())?;
+                self.add_file(filename, mem::take(table.writer.xmlfile.get_mut()))?;
                 index += 1;
             }
         }
@@ -599,10 +1043,15 @@ impl<W: Write + Seek> Packager<W> {
     }
 }
 
+// Callback used to report the file name and the completed fraction of the
+// files written during a save.
+pub(crate) type ProgressCallback<'a> = &'a mut dyn FnMut(&str, f64);
+
 // Internal struct to pass options to the Packager struct.
 pub(crate) struct PackagerOptions {
     pub(crate) has_sst_table: bool,
     pub(crate) has_dynamic_arrays: bool,
+    pub(crate) has_checkboxes: bool,
     pub(crate) has_vml: bool,
     pub(crate) num_worksheets: u16,
     pub(crate) num_drawings: u16,
@@ -613,6 +1062,10 @@ pub(crate) struct PackagerOptions {
     pub(crate) defined_names: Vec<String>,
     pub(crate) image_types: [bool; NUM_IMAGE_FORMATS],
     pub(crate) properties: DocProperties,
+    pub(crate) compression: CompressionLevel,
+    pub(crate) deterministic: bool,
+    pub(crate) is_template: bool,
+    pub(crate) creation_time: chrono::DateTime<Utc>,
 }
 
 impl PackagerOptions {
@@ -621,6 +1074,7 @@ impl PackagerOptions {
         PackagerOptions {
             has_sst_table: false,
             has_dynamic_arrays: false,
+            has_checkboxes: false,
             has_vml: false,
             num_worksheets: 0,
             num_drawings: 0,
@@ -631,6 +1085,10 @@ impl PackagerOptions {
             defined_names: vec![],
             image_types: [false; NUM_IMAGE_FORMATS],
             properties: DocProperties::new(),
+            compression: CompressionLevel::Default,
+            deterministic: false,
+            is_template: false,
+            creation_time: Utc::now(),
         }
     }
 }
@@ -0,0 +1,216 @@
+// page_setup - A module for defining reusable worksheet page setup options.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use crate::{ColNum, RowNum};
+
+/// The `PageSetup` struct is used to define page setup options that can be
+/// applied to several worksheets.
+///
+/// Reports often need the same printing options on every worksheet. Rather
+/// than repeating the individual page setup methods for each worksheet the
+/// options can be defined once in a `PageSetup` struct and applied with
+/// [`worksheet.set_page_setup()`](crate::Worksheet::set_page_setup).
+///
+/// The methods of `PageSetup` have the same names, arguments and defaults as
+/// the equivalent [`Worksheet`](crate::Worksheet) methods, and only the
+/// options that are set are applied to the worksheet. Header and footer
+/// images aren't part of the page setup and should be added to each worksheet
+/// with [`set_header_image()`](crate::Worksheet::set_header_image) or
+/// [`set_footer_image()`](crate::Worksheet::set_footer_image).
+///
+/// # Examples
+///
+/// The following example demonstrates applying the same page setup to several
+/// worksheets.
+///
+/// ```
+/// # // This code is available in examples/doc_page_setup_intro.rs
+/// #
+/// # use rust_xlsxwriter::{PageSetup, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #
+///     let page_setup = PageSetup::new()
+///         .set_landscape()
+///         .set_paper_size(9) // A4 paper size.
+///         .set_margins(0.5, 0.5, 0.75, 0.75, 0.3, 0.3)
+///         .set_print_fit_to_pages(1, 0)
+///         .set_header("&CQuarterly Sales")
+///         .set_footer("&CPage &P of &N");
+///
+///     for region in ["North", "South", "East", "West"] {
+///         let worksheet = workbook.add_worksheet();
+///         worksheet.set_name(region)?;
+///         worksheet.set_page_setup(&page_setup)?;
+///     }
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct PageSetup {
+    pub(crate) portrait: Option<bool>,
+    pub(crate) paper_size: Option<u8>,
+    pub(crate) margins: Option<(f64, f64, f64, f64, f64, f64)>,
+    pub(crate) print_scale: Option<u16>,
+    pub(crate) print_fit_to_pages: Option<(u16, u16)>,
+    pub(crate) print_area: Option<(RowNum, ColNum, RowNum, ColNum)>,
+    pub(crate) header: Option<String>,
+    pub(crate) footer: Option<String>,
+}
+
+impl PageSetup {
+    /// Create a new `PageSetup` struct with no options set.
+    pub fn new() -> PageSetup {
+        PageSetup::default()
+    }
+
+    /// Set the page orientation to landscape.
+    ///
+    /// See [`Worksheet::set_landscape()`](crate::Worksheet::set_landscape).
+    ///
+    pub fn set_landscape(mut self) -> PageSetup {
+        self.portrait = Some(false);
+        self
+    }
+
+    /// Set the page orientation to portrait.
+    ///
+    /// See [`Worksheet::set_portrait()`](crate::Worksheet::set_portrait).
+    ///
+    pub fn set_portrait(mut self) -> PageSetup {
+        self.portrait = Some(true);
+        self
+    }
+
+    /// Set the paper type/size when printing.
+    ///
+    /// See [`Worksheet::set_paper_size()`](crate::Worksheet::set_paper_size)
+    /// for the list of paper sizes.
+    ///
+    /// # Arguments
+    ///
+    /// * `paper_size` - The paper size index.
+    ///
+    pub fn set_paper_size(mut self, paper_size: u8) -> PageSetup {
+        self.paper_size = Some(paper_size);
+        self
+    }
+
+    /// Set the page margins.
+    ///
+    /// See [`Worksheet::set_margins()`](crate::Worksheet::set_margins). As
+    /// with that method, `-1.0` leaves a margin at the Excel default.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Left margin in inches. Excel default is 0.7.
+    /// * `right` - Right margin in inches. Excel default is 0.7.
+    /// * `top` - Top margin in inches. Excel default is 0.75.
+    /// * `bottom` - Bottom margin in inches. Excel default is 0.75.
+    /// * `header` - Header margin in inches. Excel default is 0.3.
+    /// * `footer` - Footer margin in inches. Excel default is 0.3.
+    ///
+    pub fn set_margins(
+        mut self,
+        left: f64,
+        right: f64,
+        top: f64,
+        bottom: f64,
+        header: f64,
+        footer: f64,
+    ) -> PageSetup {
+        self.margins = Some((left, right, top, bottom, header, footer));
+        self
+    }
+
+    /// Set the printed page scale factor.
+    ///
+    /// See [`Worksheet::set_print_scale()`](crate::Worksheet::set_print_scale).
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The print scale factor in the range 10 <= scale <= 400.
+    ///
+    pub fn set_print_scale(mut self, scale: u16) -> PageSetup {
+        self.print_scale = Some(scale);
+        self
+    }
+
+    /// Fit the printed area to a specific number of pages both vertically and
+    /// horizontally.
+    ///
+    /// See
+    /// [`Worksheet::set_print_fit_to_pages()`](crate::Worksheet::set_print_fit_to_pages).
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Number of pages horizontally.
+    /// * `height` - Number of pages vertically.
+    ///
+    pub fn set_print_fit_to_pages(mut self, width: u16, height: u16) -> PageSetup {
+        self.print_fit_to_pages = Some((width, height));
+        self
+    }
+
+    /// Set the print area for the worksheet.
+    ///
+    /// See [`Worksheet::set_print_area()`](crate::Worksheet::set_print_area).
+    /// The range is checked when the page setup is applied to a worksheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first row of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last row of the range.
+    ///
+    pub fn set_print_area(
+        mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> PageSetup {
+        self.print_area = Some((first_row, first_col, last_row, last_col));
+        self
+    }
+
+    /// Set the printed page header caption.
+    ///
+    /// See [`Worksheet::set_header()`](crate::Worksheet::set_header) for the
+    /// header string syntax.
+    ///
+    /// # Arguments
+    ///
+    /// * `header` - The header string with optional control characters, or a
+    ///   [`HeaderFooter`](crate::HeaderFooter).
+    ///
+    pub fn set_header(mut self, header: impl Into<String>) -> PageSetup {
+        self.header = Some(header.into());
+        self
+    }
+
+    /// Set the printed page footer caption.
+    ///
+    /// See [`Worksheet::set_footer()`](crate::Worksheet::set_footer).
+    ///
+    /// # Arguments
+    ///
+    /// * `footer` - The footer string with optional control characters, or a
+    ///   [`HeaderFooter`](crate::HeaderFooter).
+    ///
+    pub fn set_footer(mut self, footer: impl Into<String>) -> PageSetup {
+        self.footer = Some(footer.into());
+        self
+    }
+}
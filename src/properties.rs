@@ -8,6 +8,8 @@
 
 use chrono::{DateTime, Utc};
 
+use crate::Image;
+
 /// The `DocProperties` struct is used to create an object to represent document
 /// metadata properties.
 ///
@@ -207,8 +209,15 @@ pub struct DocProperties {
     pub(crate) category: String,
     pub(crate) keywords: String,
     pub(crate) hyperlink_base: String,
-    pub(crate) creation_time: DateTime<Utc>,
+    pub(crate) language: String,
+    pub(crate) application: String,
+    pub(crate) app_version: String,
+    pub(crate) scale_crop: bool,
+    pub(crate) links_up_to_date: bool,
+    pub(crate) thumbnail: Option<Image>,
+    pub(crate) creation_time: Option<DateTime<Utc>>,
     pub(crate) custom_properties: Vec<CustomProperty>,
+    pub(crate) heading_pairs: Vec<(String, Vec<String>)>,
 }
 
 impl Default for DocProperties {
@@ -231,8 +240,15 @@ impl DocProperties {
             category: String::new(),
             keywords: String::new(),
             hyperlink_base: String::new(),
-            creation_time: Utc::now(),
+            language: String::new(),
+            application: "Microsoft Excel".to_string(),
+            app_version: "12.0000".to_string(),
+            scale_crop: false,
+            links_up_to_date: false,
+            thumbnail: None,
+            creation_time: None,
             custom_properties: vec![],
+            heading_pairs: vec![],
         }
     }
 
@@ -360,7 +376,7 @@ impl DocProperties {
     /// Set the Status field of the document properties.
     ///
     /// Set the "Status" field of the document properties such as "Draft" or
-    /// "Final".
+    /// "Final". This is stored as the "Content status" property in the file.
     ///
     /// # Arguments
     ///
@@ -387,6 +403,131 @@ impl DocProperties {
         self
     }
 
+    /// Set the Language field of the document properties.
+    ///
+    /// Set the "Language" field of the document properties to the primary
+    /// language of the document, as a language tag such as "en-US" or "de-DE".
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language string property.
+    ///
+    pub fn set_language(mut self, language: impl Into<String>) -> DocProperties {
+        self.language = language.into();
+
+        self
+    }
+
+    /// Set the Application field of the document properties.
+    ///
+    /// Set the "Application" field in the extended document properties to the
+    /// name of the application that created the document. The default is
+    /// "Microsoft Excel", which is what Excel expects.
+    ///
+    /// # Arguments
+    ///
+    /// * `application` - The application name string property.
+    ///
+    pub fn set_application(mut self, application: impl Into<String>) -> DocProperties {
+        self.application = application.into();
+
+        self
+    }
+
+    /// Set the AppVersion field of the document properties.
+    ///
+    /// Set the "AppVersion" field in the extended document properties to the
+    /// version of the application that created the document. The default is
+    /// "12.0000".
+    ///
+    /// Excel requires the version to be in the form "XX.YYYY", with 4 digits
+    /// after the decimal point, and will report a corrupt file for other
+    /// formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The application version string property.
+    ///
+    pub fn set_app_version(mut self, version: impl Into<String>) -> DocProperties {
+        self.app_version = version.into();
+
+        self
+    }
+
+    /// Set the "Scale crop" flag of the document properties.
+    ///
+    /// Set the "ScaleCrop" flag in the extended document properties. This
+    /// indicates how the document thumbnail is displayed: scaled to fit the
+    /// display when it is on, or cropped when it is off.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_scale_crop(mut self, enable: bool) -> DocProperties {
+        self.scale_crop = enable;
+
+        self
+    }
+
+    /// Set the "Links up to date" flag of the document properties.
+    ///
+    /// Set the "LinksUpToDate" flag in the extended document properties. This
+    /// indicates whether the hyperlinks in the document are up to date.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_links_up_to_date(mut self, enable: bool) -> DocProperties {
+        self.links_up_to_date = enable;
+
+        self
+    }
+
+    /// Set a thumbnail image for the document.
+    ///
+    /// Set an image that is used as the preview of the document by file
+    /// browsers, such as Windows Explorer and SharePoint, that display
+    /// document thumbnails. Excel uses a JPEG image, typically around 256
+    /// pixels wide, for the thumbnails that it creates.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The [`Image`] to use as the thumbnail.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a thumbnail image to the
+    /// document properties.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_properties_set_thumbnail.rs
+    /// #
+    /// # use rust_xlsxwriter::{DocProperties, Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let image = Image::new("examples/rust_logo.png")?;
+    ///     let properties = DocProperties::new().set_thumbnail(&image);
+    ///     workbook.set_properties(&properties);
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///
+    ///     workbook.save("properties.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_thumbnail(mut self, image: &Image) -> DocProperties {
+        self.thumbnail = Some(image.clone());
+
+        self
+    }
+
     /// Set the create date/time for the document.
     ///
     /// Excel sets a date and time for every new document in UTC. The
@@ -401,7 +542,7 @@ impl DocProperties {
     ///     https://docs.rs/chrono/latest/chrono/struct.DateTime.html
     ///
     pub fn set_creation_datetime(mut self, create_time: &DateTime<Utc>) -> DocProperties {
-        self.creation_time = *create_time;
+        self.creation_time = Some(*create_time);
 
         self
     }
@@ -460,6 +601,65 @@ impl DocProperties {
 
         self
     }
+
+    /// Add a heading and list of part titles to the document properties.
+    ///
+    /// The extended document properties contain a list of headings, such as
+    /// "Worksheets" and "Named Ranges", and the titles of the parts of the
+    /// document under each heading. These are generated automatically from
+    /// the worksheet and defined names in the workbook. Some document
+    /// management tools use them to display an outline of the document.
+    ///
+    /// The `set_heading_pair()` method adds a user defined heading, and its
+    /// part titles, after the automatically generated ones. It can be called
+    /// more than once to add several headings.
+    ///
+    /// # Arguments
+    ///
+    /// * `heading` - The heading name, such as "Reports".
+    /// * `titles` - The titles of the parts under the heading.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a user defined heading to the
+    /// document properties.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_properties_set_heading_pair.rs
+    /// #
+    /// # use rust_xlsxwriter::{DocProperties, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.add_worksheet().set_name("Summary")?;
+    ///     workbook.add_worksheet().set_name("Q1")?;
+    ///     workbook.add_worksheet().set_name("Q2")?;
+    ///
+    ///     let properties = DocProperties::new().set_heading_pair("Quarterly Reports", &["Q1", "Q2"]);
+    ///
+    ///     workbook.set_properties(&properties);
+    ///
+    ///     workbook.save("properties.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_heading_pair(
+        mut self,
+        heading: impl Into<String>,
+        titles: &[impl AsRef<str>],
+    ) -> DocProperties {
+        let titles = titles
+            .iter()
+            .map(|title| title.as_ref().to_string())
+            .collect();
+
+        self.heading_pairs.push((heading.into(), titles));
+
+        self
+    }
 }
 
 // -----------------------------------------------------------------------
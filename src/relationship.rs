@@ -44,6 +44,17 @@ impl Relationship {
         ));
     }
 
+    // Add Microsoft Office specific relationship to xlsx .rels xml files.
+    pub(crate) fn add_office_relationship(&mut self, version: &str, rel_type: &str, target: &str) {
+        let office_schema = format!("http://schemas.microsoft.com/office/{version}/relationships");
+
+        self.relationships.push((
+            format!("{office_schema}/{rel_type}"),
+            target.to_string(),
+            String::new(),
+        ));
+    }
+
     // Add container relationship to xlsx .rels xml files.
     pub(crate) fn add_package_relationship(&mut self, rel_type: &str, target: &str) {
         let package_schema = "http://schemas.openxmlformats.org/package/2006/relationships";
@@ -140,4 +151,33 @@ mod tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_package_relationships() {
+        let mut rels = Relationship::new();
+
+        rels.add_document_relationship("officeDocument", "xl/workbook.xml", "");
+        rels.add_package_relationship("metadata/core-properties", "docProps/core.xml");
+        rels.add_document_relationship("extended-properties", "docProps/app.xml", "");
+        rels.add_package_relationship("metadata/thumbnail", "docProps/thumbnail.jpeg");
+
+        rels.assemble_xml_file();
+
+        let got = rels.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+              <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>
+              <Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/>
+              <Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties" Target="docProps/app.xml"/>
+              <Relationship Id="rId4" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail" Target="docProps/thumbnail.jpeg"/>
+            </Relationships>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
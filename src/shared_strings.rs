@@ -7,7 +7,7 @@
 use itertools::Itertools;
 
 use crate::shared_strings_table::SharedStringsTable;
-use crate::xmlwriter::XMLWriter;
+use crate::xmlwriter::{XMLWriter, XmlFlush};
 
 pub struct SharedStrings {
     pub(crate) writer: XMLWriter,
@@ -29,15 +29,27 @@ impl SharedStrings {
     // XML assembly methods.
     // -----------------------------------------------------------------------
 
-    //  Assemble and write the XML file.
+    //  Assemble and write the XML file. The packager writes the file in chunks
+    //  so this is used for tests.
+    #[allow(dead_code)]
     pub(crate) fn assemble_xml_file(&mut self, string_table: &SharedStringsTable) {
+        self.assemble_xml_file_in_chunks(string_table, None);
+    }
+
+    // Assemble the XML file, passing the xml to the flush callback, if any, in
+    // chunks as the strings are written.
+    pub(crate) fn assemble_xml_file_in_chunks(
+        &mut self,
+        string_table: &SharedStringsTable,
+        mut flush: Option<XmlFlush>,
+    ) {
         self.writer.xml_declaration();
 
         // Write the sst element.
         self.write_sst(string_table);
 
         // Write the sst strings.
-        self.write_sst_strings(string_table);
+        self.write_sst_strings(string_table, &mut flush);
 
         // Close the sst tag.
         self.writer.xml_end_tag("sst");
@@ -54,7 +66,11 @@ impl SharedStrings {
     }
 
     // Write the sst string elements.
-    fn write_sst_strings(&mut self, string_table: &SharedStringsTable) {
+    fn write_sst_strings(
+        &mut self,
+        string_table: &SharedStringsTable,
+        flush: &mut Option<XmlFlush>,
+    ) {
         for (string, _) in string_table.strings.iter().sorted_by_key(|x| x.1) {
             let preserve_whitespace =
                 string.starts_with(['\t', '\n', ' ']) || string.ends_with(['\t', '\n', ' ']);
@@ -64,6 +80,8 @@ impl SharedStrings {
             } else {
                 self.writer.xml_si_element(string, preserve_whitespace);
             }
+
+            self.writer.flush_chunk(flush);
         }
     }
 }
@@ -36,7 +36,7 @@ impl<'a> Styles<'a> {
         num_formats: Vec<String>,
         has_hyperlink_style: bool,
         is_rich_string_style: bool,
-    ) -> Styles {
+    ) -> Styles<'_> {
         let writer = XMLWriter::new();
 
         Styles {
@@ -542,7 +542,7 @@ impl<'a> Styles<'a> {
             attributes.push(("applyProtection", "1".to_string()));
         }
 
-        if has_alignment || has_protection {
+        if has_alignment || has_protection || xf_format.checkbox {
             self.writer.xml_start_tag("xf", &attributes);
 
             if has_alignment {
@@ -555,6 +555,11 @@ impl<'a> Styles<'a> {
                 self.write_protection(xf_format);
             }
 
+            if xf_format.checkbox {
+                // Write the checkbox extLst element.
+                self.write_xf_checkbox_ext_lst();
+            }
+
             self.writer.xml_end_tag("xf");
         } else {
             self.writer.xml_empty_tag("xf", &attributes);
@@ -576,6 +581,25 @@ impl<'a> Styles<'a> {
         self.writer.xml_empty_tag("protection", &attributes);
     }
 
+    // Write the <extLst> element for a checkbox format. This links the xf to
+    // the XFComplement bag in the featurePropertyBag.xml file.
+    fn write_xf_checkbox_ext_lst(&mut self) {
+        let attributes = [
+            ("uri", "{C7286773-470A-42A8-94C5-96B5CB345126}"),
+            (
+                "xmlns:xfpb",
+                "http://schemas.microsoft.com/office/spreadsheetml/2022/featurepropertybag",
+            ),
+        ];
+
+        self.writer.xml_start_tag_only("extLst");
+        self.writer.xml_start_tag("ext", &attributes);
+        self.writer
+            .xml_empty_tag("xfpb:xfComplement", &[("i", "0")]);
+        self.writer.xml_end_tag("ext");
+        self.writer.xml_end_tag("extLst");
+    }
+
     // Write the <alignment> element.
     fn write_alignment(&mut self, alignment: Alignment) {
         let mut attributes = vec![];
@@ -821,4 +845,38 @@ mod tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_checkbox() {
+        let mut xf_format = Format::new();
+        xf_format.set_font_index(0, true);
+        xf_format.set_border_index(0, true);
+
+        let checkbox_format = Format::new().set_checkbox();
+
+        let xf_formats = vec![xf_format, checkbox_format];
+        let mut styles = Styles::new(&xf_formats, 1, 2, 1, vec![], false, false);
+
+        styles.write_cell_xfs();
+
+        let got = styles.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <cellXfs count="2">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0">
+                    <extLst>
+                        <ext uri="{C7286773-470A-42A8-94C5-96B5CB345126}" xmlns:xfpb="http://schemas.microsoft.com/office/spreadsheetml/2022/featurepropertybag">
+                        <xfpb:xfComplement i="0"/>
+                        </ext>
+                    </extLst>
+                    </xf>
+                </cellXfs>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
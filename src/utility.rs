@@ -4,10 +4,32 @@
 //
 // Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
 
+//! Utility functions for `rust_xlsxwriter`.
+//!
+//! The `rust_xlsxwriter` library uses zero indexed row and column numbers but
+//! Excel formulas use "A1" style cell references. The functions in this module
+//! can be used to convert between the two, and to build the parts of a formula
+//! that refer to user provided worksheet names, strings and table columns so
+//! that the formula is always valid.
+//!
+//! The module also exposes the string width estimate that is used by
+//! [`Worksheet::autofit()`](crate::Worksheet::autofit).
+
+#![warn(missing_docs)]
+
+use regex::Regex;
+
 use crate::worksheet::ColNum;
 use crate::worksheet::RowNum;
 
-// Convert a zero indexed column cell reference to a string.
+/// Convert a zero indexed column number to a column name string such as "A".
+///
+/// For example, column 0 is "A", column 25 is "Z" and column 26 is "AA".
+///
+/// # Arguments
+///
+/// * `col_num` - The zero indexed column number.
+///
 pub fn col_to_name(col_num: ColNum) -> String {
     let mut col_name = String::new();
 
@@ -35,7 +57,7 @@ pub fn col_to_name(col_num: ColNum) -> String {
 }
 
 // Convert a column string such as "A" to a zero indexed column reference.
-pub fn name_to_col(column: &str) -> ColNum {
+pub(crate) fn name_to_col(column: &str) -> ColNum {
     let mut col_num = 0;
 
     for char in column.chars() {
@@ -45,18 +67,43 @@ pub fn name_to_col(column: &str) -> ColNum {
     col_num - 1
 }
 
-// Convert a zero indexed row and column cell reference to a A1 style string.
+/// Convert zero indexed row and column numbers to an "A1" style cell
+/// reference.
+///
+/// # Arguments
+///
+/// * `row_num` - The zero indexed row number.
+/// * `col_num` - The zero indexed column number.
+///
 pub fn rowcol_to_cell(row_num: RowNum, col_num: ColNum) -> String {
     format!("{}{}", col_to_name(col_num), row_num + 1)
 }
 
-// Convert a zero indexed row and column cell reference to an absolute $A$1
-// style string.
+/// Convert zero indexed row and column numbers to an absolute "$A$1" style
+/// cell reference.
+///
+/// # Arguments
+///
+/// * `row_num` - The zero indexed row number.
+/// * `col_num` - The zero indexed column number.
+///
 pub fn rowcol_to_cell_abs(row_num: RowNum, col_num: ColNum) -> String {
     format!("${}${}", col_to_name(col_num), row_num + 1)
 }
 
-// Convert zero indexed row and col cell references to a A1:B1 style range string.
+/// Convert zero indexed row and column numbers to an "A1:B2" style range
+/// reference.
+///
+/// If the first and last cells are the same a single cell reference such as
+/// "A1" is returned.
+///
+/// # Arguments
+///
+/// * `first_row` - The first row of the range. (All zero indexed.)
+/// * `first_col` - The first column of the range.
+/// * `last_row` - The last row of the range.
+/// * `last_col` - The last column of the range.
+///
 pub fn cell_range(
     first_row: RowNum,
     first_col: ColNum,
@@ -73,8 +120,19 @@ pub fn cell_range(
     }
 }
 
-// Convert zero indexed row and col cell references to an absolute $A$1:$B$1
-// style range string.
+/// Convert zero indexed row and column numbers to an absolute "$A$1:$B$2"
+/// style range reference.
+///
+/// If the first and last cells are the same a single cell reference such as
+/// "$A$1" is returned.
+///
+/// # Arguments
+///
+/// * `first_row` - The first row of the range. (All zero indexed.)
+/// * `first_col` - The first column of the range.
+/// * `last_row` - The last row of the range.
+/// * `last_col` - The last column of the range.
+///
 pub fn cell_range_abs(
     first_row: RowNum,
     first_col: ColNum,
@@ -93,7 +151,7 @@ pub fn cell_range_abs(
 
 // Convert zero indexed row and col cell references to a chart absolute
 // Sheet1!$A$1:$B$1 style range string.
-pub fn chart_range_abs(
+pub(crate) fn chart_range_abs(
     sheet_name: &str,
     first_row: RowNum,
     first_col: ColNum,
@@ -111,28 +169,159 @@ pub fn chart_range_abs(
     }
 }
 
-// Create a quoted version of a worksheet name. Excel single quotes worksheet
-// names that contain spaces and some other characters.
+/// Quote a worksheet name for use in a formula, if required.
+///
+/// Excel requires worksheet names to be enclosed in single quotes in formulas
+/// and references, such as `='Sales Data'!A1`, if the name contains spaces or
+/// punctuation, starts with a digit, or looks like a cell reference such as
+/// "A1" or "R1C1". Any single quotes within the name are doubled.
+///
+/// Names that are already quoted are returned unchanged.
+///
+/// # Arguments
+///
+/// * `sheetname` - The worksheet name.
+///
+/// # Examples
+///
+/// The following example demonstrates building a formula that refers to a
+/// user provided worksheet name.
+///
+/// ```
+/// # // This code is available in examples/doc_utility_quote_sheetname.rs
+/// #
+/// # use rust_xlsxwriter::utility::quote_sheetname;
+/// #
+/// # fn main() {
+///     assert_eq!(quote_sheetname("Sales"), "Sales");
+///     assert_eq!(quote_sheetname("Sales Data"), "'Sales Data'");
+///     assert_eq!(quote_sheetname("Bob's"), "'Bob''s'");
+///     assert_eq!(quote_sheetname("Q1"), "'Q1'");
+///
+///     let formula = format!("=SUM({}!B2:B10)", quote_sheetname("Sales Data"));
+///     assert_eq!(formula, "=SUM('Sales Data'!B2:B10)");
+/// # }
+/// ```
+///
 pub fn quote_sheetname(sheetname: &str) -> String {
-    let mut sheetname = sheetname.to_string();
+    lazy_static! {
+        static ref CELL_REFERENCE: Regex =
+            Regex::new(r"^(?i)([A-Z]{1,3}\d+|R\d*C\d*|[RC]\d*)$").unwrap();
+    }
 
     // Ignore strings that are already quoted.
-    if !sheetname.starts_with('\'') {
-        // double quote and other single quotes.
-        sheetname = sheetname.replace('\'', "''");
-
-        // Single quote the worksheet name if it contains any of the characters
-        // that Excel quotes when using the name in a formula.
-        if sheetname.contains(' ') || sheetname.contains('!') || sheetname.contains('\'') {
-            sheetname = format!("'{sheetname}'");
-        }
+    if sheetname.starts_with('\'') {
+        return sheetname.to_string();
     }
 
-    sheetname
+    // Excel doesn't quote names made up of word characters and periods,
+    // unless they start with a digit or period, or look like a cell reference.
+    let needs_quoting = sheetname
+        .chars()
+        .any(|c| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        || sheetname.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        || CELL_REFERENCE.is_match(sheetname);
+
+    if needs_quoting {
+        format!("'{}'", sheetname.replace('\'', "''"))
+    } else {
+        sheetname.to_string()
+    }
 }
 
-// Get the pixel width of a string based on character widths taken from Excel.
-// Non-ascii characters are given a default width of 8 pixels.
+/// Convert a string into a string literal for use in a formula.
+///
+/// Strings in Excel formulas are enclosed in double quotes and any double
+/// quotes within the string are doubled, for example `="Say ""Hello"""`.
+///
+/// # Arguments
+///
+/// * `string` - The string to convert.
+///
+/// # Examples
+///
+/// The following example demonstrates building a formula that contains a
+/// user provided string.
+///
+/// ```
+/// # // This code is available in examples/doc_utility_quote_string.rs
+/// #
+/// # use rust_xlsxwriter::utility::quote_string;
+/// #
+/// # fn main() {
+///     let formula = format!("=COUNTIF(A:A,{})", quote_string(r#"12" pipe"#));
+///
+///     assert_eq!(formula, r#"=COUNTIF(A:A,"12"" pipe")"#);
+/// # }
+/// ```
+///
+pub fn quote_string(string: &str) -> String {
+    format!("\"{}\"", string.replace('"', "\"\""))
+}
+
+/// Create a structured reference to a table column for use in a formula.
+///
+/// Excel formulas can refer to a table column by name, such as
+/// `=SUM(Sales[Amount])`, rather than by its cell range. Column names that
+/// contain the special characters `[ ] # '` need to be escaped with a single
+/// quote and names that contain spaces or other punctuation need an extra set
+/// of brackets, such as `Sales[[Amount (USD)]]`. This function handles the
+/// escaping so that the reference is always valid.
+///
+/// # Arguments
+///
+/// * `table_name` - The name of the table. This can be an empty string to
+///   create a reference to a column in the same table, such as `[Amount]`.
+/// * `column_name` - The name of the table column.
+///
+/// # Examples
+///
+/// The following example demonstrates building formulas that refer to table
+/// columns.
+///
+/// ```
+/// # // This code is available in examples/doc_utility_structured_reference.rs
+/// #
+/// # use rust_xlsxwriter::utility::structured_reference;
+/// #
+/// # fn main() {
+///     assert_eq!(structured_reference("Sales", "Amount"), "Sales[Amount]");
+///     assert_eq!(structured_reference("Sales", "Amount (USD)"), "Sales[[Amount (USD)]]");
+///     assert_eq!(structured_reference("Sales", "Item #"), "Sales[[Item '#]]");
+///     assert_eq!(structured_reference("", "Amount"), "[Amount]");
+/// # }
+/// ```
+///
+pub fn structured_reference(table_name: &str, column_name: &str) -> String {
+    let escaped = column_name
+        .replace('\'', "''")
+        .replace('#', "'#")
+        .replace(']', "']")
+        .replace('[', "'[");
+
+    if column_name
+        .chars()
+        .any(|c| !(c.is_alphanumeric() || c == '_'))
+    {
+        format!("{table_name}[[{escaped}]]")
+    } else {
+        format!("{table_name}[{escaped}]")
+    }
+}
+
+/// Get the pixel width of a string in Excel's default font.
+///
+/// The width is calculated from character widths taken from Excel for the
+/// default Calibri 11 font. Non-ascii characters are given a default width of
+/// 8 pixels. This is the estimate used by
+/// [`Worksheet::autofit()`](crate::Worksheet::autofit) and it can be used as
+/// the basis for a custom function passed to
+/// [`Worksheet::set_autofit_string_width()`](crate::Worksheet::set_autofit_string_width).
+///
+/// # Arguments
+///
+/// * `string` - The string to measure.
+///
 #[allow(clippy::match_same_arms)]
 pub fn pixel_width(string: &str) -> u16 {
     let mut length = 0;
@@ -374,6 +563,18 @@ mod tests {
                 "'a''''''''''''''''''''''''''''''''''''''''''''''''''''''''''b'",
                 "a'''''''''''''''''''''''''''''b",
             ),
+            ("'Sheet-8'", "Sheet-8"),
+            ("'Q&A'", "Q&A"),
+            ("'2023'", "2023"),
+            ("'.Sheet'", ".Sheet"),
+            ("'A1'", "A1"),
+            ("'xfd1048576'", "xfd1048576"),
+            ("'R1C1'", "R1C1"),
+            ("'rc'", "rc"),
+            ("'R'", "R"),
+            ("Résumé", "Résumé"),
+            ("ABCD1", "ABCD1"),
+            ("Data2023", "Data2023"),
         ];
 
         for (exp, sheetname) in tests {
@@ -381,6 +582,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_quote_string() {
+        let tests = vec![
+            ("", r#""""#),
+            ("Hello", r#""Hello""#),
+            (r#"Say "Hi""#, r#""Say ""Hi""""#),
+        ];
+
+        for (string, exp) in tests {
+            assert_eq!(exp, utility::quote_string(string));
+        }
+    }
+
+    #[test]
+    fn test_structured_reference() {
+        let tests = vec![
+            ("Table1", "Column1", "Table1[Column1]"),
+            ("Table1", "Sales_2023", "Table1[Sales_2023]"),
+            ("Table1", "Sales 2023", "Table1[[Sales 2023]]"),
+            ("Table1", "Item #", "Table1[[Item '#]]"),
+            ("Table1", "[Total]", "Table1[['[Total']]]"),
+            ("Table1", "Bob's", "Table1[[Bob''s]]"),
+            ("", "Column1", "[Column1]"),
+        ];
+
+        for (table_name, column_name, exp) in tests {
+            assert_eq!(exp, utility::structured_reference(table_name, column_name));
+        }
+    }
+
     #[test]
     fn test_pixel_width() {
         let tests = vec![
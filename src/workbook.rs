@@ -6,20 +6,27 @@
 
 #![warn(missing_docs)]
 
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::io::{Cursor, Seek, Write};
 use std::mem;
 use std::path::Path;
 
+use chrono::{TimeZone, Utc};
+use zip::ZipWriter;
+
 use crate::error::XlsxError;
 use crate::format::Format;
 use crate::packager::Packager;
 use crate::packager::PackagerOptions;
+use crate::packager::ProgressCallback;
+use crate::packager::ZipOutput;
 use crate::worksheet::Worksheet;
+use crate::worksheet_group::WorksheetGroup;
 use crate::xmlwriter::XMLWriter;
 use crate::{
     utility, Border, ChartSeriesCacheData, ColNum, DefinedName, DefinedNameType, DocProperties,
-    Fill, Font, RowNum, NUM_IMAGE_FORMATS,
+    Fill, Font, RowNum, StringOverflow, NUM_IMAGE_FORMATS,
 };
 use crate::{Color, FormatPattern};
 
@@ -109,6 +116,13 @@ pub struct Workbook {
     defined_names: Vec<DefinedName>,
     user_defined_names: Vec<DefinedName>,
     read_only_mode: u8,
+    reservation_password: u16,
+    compression: CompressionLevel,
+    deterministic: bool,
+    is_template: bool,
+    right_to_left: bool,
+    string_overflow: StringOverflow,
+    pub(crate) xml_buffers: Vec<Vec<u8>>,
 }
 
 impl Default for Workbook {
@@ -170,12 +184,19 @@ impl Workbook {
             border_count: 0,
             num_formats: vec![],
             read_only_mode: 0,
+            reservation_password: 0,
             has_hyperlink_style: false,
             worksheets: vec![],
             xf_formats: vec![],
             defined_names: vec![],
             user_defined_names: vec![],
             xf_indices: HashMap::new(),
+            compression: CompressionLevel::Default,
+            deterministic: false,
+            is_template: false,
+            right_to_left: false,
+            string_overflow: StringOverflow::Error,
+            xml_buffers: vec![],
         };
 
         // Initialize the workbook with the same function used to reset it.
@@ -244,6 +265,7 @@ impl Workbook {
 
         let mut worksheet = Worksheet::new();
         worksheet.set_name(&name).unwrap();
+        worksheet.set_string_overflow(self.string_overflow);
 
         self.worksheets.push(worksheet);
         let worksheet = self.worksheets.last_mut().unwrap();
@@ -423,6 +445,83 @@ impl Workbook {
         ))
     }
 
+    /// Convert a string into a valid worksheet name that is unique in the
+    /// workbook.
+    ///
+    /// The `unique_worksheet_name()` method converts a string into a valid
+    /// worksheet name using
+    /// [`Worksheet::sanitize_name()`](crate::Worksheet::sanitize_name) and
+    /// then, if the name is already used by a worksheet in the workbook,
+    /// appends a number in the same way that Excel names copied worksheets:
+    /// "Name (2)", "Name (3)" and so on. The name is shortened if required to
+    /// stay within the 31 character limit. Excel compares worksheet names
+    /// without regard to case so "DATA" is treated as a duplicate of "Data".
+    ///
+    /// This is useful when worksheet names are derived from data, such as
+    /// customer names, that may be long, contain invalid characters or repeat.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The string to convert to a worksheet name.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating worksheets with names
+    /// derived from data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_unique_worksheet_name.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     for customer in ["Acme Corp.", "ACME Corp.", "Smith & Sons [UK]"] {
+    ///         let name = workbook.unique_worksheet_name(customer);
+    ///         workbook.add_worksheet().set_name(name)?;
+    ///     }
+    ///
+    ///     // The worksheet names are "Acme Corp.", "ACME Corp. (2)" and
+    ///     // "Smith & Sons UK".
+    ///     assert_eq!("ACME Corp. (2)", workbook.worksheet_from_index(1)?.name());
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn unique_worksheet_name(&self, name: &str) -> String {
+        let name = Worksheet::sanitize_name(name);
+
+        let is_used = |candidate: &str| {
+            self.worksheets.iter().any(|worksheet| {
+                worksheet
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| name.as_str().to_lowercase() == candidate.to_lowercase())
+            })
+        };
+
+        if !is_used(&name) {
+            return name;
+        }
+
+        let mut index = 2;
+        loop {
+            let suffix = format!(" ({index})");
+            let base: String = name.chars().take(31 - suffix.chars().count()).collect();
+            let candidate = format!("{}{suffix}", base.trim_end());
+
+            if !is_used(&candidate) {
+                return candidate;
+            }
+
+            index += 1;
+        }
+    }
+
     /// Get a mutable reference to the vector of worksheets.
     ///
     /// Get a mutable reference to the vector of Worksheets used by the Workbook
@@ -522,6 +621,87 @@ impl Workbook {
         &self.worksheets
     }
 
+    /// Get a group of worksheets to apply the same operations to.
+    ///
+    /// Create a [`WorksheetGroup`] from worksheets in the workbook so that the
+    /// same data, formatting and page setup can be applied to all of them at
+    /// once. This is similar to grouped sheet editing in Excel. The worksheets
+    /// in the group are in workbook order.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheetnames` - The names of the worksheets to group.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::UnknownWorksheetNameOrIndex`] - Error when one of the
+    ///   worksheet names doesn't exist in the workbook.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing the same headers and page
+    /// setup to a group of worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_group_intro.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, PageSetup, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let bold = Format::new().set_bold();
+    /// #     let page_setup = PageSetup::new().set_landscape().set_header("&CMonthly Sales");
+    /// #
+    /// #     for month in ["Jan", "Feb", "Mar"] {
+    /// #         let worksheet = workbook.add_worksheet();
+    /// #         worksheet.set_name(month)?;
+    /// #     }
+    /// #
+    ///     // Apply the same layout to all three worksheets.
+    ///     let mut group = workbook.group(&["Jan", "Feb", "Mar"])?;
+    ///     group.write_with_format(0, 0, "Region", &bold)?;
+    ///     group.write_with_format(0, 1, "Sales", &bold)?;
+    ///     group.set_page_setup(&page_setup)?;
+    ///     group.apply(|worksheet| {
+    ///         worksheet.set_column_width(0, 16)?;
+    ///         Ok(())
+    ///     })?;
+    /// #
+    /// #     // Add the worksheet specific data.
+    /// #     let worksheet = workbook.worksheet_from_name("Feb")?;
+    /// #     worksheet.write(1, 0, "North")?;
+    /// #     worksheet.write(1, 1, 1234)?;
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn group(&mut self, sheetnames: &[&str]) -> Result<WorksheetGroup<'_>, XlsxError> {
+        // Check that all the worksheets exist before creating the group.
+        for sheetname in sheetnames {
+            if !self
+                .worksheets
+                .iter()
+                .any(|worksheet| worksheet.name() == *sheetname)
+            {
+                return Err(XlsxError::UnknownWorksheetNameOrIndex(
+                    sheetname.to_string(),
+                ));
+            }
+        }
+
+        let worksheets = self
+            .worksheets
+            .iter_mut()
+            .filter(|worksheet| sheetnames.contains(&worksheet.name().as_str()))
+            .collect();
+
+        Ok(WorksheetGroup { worksheets })
+    }
+
     /// Add a worksheet object to a workbook.
     ///
     /// Add a worksheet created directly using `Workbook::new()` to a workbook.
@@ -661,11 +841,81 @@ impl Workbook {
         {
             // Some test code to test double/multiple saves.
             let file = std::fs::File::create(<&std::path::Path>::clone(&path.as_ref()))?;
-            self.save_internal(file)?;
+            self.save_internal(file, ZipWriter::new, None)?;
         }
 
         let file = std::fs::File::create(path)?;
-        self.save_internal(file)?;
+        self.save_internal(file, ZipWriter::new, None)?;
+        Ok(())
+    }
+
+    /// Save the Workbook as an xlsx file and report the progress of the save.
+    ///
+    /// The workbook `save_with_progress()` method is the same as the
+    /// [`save()`](Workbook::save) method except that it calls a user supplied
+    /// closure as each part of the xlsx file is written. This can be used to
+    /// display a progress bar in a GUI, or log the progress of long running
+    /// exports, when saving large workbooks.
+    ///
+    /// The closure is called with two parameters:
+    ///
+    /// * `stage` - The name of the part of the file that has just been
+    ///   generated, such as `xl/worksheets/sheet1.xml`.
+    /// * `fraction` - The fraction of the parts of the file that have been
+    ///   completed, in the range 0.0 to 1.0. The last call is always 1.0.
+    ///
+    /// The progress is measured in parts of the file rather than bytes, so
+    /// the fraction will increase slowly while a large worksheet is being
+    /// written and quickly for the small metadata parts that follow it.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path of the new Excel file to create as a `&str` or as
+    ///   a [`std::path`] `Path` or `PathBuf` instance.
+    /// * `progress` - A closure with the signature `FnMut(&str, f64)`.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::SheetnameReused`] - Worksheet name is already in use in
+    ///   the workbook.
+    /// * [`XlsxError::IoError`] - A wrapper for various IO errors when creating
+    ///   the xlsx file, or its sub-files.
+    /// * [`XlsxError::ZipError`] - A wrapper for various zip errors when
+    ///   creating the xlsx file, or its sub-files.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates displaying the progress of saving a
+    /// workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_save_with_progress.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     for _ in 0..3 {
+    ///         let worksheet = workbook.add_worksheet();
+    ///         worksheet.write_string(0, 0, "Hello")?;
+    ///     }
+    ///
+    ///     workbook.save_with_progress("workbook.xlsx", |stage, fraction| {
+    ///         println!("{:3.0}% {stage}", fraction * 100.0);
+    ///     })?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn save_with_progress<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        mut progress: impl FnMut(&str, f64),
+    ) -> Result<(), XlsxError> {
+        let file = std::fs::File::create(path)?;
+        self.save_internal(file, ZipWriter::new, Some(&mut progress))?;
         Ok(())
     }
 
@@ -711,10 +961,134 @@ impl Workbook {
     pub fn save_to_buffer(&mut self) -> Result<Vec<u8>, XlsxError> {
         let mut buf = vec![];
         let cursor = Cursor::new(&mut buf);
-        self.save_internal(cursor)?;
+        self.save_internal(cursor, ZipWriter::new, None)?;
         Ok(buf)
     }
 
+    /// Save the Workbook as an Excel template file.
+    ///
+    /// The workbook `save_as_template()` method is the same as the
+    /// [`save()`](Workbook::save) method except that the file is saved as an
+    /// Excel `xltx` template. When a user opens a template in Excel it creates
+    /// a new, unsaved, workbook based on the template rather than editing the
+    /// template itself.
+    ///
+    /// The only difference in the file structure is the content type of the
+    /// main workbook part. The file extension isn't changed so the path should
+    /// end in `.xltx` to be recognized by Excel as a template.
+    ///
+    /// Macro enabled `xltm` templates aren't supported since `rust_xlsxwriter`
+    /// doesn't support VBA macros.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the new Excel template file to create as a `&str`
+    ///   or as a [`std::path`] Path or `PathBuf` instance.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::SheetnameReused`] - Worksheet name is already in use in
+    ///   the workbook.
+    /// * [`XlsxError::IoError`] - A wrapper for various IO errors when creating
+    ///   the xlsx file, or its sub-files.
+    /// * [`XlsxError::ZipError`] - A wrapper for various zip errors when
+    ///   creating the xlsx file, or its sub-files.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates saving a workbook as an Excel
+    /// template.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_save_as_template.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Invoice")?;
+    ///
+    ///     workbook.save_as_template("template.xltx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn save_as_template<P: AsRef<Path>>(&mut self, path: P) -> Result<(), XlsxError> {
+        let file = std::fs::File::create(path)?;
+
+        self.is_template = true;
+        let result = self.save_internal(file, ZipWriter::new, None);
+        self.is_template = false;
+
+        result
+    }
+
+    /// Save the Workbook as an xlsx file to a type that implements the
+    /// [`Write`] trait.
+    ///
+    /// The workbook `save_to_writer()` method is similar to the
+    /// [`save()`](Workbook::save) method except that it writes the xlsx file
+    /// to any type that implements the [`Write`] trait such as a
+    /// [`std::fs::File`], [`std::io::Stdout`], a network socket or a web
+    /// framework response body.
+    ///
+    /// The writer doesn't need to implement [`Seek`] since the zip container
+    /// is streamed out sequentially. This avoids having to buffer the entire
+    /// file in memory, as is the case with
+    /// [`save_to_buffer()`](Workbook::save_to_buffer), before sending it on.
+    /// In this streaming mode the size and checksum of each file in the zip
+    /// container are written in a "data descriptor" after the file data,
+    /// rather than in its header, so the output isn't byte-for-byte the same
+    /// as that of [`save()`](Workbook::save). Some strict third party xlsx
+    /// readers don't accept data descriptors so
+    /// [`save()`](Workbook::save) or
+    /// [`save_to_buffer()`](Workbook::save_to_buffer) should be used if the
+    /// file is intended for those applications.
+    ///
+    /// # Parameters
+    ///
+    /// * `writer` - An object that implements the [`Write`] trait.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::SheetnameReused`] - Worksheet name is already in use in
+    ///   the workbook.
+    /// * [`XlsxError::IoError`] - A wrapper for various IO errors when creating
+    ///   the xlsx file, or its sub-files.
+    /// * [`XlsxError::ZipError`] - A wrapper for various zip errors when
+    ///   creating the xlsx file, or its sub-files.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a simple workbook and
+    /// streaming it to STDOUT, which doesn't support seeking.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_save_to_writer.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///
+    ///     // Stream the file to STDOUT, for example: `cargo run > hello.xlsx`.
+    ///     workbook.save_to_writer(std::io::stdout())?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    pub fn save_to_writer<W: Write>(&mut self, writer: W) -> Result<(), XlsxError> {
+        self.save_internal(writer, ZipWriter::new_stream, None)?;
+        Ok(())
+    }
+
     // Set the index for the format. This is currently only used in testing but
     // may be used publicly at a later stage.
     #[doc(hidden)]
@@ -1032,29 +1406,282 @@ impl Workbook {
         self
     }
 
-    // -----------------------------------------------------------------------
-    // Internal function/methods.
-    // -----------------------------------------------------------------------
-
-    // Reset workbook between saves.
-    fn reset(&mut self) {
-        self.writer.reset();
-
-        self.xf_indices = HashMap::from([(Format::default(), 0)]);
-        self.xf_formats = vec![Format::default()];
-        self.font_count = 0;
-        self.fill_count = 0;
-        self.border_count = 0;
-        self.num_formats = vec![];
+    /// Add a password that is required to save changes to the file.
+    ///
+    /// This method can be used to set the Excel “Password to modify” option
+    /// that is available when saving a file. When the file is opened the user
+    /// is asked for the password, or to open the file in "read-only" mode. In
+    /// read-only mode any changes to the file can’t be saved back to the same
+    /// file and must be saved to a new file.
+    ///
+    /// This can be combined with
+    /// [`read_only_recommended()`](Workbook::read_only_recommended).
+    ///
+    /// **Note**: The password offers very weak protection. It is stored as a
+    /// hash in the file, it doesn't encrypt the data, and the option can be
+    /// removed by editing the file. It is intended to prevent accidental
+    /// changes to the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `password` - The password string. Note, only ascii text passwords are
+    ///   supported. Passing the empty string "" turns the option off.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a simple workbook that
+    /// requires a password to save changes to the file.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_read_only_with_password.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let _worksheet = workbook.add_worksheet();
+    ///
+    ///     workbook.read_only_with_password("abc123");
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn read_only_with_password(&mut self, password: &str) -> &mut Workbook {
+        self.reservation_password = utility::hash_password(password);
+        self
+    }
 
-        for worksheet in &mut self.worksheets {
-            worksheet.reset();
-        }
+    /// Set the zip compression level used when saving the workbook.
+    ///
+    /// An xlsx file is a zip container of xml and image files. By default the
+    /// files are compressed with the deflate method at a level that balances
+    /// file size against save time. Since compression dominates the save time
+    /// of large workbooks it can be useful to change this trade-off.
+    ///
+    /// For example [`CompressionLevel::Stored`] is much faster to save and is
+    /// a good choice for files that will be re-compressed in transit or
+    /// archived in a compressed format anyway.
+    ///
+    /// # Parameters
+    ///
+    /// * `level` - A [`CompressionLevel`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates saving a workbook without zip
+    /// compression to reduce the save time.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_compression.rs
+    /// #
+    /// # use rust_xlsxwriter::{CompressionLevel, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///
+    ///     workbook.set_compression(CompressionLevel::Stored);
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_compression(&mut self, level: CompressionLevel) -> &mut Workbook {
+        self.compression = level;
+        self
+    }
+
+    /// Turn on deterministic output so that the same workbook data always
+    /// produces a byte-identical file.
+    ///
+    /// By default the document creation time, which is stored in the
+    /// `docProps/core.xml` metadata file, is set to the time that the file is
+    /// saved. This means that two files saved from the same data will be
+    /// different. This makes the files unsuitable for build caching or
+    /// snapshot testing.
+    ///
+    /// In deterministic mode the creation date set via
+    /// [`DocProperties::set_creation_datetime()`] is used for the document
+    /// metadata and for the timestamps of the files in the xlsx zip
+    /// container. If no creation date has been set a fixed date of
+    /// 1980-01-01T00:00:00Z is used, which is also the earliest date that can
+    /// be stored in a zip file.
+    ///
+    /// Zip file timestamps can only store dates in the range 1980 to 2107,
+    /// with a 2 second resolution. Creation dates outside that range are
+    /// clamped to the nearest valid zip date for the file timestamps but are
+    /// stored unchanged in the document metadata.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates saving a workbook in deterministic
+    /// mode so that the output is the same each time it is saved.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_deterministic.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///
+    ///     workbook.set_deterministic(true);
+    ///
+    ///     let first = workbook.save_to_buffer()?;
+    ///     let second = workbook.save_to_buffer()?;
+    ///
+    ///     assert_eq!(first, second);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_deterministic(&mut self, enable: bool) -> &mut Workbook {
+        self.deterministic = enable;
+        self
+    }
+
+    /// Display all the worksheets in the workbook from right to left.
+    ///
+    /// The `set_right_to_left_interface()` method is used to change the
+    /// direction of every worksheet in the workbook to right-to-left, with the
+    /// A1 cell in the top right. When the active worksheet is right-to-left
+    /// Excel also displays the worksheet tabs on the right hand side of the
+    /// window.
+    ///
+    /// Excel doesn't store a workbook level direction in the file so this is
+    /// equivalent to calling
+    /// [`Worksheet::set_right_to_left()`](crate::Worksheet::set_right_to_left)
+    /// on each worksheet, including worksheets added after this method is
+    /// called. It is applied when the workbook is saved.
+    ///
+    /// # Parameters
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates changing the direction of all the
+    /// worksheets in a workbook to right-to-left.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_right_to_left_interface.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.add_worksheet().write_string(0, 0, "نص عربي")?;
+    ///     workbook.add_worksheet().write_string(0, 0, "نص عربي")?;
+    ///
+    ///     workbook.set_right_to_left_interface(true);
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_right_to_left_interface(&mut self, enable: bool) -> &mut Workbook {
+        self.right_to_left = enable;
+        self
+    }
+
+    /// Set the default handling of strings that exceed Excel's length limit.
+    ///
+    /// The `set_string_overflow()` method sets the [`StringOverflow`] option
+    /// that worksheets added with
+    /// [`add_worksheet()`](Workbook::add_worksheet) start with. This avoids
+    /// having to set it on each worksheet in data pipelines that add a
+    /// worksheet per table or query. See
+    /// [`Worksheet::set_string_overflow()`](crate::Worksheet::set_string_overflow)
+    /// for details of the options.
+    ///
+    /// The default only applies to worksheets added after this method is
+    /// called and it can be overridden for individual worksheets. Worksheets
+    /// created separately and added with
+    /// [`push_worksheet()`](Workbook::push_worksheet) keep their own setting.
+    ///
+    /// # Parameters
+    ///
+    /// * `overflow` - A [`StringOverflow`] enum value. The default is
+    ///   [`StringOverflow::Error`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates truncating long strings in all the
+    /// worksheets in a workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_string_overflow.rs
+    /// #
+    /// # use rust_xlsxwriter::{StringOverflow, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     workbook.set_string_overflow(StringOverflow::Truncate);
+    ///
+    ///     // The strings are truncated to 32,767 characters, ending in "…".
+    ///     let notes = "Lorem ipsum. ".repeat(3000);
+    ///     workbook.add_worksheet().write_string(0, 0, &notes)?;
+    ///     workbook.add_worksheet().write_string(0, 0, &notes)?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_string_overflow(&mut self, overflow: StringOverflow) -> &mut Workbook {
+        self.string_overflow = overflow;
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal function/methods.
+    // -----------------------------------------------------------------------
+
+    // Reset workbook between saves.
+    fn reset(&mut self) {
+        self.writer.reset();
+
+        self.xf_indices = HashMap::from([(Format::default(), 0)]);
+        self.xf_formats = vec![Format::default()];
+        self.font_count = 0;
+        self.fill_count = 0;
+        self.border_count = 0;
+        self.num_formats = vec![];
+
+        for worksheet in &mut self.worksheets {
+            worksheet.reset();
+        }
     }
 
     // Internal function to prepare the workbook and other component files for
     // writing to the xlsx file.
-    fn save_internal<W: Write + Seek>(&mut self, writer: W) -> Result<(), XlsxError> {
+    fn save_internal<W: Write, Z: Write + Seek>(
+        &mut self,
+        writer: W,
+        new_zip: impl FnOnce(ZipOutput<W>) -> ZipWriter<Z>,
+        progress: Option<ProgressCallback>,
+    ) -> Result<(), XlsxError> {
         // Reset workbook and worksheet state data between saves.
         self.reset();
 
@@ -1077,30 +1704,28 @@ impl Workbook {
             }
         }
 
-        // Convert any worksheet local formats to workbook/global formats.
-        let mut worksheet_formats: Vec<Vec<Format>> = vec![];
-        for worksheet in &self.worksheets {
-            let formats = worksheet.xf_formats.clone();
-            worksheet_formats.push(formats);
-        }
+        // Convert any worksheet local formats to workbook/global formats. The
+        // worksheets are moved out of the workbook temporarily so that their
+        // formats can be registered without cloning them first.
+        let mut worksheets = mem::take(&mut self.worksheets);
+        for worksheet in &mut worksheets {
+            let indices: Vec<u32> = worksheet
+                .xf_formats
+                .iter()
+                .map(|format| self.format_index(format))
+                .collect();
 
-        let mut worksheet_indices: Vec<Vec<u32>> = vec![];
-        for formats in &worksheet_formats {
-            let mut indices = vec![];
-            for format in formats {
-                let index = self.format_index(format);
-                indices.push(index);
-            }
-            worksheet_indices.push(indices);
-        }
-
-        for (i, worksheet) in self.worksheets.iter_mut().enumerate() {
             // Map worksheet/local format indices to the workbook/global values.
-            worksheet.set_global_xf_indices(&worksheet_indices[i]);
+            worksheet.set_global_xf_indices(&indices);
 
             // Perform the autofilter row hiding.
             worksheet.hide_autofilter_rows();
+
+            // Apply the workbook level right to left direction for this save.
+            // The worksheet's own setting is left unchanged.
+            worksheet.workbook_right_to_left = self.right_to_left;
         }
+        self.worksheets = worksheets;
 
         // Convert the images in the workbooks into drawing files and rel links.
         self.prepare_drawings();
@@ -1119,8 +1744,8 @@ impl Workbook {
         package_options = self.set_package_options(package_options)?;
 
         // Create the Packager object that will assemble the zip/xlsx file.
-        let mut packager = Packager::new(writer);
-        packager.assemble_file(self, &package_options)?;
+        let packager = Packager::new(writer, new_zip, &package_options);
+        packager.assemble_file(self, &package_options, progress)?;
 
         Ok(())
     }
@@ -1153,7 +1778,7 @@ impl Workbook {
         let mut header_footer_image_ids: HashMap<u64, u32> = HashMap::new();
 
         for worksheet in &mut self.worksheets {
-            if !worksheet.images.is_empty() {
+            if worksheet.has_images() {
                 worksheet.prepare_worksheet_images(&mut worksheet_image_ids, drawing_id);
             }
 
@@ -1162,7 +1787,7 @@ impl Workbook {
             }
 
             // Increase the drawing number/id for image/chart file.
-            if !worksheet.images.is_empty() || !worksheet.charts.is_empty() {
+            if worksheet.has_images() || !worksheet.charts.is_empty() {
                 drawing_id += 1;
             }
 
@@ -1247,6 +1872,14 @@ impl Workbook {
                             chart_caches
                                 .insert(series.category_range.key(), ChartSeriesCacheData::new());
                         }
+                        if let Some(data_label) = &series.data_label {
+                            if data_label.label_range.has_data() {
+                                chart_caches.insert(
+                                    data_label.label_range.key(),
+                                    ChartSeriesCacheData::new(),
+                                );
+                            }
+                        }
                         for data_label in &series.custom_data_labels {
                             if data_label.title.range.has_data() {
                                 chart_caches.insert(
@@ -1292,6 +1925,12 @@ impl Workbook {
                             series.category_cache_data = cache.clone();
                         }
 
+                        if let Some(data_label) = &mut series.data_label {
+                            if let Some(cache) = chart_caches.get(&data_label.label_range.key()) {
+                                data_label.label_cache_data = cache.clone();
+                            }
+                        }
+
                         for data_label in &mut series.custom_data_labels {
                             if let Some(cache) = chart_caches.get(&data_label.title.range.key()) {
                                 data_label.title.cache_data = cache.clone();
@@ -1479,6 +2118,18 @@ impl Workbook {
     ) -> Result<PackagerOptions, XlsxError> {
         package_options.num_worksheets = self.worksheets.len() as u16;
         package_options.doc_security = self.read_only_mode;
+        package_options.compression = self.compression;
+        package_options.deterministic = self.deterministic;
+        package_options.is_template = self.is_template;
+        package_options.has_checkboxes = self.xf_formats.iter().any(|format| format.checkbox);
+
+        // Use the same creation time in all parts of the file. In deterministic
+        // mode a fixed date is used if the user hasn't set one.
+        package_options.creation_time = match self.properties.creation_time {
+            Some(datetime) => datetime,
+            None if self.deterministic => Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap(),
+            None => Utc::now(),
+        };
 
         let mut defined_names = self.user_defined_names.clone();
         let mut sheet_names: HashMap<String, u16> = HashMap::new();
@@ -1499,7 +2150,7 @@ impl Workbook {
 
             package_options.properties = self.properties.clone();
 
-            if worksheet.uses_string_table {
+            if worksheet.uses_string_table && !worksheet.use_inline_strings {
                 package_options.has_sst_table = true;
             }
 
@@ -1553,6 +2204,11 @@ impl Workbook {
             }
         }
 
+        // The document thumbnail also needs a content type for its format.
+        if let Some(thumbnail) = &self.properties.thumbnail {
+            package_options.image_types[thumbnail.image_type.clone() as usize] = true;
+        }
+
         // Map the sheet name and associated index so that we can map a sheet
         // reference in a Local/Sheet defined name to a worksheet index.
         for defined_name in &mut defined_names {
@@ -1603,7 +2259,7 @@ impl Workbook {
         self.write_file_version();
 
         // Write the fileSharing element.
-        if self.read_only_mode == 2 {
+        if self.read_only_mode == 2 || self.reservation_password != 0 {
             self.write_file_sharing();
         }
 
@@ -1652,7 +2308,18 @@ impl Workbook {
 
     // Write the <fileSharing> element.
     fn write_file_sharing(&mut self) {
-        let attributes = [("readOnlyRecommended", "1")];
+        let mut attributes = vec![];
+
+        if self.read_only_mode == 2 {
+            attributes.push(("readOnlyRecommended", "1".to_string()));
+        }
+
+        if self.reservation_password != 0 {
+            attributes.push((
+                "reservationPassword",
+                format!("{:04X}", self.reservation_password),
+            ));
+        }
 
         self.writer.xml_empty_tag("fileSharing", &attributes);
     }
@@ -1735,17 +2402,18 @@ impl Workbook {
         self.writer.xml_start_tag_only("definedNames");
 
         for defined_name in &self.defined_names {
-            let mut attributes = vec![("name", defined_name.name())];
+            let mut attributes = vec![("name", Cow::Owned(defined_name.name()))];
 
             match defined_name.name_type {
                 DefinedNameType::Global => {}
                 _ => {
-                    attributes.push(("localSheetId", defined_name.index.to_string()));
+                    let local_sheet_id = defined_name.index.to_string();
+                    attributes.push(("localSheetId", Cow::Owned(local_sheet_id)));
                 }
             }
 
             if let DefinedNameType::Autofilter = defined_name.name_type {
-                attributes.push(("hidden", "1".to_string()));
+                attributes.push(("hidden", Cow::Borrowed("1")));
             }
 
             self.writer
@@ -1767,15 +2435,49 @@ impl Workbook {
 // Helper enums/structs/functions.
 // -----------------------------------------------------------------------
 
+/// The zip compression level used when saving a workbook.
+///
+/// Used with [`workbook.set_compression()`](Workbook::set_compression).
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionLevel {
+    /// Deflate compression at the default level. This is the default.
+    Default,
+
+    /// Deflate compression at the fastest level. Produces larger files than
+    /// the default level but saves faster.
+    Fast,
+
+    /// Deflate compression at the best level. Produces the smallest files but
+    /// is the slowest to save.
+    Best,
+
+    /// No compression. The files are stored in the zip container as is. This
+    /// is the fastest to save but produces the largest files.
+    Stored,
+}
+
 // -----------------------------------------------------------------------
 // Tests.
 // -----------------------------------------------------------------------
 #[cfg(test)]
 mod tests {
 
+    use crate::packager::MAX_XML_BUFFERS;
+    use crate::shared_strings::SharedStrings;
+    use crate::image::XlsxImageType;
+    use crate::shared_strings_table::SharedStringsTable;
+    use crate::xmlwriter::XML_CHUNK_SIZE;
     use crate::Workbook;
-    use crate::{test_functions::xml_to_vec, XlsxError};
+    use crate::{
+        test_functions::xml_to_vec, Chart, ChartType, CompressionLevel, DocProperties,
+        FilterCondition, Format, HeaderImagePosition, Image, StringOverflow, Table, Worksheet,
+        XlsxError,
+    };
+    use chrono::{TimeZone, Utc};
     use pretty_assertions::assert_eq;
+    use std::io::{Cursor, Read};
+    use zip::ZipWriter;
 
     #[test]
     fn test_assemble() {
@@ -1836,6 +2538,24 @@ mod tests {
         assert!(matches!(result, Err(XlsxError::SheetnameReused(_))));
     }
 
+    #[test]
+    fn unique_worksheet_name() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().set_name("Data").unwrap();
+        workbook.add_worksheet().set_name("Data (2)").unwrap();
+        workbook
+            .add_worksheet()
+            .set_name("A very long worksheet name here")
+            .unwrap();
+
+        assert_eq!("Report", workbook.unique_worksheet_name("Report"));
+        assert_eq!("DATA (3)", workbook.unique_worksheet_name("DATA"));
+        assert_eq!(
+            "A very long worksheet name (2)",
+            workbook.unique_worksheet_name("A very long worksheet name here")
+        );
+    }
+
     #[test]
     fn duplicate_tables() {
         let mut workbook = Workbook::default();
@@ -1853,4 +2573,530 @@ mod tests {
 
         assert!(matches!(result, Err(XlsxError::TableNameReused(_))));
     }
+
+    #[test]
+    fn worksheet_xml_chunks() {
+        let mut workbook = Workbook::default();
+        let worksheet = workbook.add_worksheet();
+        for row in 0..12_000 {
+            for col in 0..10 {
+                worksheet.write(row, col, row).unwrap();
+            }
+        }
+
+        let buf = workbook.save_to_buffer().unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+        let mut got = String::new();
+        archive
+            .by_name("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut got)
+            .unwrap();
+
+        // The worksheet xml is larger than a chunk so it is written to the
+        // zip file in several parts. Compare it to the unchunked xml.
+        let worksheet = &mut workbook.worksheets[0];
+        worksheet.writer.reset();
+        worksheet.assemble_xml_file(&mut SharedStringsTable::new());
+        let expected = worksheet.writer.read_to_str();
+
+        assert!(expected.len() > 2 * XML_CHUNK_SIZE);
+        assert!(expected == got);
+    }
+
+    #[test]
+    fn shared_strings_chunks() {
+        let mut workbook = Workbook::default();
+        let worksheet = workbook.add_worksheet();
+        let mut string_table = SharedStringsTable::new();
+        for row in 0..50_000 {
+            let string = format!("String number {row}");
+            worksheet.write(row, 0, &string).unwrap();
+            string_table.shared_string_index(string.into());
+        }
+
+        let buf = workbook.save_to_buffer().unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+        let mut got = String::new();
+        archive
+            .by_name("xl/sharedStrings.xml")
+            .unwrap()
+            .read_to_string(&mut got)
+            .unwrap();
+
+        let mut shared_strings = SharedStrings::new();
+        shared_strings.assemble_xml_file(&string_table);
+        let expected = shared_strings.writer.read_to_str();
+
+        assert!(expected.len() > XML_CHUNK_SIZE);
+        assert!(expected == got);
+    }
+
+    #[test]
+    fn reuse_xml_buffers() {
+        let mut workbook = Workbook::default();
+        workbook.set_deterministic(true);
+
+        for _ in 0..3 {
+            let worksheet = workbook.add_worksheet();
+            for row in 0..1000 {
+                worksheet.write(row, 0, row).unwrap();
+            }
+        }
+
+        let first = workbook.save_to_buffer().unwrap();
+        assert!(!workbook.xml_buffers.is_empty());
+        assert!(workbook.xml_buffers.len() <= MAX_XML_BUFFERS);
+
+        // The pooled buffers are reused by the worksheets in the next save.
+        let second = workbook.save_to_buffer().unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn read_only_with_password() {
+        let mut workbook = Workbook::default();
+        workbook.read_only_with_password("password");
+
+        workbook.add_worksheet();
+        workbook.assemble_xml_file();
+        let got = workbook.writer.read_to_str();
+        assert!(got.contains(r#"<fileSharing reservationPassword="83AF"/>"#));
+
+        workbook.writer.reset();
+        workbook.read_only_recommended();
+        workbook.assemble_xml_file();
+        let got = workbook.writer.read_to_str();
+        assert!(
+            got.contains(r#"<fileSharing readOnlyRecommended="1" reservationPassword="83AF"/>"#)
+        );
+    }
+
+    #[test]
+    fn set_thumbnail() {
+        let mut workbook = Workbook::default();
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+        let properties = DocProperties::new().set_thumbnail(&image);
+        workbook.set_properties(&properties);
+        workbook.add_worksheet();
+
+        // The thumbnail image type needs a content type, even though it isn't
+        // used in a worksheet.
+        let options = workbook
+            .set_package_options(crate::workbook::PackagerOptions::new())
+            .unwrap();
+        assert!(options.image_types[XlsxImageType::Jpg as usize]);
+        assert!(!options.image_types[XlsxImageType::Png as usize]);
+    }
+
+    #[test]
+    fn set_right_to_left_interface() {
+        let mut workbook = Workbook::default();
+        workbook.set_right_to_left_interface(true);
+        workbook.add_worksheet();
+        workbook.add_worksheet();
+
+        workbook.save_to_buffer().unwrap();
+        assert!(workbook.worksheets[0].workbook_right_to_left);
+        assert!(workbook.worksheets[1].workbook_right_to_left);
+
+        // Turning the option off again shouldn't leave the worksheets in the
+        // right to left direction.
+        workbook.set_right_to_left_interface(false);
+
+        workbook.save_to_buffer().unwrap();
+        assert!(!workbook.worksheets[0].workbook_right_to_left);
+        assert!(!workbook.worksheets[1].workbook_right_to_left);
+    }
+
+    #[test]
+    fn set_string_overflow() {
+        let notes = "Lorem ipsum. ".repeat(3000);
+
+        let mut workbook = Workbook::default();
+        let result = workbook.add_worksheet().write_string(0, 0, &notes);
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded(_))));
+
+        // Worksheets added after the default is set inherit it.
+        workbook.set_string_overflow(StringOverflow::Truncate);
+        let worksheet = workbook.add_worksheet();
+        assert!(worksheet.write_string(0, 0, &notes).is_ok());
+
+        // The default can be overridden for a worksheet.
+        worksheet.set_string_overflow(StringOverflow::Error);
+        let result = worksheet.write_string(0, 0, &notes);
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded(_))));
+
+        // Worksheets created outside the workbook keep their own setting.
+        let mut worksheet = Worksheet::new();
+        let result = worksheet.write_string(0, 0, &notes);
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded(_))));
+        workbook.push_worksheet(worksheet);
+    }
+
+    #[test]
+    fn zip_entry_layout() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().write(0, 0, "Hello").unwrap();
+
+        let buf = workbook.save_to_buffer().unwrap();
+
+        // The content types file must be the first entry and the local file
+        // headers shouldn't have data descriptors or extra fields.
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+        assert_eq!("[Content_Types].xml", archive.by_index(0).unwrap().name());
+
+        for i in 0..archive.len() {
+            let offset = archive.by_index(i).unwrap().header_start() as usize;
+            let header = &buf[offset..offset + 30];
+
+            assert_eq!(b"PK\x03\x04", &header[0..4]);
+            assert_eq!(0, u16::from_le_bytes([header[6], header[7]]) & 0x0008);
+            assert_eq!(0, u16::from_le_bytes([header[28], header[29]]));
+        }
+    }
+
+    #[test]
+    fn inline_strings() {
+        for inline in [false, true] {
+            let mut workbook = Workbook::default();
+            let worksheet = workbook.add_worksheet();
+            worksheet.set_inline_strings(inline);
+            worksheet.write(0, 0, "Foo").unwrap();
+
+            let buf = workbook.save_to_buffer().unwrap();
+            let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+
+            assert_eq!(!inline, archive.by_name("xl/sharedStrings.xml").is_ok());
+        }
+    }
+
+    #[test]
+    fn set_compression() {
+        let mut sizes = vec![];
+
+        for level in [
+            CompressionLevel::Stored,
+            CompressionLevel::Fast,
+            CompressionLevel::Best,
+        ] {
+            let mut workbook = Workbook::default();
+            let worksheet = workbook.add_worksheet();
+            for row in 0..100 {
+                worksheet.write(row, 0, row).unwrap();
+            }
+
+            workbook.set_compression(level);
+            let buf = workbook.save_to_buffer().unwrap();
+
+            let expected = match level {
+                CompressionLevel::Stored => zip::CompressionMethod::Stored,
+                _ => zip::CompressionMethod::Deflated,
+            };
+
+            let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+            for i in 0..archive.len() {
+                assert_eq!(expected, archive.by_index(i).unwrap().compression());
+            }
+
+            sizes.push(buf.len());
+        }
+
+        assert!(sizes[0] > sizes[1]);
+        assert!(sizes[1] >= sizes[2]);
+    }
+
+    #[test]
+    fn set_deterministic() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet();
+        workbook.set_deterministic(true);
+
+        // Check for the fixed creation date.
+        let buf = workbook.save_to_buffer().unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+
+        let mut core = String::new();
+        archive
+            .by_name("docProps/core.xml")
+            .unwrap()
+            .read_to_string(&mut core)
+            .unwrap();
+        assert!(core.contains(">1980-01-01T00:00:00Z</dcterms:created>"));
+
+        // Check that a user defined creation date is used everywhere.
+        let date = Utc.with_ymd_and_hms(2023, 6, 15, 12, 30, 0).unwrap();
+        workbook.set_properties(&DocProperties::new().set_creation_datetime(&date));
+
+        let buf = workbook.save_to_buffer().unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+
+        for i in 0..archive.len() {
+            let datetime = archive.by_index(i).unwrap().last_modified().unwrap();
+            assert_eq!(
+                (2023, 6, 15, 12, 30),
+                (
+                    datetime.year(),
+                    datetime.month(),
+                    datetime.day(),
+                    datetime.hour(),
+                    datetime.minute()
+                )
+            );
+        }
+
+        let mut core = String::new();
+        archive
+            .by_name("docProps/core.xml")
+            .unwrap()
+            .read_to_string(&mut core)
+            .unwrap();
+        assert!(core.contains(">2023-06-15T12:30:00Z</dcterms:modified>"));
+
+        // Check that repeated saves are identical.
+        assert_eq!(buf, workbook.save_to_buffer().unwrap());
+    }
+
+    #[test]
+    fn set_deterministic_clamped_date() {
+        let date = Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap();
+
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet();
+        workbook.set_deterministic(true);
+        workbook.set_properties(&DocProperties::new().set_creation_datetime(&date));
+
+        let buf = workbook.save_to_buffer().unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+
+        // The zip timestamps are clamped to the earliest zip date.
+        for i in 0..archive.len() {
+            let datetime = archive.by_index(i).unwrap().last_modified().unwrap();
+            assert_eq!(
+                (1980, 1, 1),
+                (datetime.year(), datetime.month(), datetime.day())
+            );
+        }
+
+        // The document metadata keeps the user date.
+        let mut core = String::new();
+        archive
+            .by_name("docProps/core.xml")
+            .unwrap()
+            .read_to_string(&mut core)
+            .unwrap();
+        assert!(core.contains(">1970-01-01T00:00:00Z</dcterms:created>"));
+    }
+
+    #[test]
+    fn set_deterministic_ordering() {
+        // Several parts of the workbook are stored in HashMaps, which have a
+        // different random iteration order for each instance. Check that
+        // separately created workbooks with the same data are identical.
+        fn create_workbook() -> Vec<u8> {
+            let mut workbook = Workbook::default();
+            workbook.set_deterministic(true);
+
+            let worksheet = workbook.add_worksheet();
+            for col in 0..20 {
+                let format = Format::new().set_font_size(8 + col);
+                worksheet.set_column_width(col, 10 + col).unwrap();
+                worksheet.set_row_height(col.into(), 20).unwrap();
+                worksheet
+                    .write_with_format(0, col, format!("Header {col}"), &format)
+                    .unwrap();
+                for row in 1..20 {
+                    worksheet.write(row, col, format!("{row}:{col}")).unwrap();
+                }
+            }
+
+            worksheet.autofilter(0, 0, 19, 19).unwrap();
+            for col in 0..10 {
+                let filter = FilterCondition::new().add_list_filter(format!("1:{col}").as_str());
+                worksheet.filter_column(col, &filter).unwrap();
+            }
+
+            worksheet
+                .merge_range(21, 0, 21, 3, "Merged", &Format::new())
+                .unwrap();
+
+            workbook.save_to_buffer().unwrap()
+        }
+
+        assert_eq!(create_workbook(), create_workbook());
+    }
+
+    #[test]
+    fn save_with_progress() {
+        let mut workbook = Workbook::default();
+        let image = Image::new("tests/input/images/red.png").unwrap();
+        let properties = DocProperties::new().set_custom_property("Checked", true);
+        workbook.set_properties(&properties);
+
+        // Add worksheets with all of the optional parts of a file.
+        let worksheet = workbook.add_worksheet();
+        worksheet.write(0, 0, "Hello").unwrap();
+        worksheet.write(0, 1, 1).unwrap();
+        worksheet
+            .write_url(1, 0, "https://www.rust-lang.org")
+            .unwrap();
+        worksheet
+            .write_dynamic_formula(2, 0, "=LEN(A1:A2)")
+            .unwrap();
+        worksheet.insert_image(4, 0, &image).unwrap();
+        worksheet.set_header("&C&[Picture]");
+        worksheet
+            .set_header_image(&image, HeaderImagePosition::Center)
+            .unwrap();
+        worksheet.add_table(10, 0, 12, 2, &Table::new()).unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values(("Sheet1", 0, 1, 0, 1));
+        worksheet.insert_chart(4, 4, &chart).unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_url(0, 0, "internal:Sheet1!A1").unwrap();
+        worksheet.insert_image(4, 0, &image).unwrap();
+
+        let mut buf = vec![];
+        let mut stages = vec![];
+        let mut fractions = vec![];
+        let mut progress = |stage: &str, fraction: f64| {
+            stages.push(stage.to_string());
+            fractions.push(fraction);
+        };
+
+        workbook
+            .save_internal(Cursor::new(&mut buf), ZipWriter::new, Some(&mut progress))
+            .unwrap();
+
+        // Check that every file is reported and that the progress is complete.
+        let archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+        let filenames: Vec<&str> = archive.file_names().collect();
+        assert_eq!(filenames, stages);
+
+        assert!(fractions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(Some(&1.0), fractions.last());
+    }
+
+    #[test]
+    fn save_to_failing_writer() {
+        struct FailingWriter;
+
+        impl std::io::Write for FailingWriter {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::PermissionDenied.into())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().write(0, 0, "Hello").unwrap();
+
+        // The error from the zip writer thread should be returned.
+        let result = workbook.save_to_writer(FailingWriter);
+        assert!(matches!(
+            result,
+            Err(XlsxError::ZipError(zip::result::ZipError::Io(ref e)))
+                if e.kind() == std::io::ErrorKind::PermissionDenied
+        ));
+    }
+
+    #[test]
+    fn save_to_failing_writer_without_output() {
+        // The zip writer reports errors to stderr if it is dropped before the
+        // file is finished. Since that output isn't captured by the test
+        // harness the failing save is run in a child process.
+        if std::env::var_os("RUST_XLSXWRITER_FAILING_SAVE").is_some() {
+            struct FailingWriter;
+
+            impl std::io::Write for FailingWriter {
+                fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                    Err(std::io::ErrorKind::PermissionDenied.into())
+                }
+
+                fn flush(&mut self) -> std::io::Result<()> {
+                    Ok(())
+                }
+            }
+
+            impl std::io::Seek for FailingWriter {
+                fn seek(&mut self, _pos: std::io::SeekFrom) -> std::io::Result<u64> {
+                    Ok(0)
+                }
+            }
+
+            let mut workbook = Workbook::default();
+            workbook.add_worksheet().write(0, 0, "Hello").unwrap();
+
+            // Test the streaming and the seekable zip writers.
+            assert!(workbook.save_to_writer(FailingWriter).is_err());
+            assert!(workbook
+                .save_internal(FailingWriter, ZipWriter::new, None)
+                .is_err());
+            return;
+        }
+
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "workbook::tests::save_to_failing_writer_without_output",
+                "--nocapture",
+                "--test-threads=1",
+            ])
+            .env("RUST_XLSXWRITER_FAILING_SAVE", "1")
+            .output()
+            .unwrap();
+
+        assert!(output.status.success());
+        assert_eq!("", String::from_utf8_lossy(&output.stderr));
+    }
+
+    #[test]
+    fn worksheet_group() {
+        let mut workbook = Workbook::default();
+        for name in ["Jan", "Feb", "Mar"] {
+            workbook.add_worksheet().set_name(name).unwrap();
+        }
+
+        let result = workbook.group(&["Jan", "Apr"]);
+        assert!(matches!(
+            result,
+            Err(XlsxError::UnknownWorksheetNameOrIndex(ref name)) if name == "Apr"
+        ));
+
+        let bold = Format::new().set_bold();
+        let mut group = workbook.group(&["Mar", "Jan"]).unwrap();
+        assert_eq!(group.len(), 2);
+
+        group.write(0, 0, "Region").unwrap();
+        group.write_with_format(0, 1, 1234, &bold).unwrap();
+        group
+            .apply(|worksheet| {
+                worksheet.set_landscape();
+                Ok(())
+            })
+            .unwrap();
+
+        let result = group.write(0, 16_384, "Sales");
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let buf = workbook.save_to_buffer().unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(&buf)).unwrap();
+
+        for (sheet, grouped) in [(1, true), (2, false), (3, true)] {
+            let mut xml = String::new();
+            archive
+                .by_name(&format!("xl/worksheets/sheet{sheet}.xml"))
+                .unwrap()
+                .read_to_string(&mut xml)
+                .unwrap();
+
+            assert_eq!(xml.contains(r#"<c r="B1" s="1"><v>1234</v></c>"#), grouped);
+            assert_eq!(xml.contains(r#"orientation="landscape""#), grouped);
+        }
+    }
 }
@@ -7,8 +7,8 @@
 #![warn(missing_docs)]
 use std::borrow::Cow;
 use std::cmp;
-use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
 use std::io::Write;
 use std::mem;
 use std::sync::Arc;
@@ -24,10 +24,10 @@ use crate::formula::Formula;
 use crate::shared_strings_table::SharedStringsTable;
 use crate::styles::Styles;
 use crate::vml::VmlInfo;
-use crate::xmlwriter::{XMLWriter, XML_WRITE_ERROR};
+use crate::xmlwriter::{escape_si_data, escape_xml_escapes, XMLWriter, XmlFlush, XML_WRITE_ERROR};
 use crate::{
-    utility, Color, HeaderImagePosition, Image, IntoColor, ObjectMovement, ProtectionOptions,
-    Table, TableFunction, Url,
+    utility, Color, HeaderImagePosition, Image, IntoColor, ObjectMovement, PageSetup,
+    ProtectionOptions, Table, TableFunction, Url, WorksheetOptions,
 };
 use crate::{Chart, ChartSeriesCacheData};
 use crate::{FilterCondition, FilterCriteria, FilterData, FilterDataType};
@@ -45,11 +45,16 @@ pub(crate) const ROW_MAX: RowNum = 1_048_576;
 const MAX_URL_LEN: usize = 2_080;
 const MAX_STRING_LEN: usize = 32_767;
 const MAX_PARAMETER_LEN: usize = 255;
+const MAX_HYPERLINKS: usize = 65_530;
 const DEFAULT_COL_WIDTH: f64 = 8.43;
 const DEFAULT_ROW_HEIGHT: f64 = 15.0;
-pub(crate) const NUM_IMAGE_FORMATS: usize = 5;
+const CM_PER_INCH: f64 = 2.54;
+pub(crate) const NUM_IMAGE_FORMATS: usize = 6;
 const COLUMN_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
+// A user supplied function to measure string pixel widths for autofit.
+type StringWidthFn = Box<dyn Fn(&str) -> u16 + Send + Sync>;
+
 /// The Worksheet struct represents an Excel worksheet. It handles operations
 /// such as writing data to cells or formatting the worksheet layout.
 ///
@@ -128,6 +133,10 @@ pub struct Worksheet {
     pub(crate) hidden: bool,
     pub(crate) first_sheet: bool,
     pub(crate) uses_string_table: bool,
+    pub(crate) use_inline_strings: bool,
+    string_overflow: StringOverflow,
+    hyperlink_limit_fallback: bool,
+    downgraded_hyperlinks: usize,
     pub(crate) has_dynamic_arrays: bool,
     pub(crate) print_area_defined_name: DefinedName,
     pub(crate) repeat_row_cols_defined_name: DefinedName,
@@ -141,14 +150,17 @@ pub struct Worksheet {
     pub(crate) drawing_relationships: Vec<(String, String, String)>,
     pub(crate) vml_drawing_relationships: Vec<(String, String, String)>,
     pub(crate) images: BTreeMap<(RowNum, ColNum), Image>,
+    pub(crate) absolute_images: Vec<(u32, u32, Image)>,
     pub(crate) header_footer_vml_info: Vec<VmlInfo>,
     pub(crate) drawing: Drawing,
     pub(crate) image_types: [bool; NUM_IMAGE_FORMATS],
     pub(crate) header_footer_images: [Option<Image>; 6],
     pub(crate) charts: BTreeMap<(RowNum, ColNum), Chart>,
     pub(crate) tables: Vec<Table>,
+    pub(crate) workbook_right_to_left: bool,
 
-    data_table: BTreeMap<RowNum, BTreeMap<ColNum, CellType>>,
+    data_table: BTreeMap<RowNum, CellRow>,
+    string_cache: HashSet<Arc<str>>,
     merged_ranges: Vec<CellRange>,
     merged_cells: HashMap<(RowNum, ColNum), usize>,
     table_ranges: Vec<CellRange>,
@@ -156,6 +168,7 @@ pub struct Worksheet {
     col_names: HashMap<ColNum, String>,
     dimensions: CellRange,
     xf_indices: HashMap<Format, u32>,
+    last_xf_index: u32,
     global_xf_indices: Vec<u32>,
     changed_rows: HashMap<RowNum, RowOptions>,
     changed_cols: HashMap<ColNum, ColOptions>,
@@ -167,6 +180,8 @@ pub struct Worksheet {
     paper_size: u8,
     default_page_order: bool,
     right_to_left: bool,
+    screen_gridlines: bool,
+    row_col_headers: bool,
     portrait: bool,
     page_view: PageView,
     zoom: u16,
@@ -177,6 +192,7 @@ pub struct Worksheet {
     print_gridlines: bool,
     print_black_and_white: bool,
     print_draft: bool,
+    print_quality: u16,
     print_headings: bool,
     header: String,
     footer: String,
@@ -192,6 +208,8 @@ pub struct Worksheet {
     first_page_number: u16,
     default_result: Box<str>,
     use_future_functions: bool,
+    use_default_datetime_formats: bool,
+    autofit_string_width: Option<StringWidthFn>,
     panes: Panes,
     hyperlinks: BTreeMap<(RowNum, ColNum), Hyperlink>,
     rel_count: u16,
@@ -207,6 +225,7 @@ pub struct Worksheet {
     filter_automatic_off: bool,
     has_drawing_object_linkage: bool,
     cells_with_autofilter: HashSet<(RowNum, ColNum)>,
+    buffered_row: Option<(RowNum, CellRow)>,
 }
 
 impl Default for Worksheet {
@@ -307,21 +326,28 @@ impl Worksheet {
             hidden: false,
             first_sheet: false,
             uses_string_table: false,
+            use_inline_strings: false,
+            string_overflow: StringOverflow::Error,
+            hyperlink_limit_fallback: false,
+            downgraded_hyperlinks: 0,
             has_dynamic_arrays: false,
             print_area_defined_name: DefinedName::new(),
             repeat_row_cols_defined_name: DefinedName::new(),
             autofilter_defined_name: DefinedName::new(),
             autofilter_area: String::new(),
             data_table: BTreeMap::new(),
+            string_cache: HashSet::new(),
             col_names: HashMap::new(),
             dimensions,
             merged_ranges: vec![],
             merged_cells: HashMap::new(),
             tables: vec![],
+            workbook_right_to_left: false,
             table_ranges: vec![],
             table_cells: HashMap::new(),
             xf_formats: vec![Format::default()],
             xf_indices,
+            last_xf_index: 0,
             global_xf_indices: vec![],
             changed_rows: HashMap::new(),
             changed_cols: HashMap::new(),
@@ -333,6 +359,8 @@ impl Worksheet {
             paper_size: 0,
             default_page_order: true,
             right_to_left: false,
+            screen_gridlines: true,
+            row_col_headers: true,
             portrait: true,
             page_view: PageView::Normal,
             zoom: 100,
@@ -343,6 +371,7 @@ impl Worksheet {
             print_gridlines: false,
             print_black_and_white: false,
             print_draft: false,
+            print_quality: 200,
             print_headings: false,
             header: String::new(),
             footer: String::new(),
@@ -358,6 +387,8 @@ impl Worksheet {
             first_page_number: 0,
             default_result: Box::from("0"),
             use_future_functions: false,
+            use_default_datetime_formats: true,
+            autofit_string_width: None,
             panes,
             has_hyperlink_style: false,
             hyperlinks: BTreeMap::new(),
@@ -367,6 +398,7 @@ impl Worksheet {
             drawing_relationships: vec![],
             vml_drawing_relationships: vec![],
             images: BTreeMap::new(),
+            absolute_images: vec![],
             drawing: Drawing::new(),
             image_types: [false; NUM_IMAGE_FORMATS],
             header_footer_images: [None, None, None, None, None, None],
@@ -385,6 +417,7 @@ impl Worksheet {
             charts: BTreeMap::new(),
             has_drawing_object_linkage: false,
             cells_with_autofilter: HashSet::new(),
+            buffered_row: None,
         }
     }
 
@@ -505,6 +538,87 @@ impl Worksheet {
             .unwrap_or_default()
     }
 
+    /// Check that a string is a valid Excel worksheet name.
+    ///
+    /// The `validate_name()` function checks a worksheet name against the same
+    /// rules as [`set_name()`](Worksheet::set_name), without needing a
+    /// worksheet. This is useful for checking names that are derived from data,
+    /// such as customer names or dates, before creating the worksheets.
+    ///
+    /// It doesn't check that the name is unique within a workbook. See
+    /// [`workbook.unique_worksheet_name()`](crate::Workbook::unique_worksheet_name)
+    /// for that.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The worksheet name to check.
+    ///
+    /// # Errors
+    ///
+    /// The same errors as [`set_name()`](Worksheet::set_name), such as
+    /// [`XlsxError::SheetnameLengthExceeded`] or
+    /// [`XlsxError::SheetnameContainsInvalidCharacter`].
+    ///
+    pub fn validate_name(name: &str) -> Result<(), XlsxError> {
+        Name::new(name).map(|_| ())
+    }
+
+    /// Convert a string into a valid Excel worksheet name.
+    ///
+    /// The `sanitize_name()` function converts an arbitrary string into a name
+    /// that meets the worksheet name rules listed in
+    /// [`set_name()`](Worksheet::set_name). It:
+    ///
+    /// * Removes the invalid characters: `[ ] : * ? / \`.
+    /// * Truncates the name to 31 characters.
+    /// * Removes apostrophes from the start and end of the name.
+    /// * Changes a blank name to "Sheet".
+    /// * Changes the reserved name "History" to "History_".
+    ///
+    /// It doesn't make the name unique within a workbook. See
+    /// [`workbook.unique_worksheet_name()`](crate::Workbook::unique_worksheet_name)
+    /// for that.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The string to convert to a worksheet name.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates converting a string into a valid
+    /// worksheet name.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_sanitize_name.rs
+    /// #
+    /// # use rust_xlsxwriter::Worksheet;
+    /// #
+    /// # fn main() {
+    ///     let name = Worksheet::sanitize_name("Sales: 2023/04 [Draft]");
+    ///
+    ///     assert_eq!(name, "Sales 202304 Draft");
+    ///     assert!(Worksheet::validate_name(&name).is_ok());
+    /// # }
+    /// ```
+    ///
+    pub fn sanitize_name(name: &str) -> String {
+        let name: String = name
+            .chars()
+            .filter(|c| !['*', '?', ':', '[', ']', '\\', '/'].contains(c))
+            .collect();
+
+        let name: String = name.trim_matches('\'').chars().take(31).collect();
+        let name = name.trim_end_matches('\'');
+
+        if name.is_empty() {
+            "Sheet".to_string()
+        } else if name.eq_ignore_ascii_case("History") {
+            format!("{name}_")
+        } else {
+            name.to_string()
+        }
+    }
+
     /// Write generic data to a cell.
     ///
     /// The `write()` method writes data that implements [`IntoExcelData`] to a
@@ -573,7 +687,7 @@ impl Worksheet {
         col: ColNum,
         data: T,
         format: &'a Format,
-    ) -> Result<&mut Worksheet, XlsxError>
+    ) -> Result<&'a mut Worksheet, XlsxError>
     where
         T: IntoExcelData,
     {
@@ -587,7 +701,8 @@ impl Worksheet {
     ///
     /// This methods works for arrays or array-like data structures that
     /// implement [`IntoIterator`] and that contain a data type that implements
-    /// [`IntoExcelData`].
+    /// [`IntoExcelData`]. Rows of mixed data types, such as database records,
+    /// can be written using the [`CellValue`] enum.
     ///
     /// See also [`worksheet.write_column()`](Worksheet::write_column) for a
     /// similar function that works in an orthogonal direction.
@@ -700,10 +815,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
-            self.write(row, col, item)?;
-            col += 1;
+        for (offset, item) in data.into_iter().enumerate() {
+            self.write(row, col + offset as ColNum, item)?;
         }
 
         Ok(self)
@@ -772,10 +885,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
-            self.write(row, col, item)?;
-            row += 1;
+        for (offset, item) in data.into_iter().enumerate() {
+            self.write(row + offset as RowNum, col, item)?;
         }
 
         Ok(self)
@@ -850,10 +961,8 @@ impl Worksheet {
         I::Item: IntoIterator<Item = II>,
         II: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
-            self.write_row(row, col, item)?;
-            row += 1;
+        for (offset, item) in data.into_iter().enumerate() {
+            self.write_row(row + offset as RowNum, col, item)?;
         }
 
         Ok(self)
@@ -927,15 +1036,178 @@ impl Worksheet {
         I::Item: IntoIterator<Item = II>,
         II: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
-            self.write_column(row, col, item)?;
-            col += 1;
+        for (offset, item) in data.into_iter().enumerate() {
+            self.write_column(row, col + offset as ColNum, item)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write a range of cells with values generated by a closure.
+    ///
+    /// The `write_range_with()` method fills the cell range `first_row,
+    /// first_col` to `last_row, last_col` with values returned by a closure.
+    /// The closure is called once per cell, in row order, with the zero
+    /// indexed worksheet row and column of the cell. It can return any type
+    /// that implements [`IntoExcelData`].
+    ///
+    /// This is convenient for generated data such as matrices, calendars or
+    /// multiplication tables.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first row of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last row of the range.
+    /// * `value` - A closure that returns the value for a `row, col` cell.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a multiplication table to a
+    /// worksheet using a closure.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_range_with.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Write the row and column headers.
+    ///     worksheet.write_row(0, 1, 1..=10)?;
+    ///     worksheet.write_column(1, 0, 1..=10)?;
+    ///
+    ///     // Write the table body.
+    ///     worksheet.write_range_with(1, 1, 10, 10, |row, col| row * u32::from(col))?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_range_with<F, T>(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        mut value: F,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        F: FnMut(RowNum, ColNum) -> T,
+        T: IntoExcelData,
+    {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                self.write(row, col, value(row, col))?;
+            }
         }
 
         Ok(self)
     }
 
+    /// Get a [`RowWriter`] to write cells to the worksheet in row order.
+    ///
+    /// The `row_writer()` method returns a [`RowWriter`] handle that appends
+    /// cells to the worksheet one at a time, left to right, with
+    /// [`push_cell()`](RowWriter::push_cell), and moves to the start of the
+    /// next row with [`end_row()`](RowWriter::end_row).
+    ///
+    /// This is a convenience for writing row ordered data, such as the results
+    /// of a database query, without tracking the row and column numbers. The
+    /// cells are validated and stored in the same way as
+    /// [`worksheet.write()`](Worksheet::write) but the writer holds the
+    /// current row so that cells are appended to it without looking the row
+    /// up for each cell. This makes it somewhat faster than `write()` for large
+    /// amounts of data.
+    ///
+    /// The row writer starts in column 0 of the row after the last row that
+    /// contains data, or in row 0 of an empty worksheet. This means that it
+    /// can be used to append data after a header row.
+    ///
+    /// The `RowWriter` holds a mutable borrow of the worksheet so the
+    /// worksheet can't be otherwise accessed until the writer goes out of
+    /// scope. The current row is added back to the worksheet at that point.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing rows of data to a worksheet
+    /// using a row writer.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_row_writer.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     let bold = Format::new().set_bold();
+    ///
+    ///     // Write a header row.
+    ///     worksheet.write_with_format(0, 0, "Item", &bold)?;
+    ///     worksheet.write_with_format(0, 1, "Cost", &bold)?;
+    ///
+    ///     // Some data to write.
+    ///     let expenses = [("Rent", 2000), ("Gas", 200), ("Food", 500)];
+    ///
+    ///     // Append the data to the worksheet, starting after the header row.
+    ///     let mut row_writer = worksheet.row_writer();
+    ///     for (item, cost) in expenses {
+    ///         row_writer.push_cell(item)?.push_cell(cost)?.end_row();
+    ///     }
+    ///
+    ///     // Release the worksheet borrow before saving.
+    ///     drop(row_writer);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn row_writer(&mut self) -> RowWriter<'_> {
+        self.flush_buffered_row(None);
+
+        let row = match self.data_table.last_key_value() {
+            Some((row, _)) => row + 1,
+            None => 0,
+        };
+
+        RowWriter::new(self, row)
+    }
+
     /// Write an unformatted number to a cell.
     ///
     /// Write an unformatted number to a worksheet cell. To write a formatted
@@ -2224,6 +2496,9 @@ impl Worksheet {
     ///   Excel's limit of 2080 characters.
     /// * [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
     ///   the supported types listed above.
+    /// * [`XlsxError::MaxHyperlinksExceeded`] - The worksheet already contains
+    ///   Excel's limit of 65,530 hyperlinks. See
+    ///   [`set_hyperlink_limit_fallback()`](Worksheet::set_hyperlink_limit_fallback).
     ///
     /// # Examples
     ///
@@ -2344,6 +2619,9 @@ impl Worksheet {
     ///   Excel's limit of 2080 characters.
     /// * [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
     ///   the supported types listed above.
+    /// * [`XlsxError::MaxHyperlinksExceeded`] - The worksheet already contains
+    ///   Excel's limit of 65,530 hyperlinks. See
+    ///   [`set_hyperlink_limit_fallback()`](Worksheet::set_hyperlink_limit_fallback).
     ///
     /// # Examples
     ///
@@ -2435,6 +2713,9 @@ impl Worksheet {
     ///   Excel's limit of 2080 characters.
     /// * [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
     ///   the supported types listed above.
+    /// * [`XlsxError::MaxHyperlinksExceeded`] - The worksheet already contains
+    ///   Excel's limit of 65,530 hyperlinks. See
+    ///   [`set_hyperlink_limit_fallback()`](Worksheet::set_hyperlink_limit_fallback).
     ///
     /// # Examples
     ///
@@ -2513,6 +2794,9 @@ impl Worksheet {
     ///   Excel's limit of 2080 characters or the screen tip exceed 255 characters.
     /// * [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type. See
     ///   the supported types listed above.
+    /// * [`XlsxError::MaxHyperlinksExceeded`] - The worksheet already contains
+    ///   Excel's limit of 65,530 hyperlinks. See
+    ///   [`set_hyperlink_limit_fallback()`](Worksheet::set_hyperlink_limit_fallback).
     ///
     pub fn write_url_with_options(
         &mut self,
@@ -2916,6 +3200,65 @@ impl Worksheet {
         self.store_boolean(row, col, boolean, Some(format))
     }
 
+    /// Insert a checkbox in a worksheet cell.
+    ///
+    /// Write a boolean value to a worksheet cell and display it as a
+    /// checkbox. This is a shortcut for writing the boolean with a
+    /// [`Format`] that has the [`set_checkbox()`](Format::set_checkbox)
+    /// property.
+    ///
+    /// Checkboxes are a cell format that was added to Excel 365 in 2024. Older
+    /// versions of Excel display the `TRUE`/`FALSE` value instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `checked` - The checked/unchecked state of the checkbox.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates inserting checkboxes in worksheet
+    /// cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_checkbox.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write(0, 0, "Done")?;
+    ///     worksheet.insert_checkbox(1, 0, true)?;
+    ///     worksheet.insert_checkbox(2, 0, false)?;
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_checkbox(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        checked: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let format = Format::new().set_checkbox();
+
+        // Store the cell data.
+        self.store_boolean(row, col, checked, Some(&format))
+    }
+
     /// Merge a range of cells.
     ///
     /// The `merge_range()` method allows cells to be merged together so that
@@ -3010,22 +3353,8 @@ impl Worksheet {
         string: &str,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions(first_row, first_col)
-            || !self.check_dimensions(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        // Check that the range isn't a singe cell, which isn't allowed by Excel.
-        if first_row == last_row && first_col == last_col {
-            return Err(XlsxError::MergeRangeSingleCell);
-        }
+        // Check that the range is valid and doesn't overlap a previous range.
+        let cell_range = self.check_merge_range(first_row, first_col, last_row, last_col)?;
 
         // Write the first cell in the range.
         self.write_string_with_format(first_row, first_col, string, format)?;
@@ -3041,75 +3370,191 @@ impl Worksheet {
             }
         }
 
-        // Create a cell range for storage and range testing.
-        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
-
-        // Check if the merged range overlaps any previous merged range. This is
-        // a major error in Excel. Note, the ranges are stored in a separate Vec
-        // to the cells to cut down on storage size.
-        let new_index = self.merged_ranges.len();
-        for row in first_row..=last_row {
-            for col in first_col..=last_col {
-                match self.merged_cells.get_mut(&(row, col)) {
-                    Some(index) => {
-                        let previous_cell_range = self.merged_ranges.get(*index).unwrap();
-                        return Err(XlsxError::MergeRangeOverlaps(
-                            cell_range.to_error_string(),
-                            previous_cell_range.to_error_string(),
-                        ));
-                    }
-                    None => self.merged_cells.insert((row, col), new_index),
-                };
-            }
-        }
-
         // Store the merge range if everything was okay.
-        self.merged_ranges.push(cell_range);
+        self.store_merge_range(cell_range);
 
         Ok(self)
     }
 
-    /// Add an image to a worksheet.
-    ///
-    /// Add an image to a worksheet at a cell location. The image should be
-    /// encapsulated in an [`Image`] object.
-    ///
-    /// The supported image formats are:
-    ///
-    /// - PNG
-    /// - JPG
-    /// - GIF: The image can be an animated gif in more resent versions of
-    ///   Excel.
-    /// - BMP: BMP images are only supported for backward compatibility. In
-    ///   general it is best to avoid BMP images since they are not compressed.
-    ///   If used, BMP images must be 24 bit, true color, bitmaps.
-    ///
-    /// EMF and WMF file formats will be supported in an upcoming version of the
-    /// library.
-    ///
-    /// **NOTE on SVG files**: Excel doesn't directly support SVG files in the
-    /// same way as other image file formats. It allows SVG to be inserted into
-    /// a worksheet but converts them to, and displays them as, PNG files. It
-    /// stores the original SVG image in the file so the original format can be
-    /// retrieved. This removes the file size and resolution advantage of using
-    /// SVG files. As such SVG files are not supported by `rust_xlsxwriter`
-    /// since a conversion to the PNG format would be required and that format
-    /// is already supported.
+    /// Merge a range of cells without a cell format.
     ///
+    /// The `merge_range_only()` method is similar to
+    /// [`merge_range()`](Worksheet::merge_range) except that it doesn't
+    /// require a [`Format`] and the data can be any type supported by
+    /// [`write()`](Worksheet::write). The data is written to the first cell in
+    /// the range and the other cells in the range are left unchanged.
     ///
     /// # Arguments
     ///
-    /// * `row` - The zero indexed row number.
-    /// * `col` - The zero indexed column number.
-    /// * `image` - The [`Image`] to insert into the cell.
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first row of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last row of the range.
+    /// * `data` - An type that implements the [`IntoExcelData`] trait.
     ///
     /// # Errors
     ///
     /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    ///
-    /// # Examples
-    ///
+    /// * [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// * [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
+    ///   cell in Excel.
+    /// * [`XlsxError::MergeRangeOverlaps`] - The merge range overlaps a
+    ///   previous merge range.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates merging cells without a format and
+    /// then unmerging a range.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_merge_range_only.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Merge some cells without a format.
+    ///     worksheet.merge_range_only(1, 1, 1, 3, "Merged cells")?;
+    ///     worksheet.merge_range_only(3, 1, 4, 3, 12345)?;
+    ///
+    ///     // Unmerge one of the ranges.
+    ///     worksheet.unmerge_range(3, 1, 4, 3)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn merge_range_only(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        data: impl IntoExcelData,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check that the range is valid and doesn't overlap a previous range.
+        let cell_range = self.check_merge_range(first_row, first_col, last_row, last_col)?;
+
+        // Write the first cell in the range.
+        self.write(first_row, first_col, data)?;
+
+        // Store the merge range if everything was okay.
+        self.store_merge_range(cell_range);
+
+        Ok(self)
+    }
+
+    /// Unmerge a previously merged range of cells.
+    ///
+    /// Remove a merged range that was added with
+    /// [`merge_range()`](Worksheet::merge_range) or
+    /// [`merge_range_only()`](Worksheet::merge_range_only). The range must
+    /// match the merged range exactly. The cell data and formatting in the
+    /// range are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_row` - The first row of the range. (All zero indexed.)
+    /// * `first_col` - The first row of the range.
+    /// * `last_row` - The last row of the range.
+    /// * `last_col` - The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ParameterError`] - The range isn't a merged range in the
+    ///   worksheet.
+    ///
+    pub fn unmerge_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
+
+        let index = self
+            .merged_ranges
+            .iter()
+            .position(|range| {
+                range.first_row == first_row
+                    && range.first_col == first_col
+                    && range.last_row == last_row
+                    && range.last_col == last_col
+            })
+            .ok_or_else(|| {
+                XlsxError::ParameterError(format!(
+                    "Range '{}' is not a merged range",
+                    cell_range.to_error_string()
+                ))
+            })?;
+
+        self.merged_ranges.remove(index);
+
+        // Rebuild the cell to range index lookup since the indices have moved.
+        self.merged_cells.clear();
+        for (index, range) in self.merged_ranges.iter().enumerate() {
+            for row in range.first_row..=range.last_row {
+                for col in range.first_col..=range.last_col {
+                    self.merged_cells.insert((row, col), index);
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Add an image to a worksheet.
+    ///
+    /// Add an image to a worksheet at a cell location. The image should be
+    /// encapsulated in an [`Image`] object.
+    ///
+    /// The supported image formats are:
+    ///
+    /// - PNG
+    /// - JPG
+    /// - GIF: The image can be an animated gif in more resent versions of
+    ///   Excel.
+    /// - BMP: BMP images are only supported for backward compatibility. In
+    ///   general it is best to avoid BMP images since they are not compressed.
+    ///   If used, BMP images must be 24 bit, true color, bitmaps.
+    ///
+    /// EMF and WMF file formats will be supported in an upcoming version of the
+    /// library.
+    ///
+    /// **NOTE on SVG files**: Excel doesn't directly support SVG files in the
+    /// same way as other image file formats. It allows SVG to be inserted into
+    /// a worksheet but converts them to, and displays them as, PNG files. It
+    /// stores the original SVG image in the file so the original format can be
+    /// retrieved. This removes the file size and resolution advantage of using
+    /// SVG files. As such SVG files are not supported by `rust_xlsxwriter`
+    /// since a conversion to the PNG format would be required and that format
+    /// is already supported.
+    ///
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `image` - The [`Image`] to insert into the cell.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::UnknownUrlType`] - The image hyperlink has an unknown
+    ///   URI type. See [`Image::set_url()`](crate::Image::set_url).
+    /// * [`XlsxError::MaxUrlLengthExceeded`] - The image hyperlink is longer
+    ///   than Excel's limit of 2080 characters.
+    ///
+    /// # Examples
+    ///
     /// The following example demonstrates creating a new Image object and
     /// adding it to a worksheet.
     ///
@@ -3176,6 +3621,10 @@ impl Worksheet {
     ///
     /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
+    /// * [`XlsxError::UnknownUrlType`] - The image hyperlink has an unknown
+    ///   URI type. See [`Image::set_url()`](crate::Image::set_url).
+    /// * [`XlsxError::MaxUrlLengthExceeded`] - The image hyperlink is longer
+    ///   than Excel's limit of 2080 characters.
     ///
     /// # Examples
     ///
@@ -3224,6 +3673,9 @@ impl Worksheet {
             return Err(XlsxError::RowColumnLimitError);
         }
 
+        // Check the image hyperlink length.
+        self.check_image_url(image, self.cell_location(row, col))?;
+
         let mut image = image.clone();
         image.x_offset = x_offset;
         image.y_offset = y_offset;
@@ -3262,6 +3714,10 @@ impl Worksheet {
     ///
     /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
+    /// * [`XlsxError::UnknownUrlType`] - The image hyperlink has an unknown
+    ///   URI type. See [`Image::set_url()`](crate::Image::set_url).
+    /// * [`XlsxError::MaxUrlLengthExceeded`] - The image hyperlink is longer
+    ///   than Excel's limit of 2080 characters.
     ///
     /// # Examples
     ///
@@ -3333,6 +3789,9 @@ impl Worksheet {
             return Err(XlsxError::RowColumnLimitError);
         }
 
+        // Check the image hyperlink length.
+        self.check_image_url(image, self.cell_location(row, col))?;
+
         let width = self.column_pixel_width(col, image.object_movement);
         let height = self.row_pixel_height(row, image.object_movement);
 
@@ -3344,6 +3803,81 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Add an image to a worksheet at an absolute position.
+    ///
+    /// Add an image to a worksheet at a position measured from the top left
+    /// corner of the worksheet rather than from a cell. The image should be
+    /// encapsulated in an [`Image`] object.
+    ///
+    /// The position is given in English Metric Units (EMUs), the unit that
+    /// Excel uses internally for drawing objects. There are 9,525 EMUs per
+    /// pixel and 12,700 EMUs per point.
+    ///
+    /// Unlike [`insert_image()`](Worksheet::insert_image) the position and
+    /// size of the image don't depend on the row heights and column widths of
+    /// the worksheet, which makes this method useful for dashboard type
+    /// layouts. As a consequence the
+    /// [`Image::set_object_movement()`](crate::Image::set_object_movement)
+    /// option doesn't apply to absolutely positioned images.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The [`Image`] to insert into the worksheet.
+    /// * `x` - The horizontal position of the image in EMUs.
+    /// * `y` - The vertical position of the image in EMUs.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::UnknownUrlType`] - The image hyperlink has an unknown
+    ///   URI type. See [`Image::set_url()`](crate::Image::set_url).
+    /// * [`XlsxError::MaxUrlLengthExceeded`] - The image hyperlink is longer
+    ///   than Excel's limit of 2080 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding an image to a worksheet at an
+    /// absolute position.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_image_absolute.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new image object.
+    ///     let image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     // Insert the image 100 pixels from the left and 50 pixels from the
+    ///     // top of the worksheet, at 9,525 EMUs per pixel.
+    ///     worksheet.insert_image_absolute(&image, 100 * 9525, 50 * 9525)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("image.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_image_absolute(
+        &mut self,
+        image: &Image,
+        x: u32,
+        y: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check the image hyperlink length.
+        self.check_image_url(image, format!("absolute position ({x}, {y})"))?;
+
+        self.absolute_images.push((x, y, image.clone()));
+
+        Ok(self)
+    }
+
     /// Add a chart to a worksheet.
     ///
     /// Add a [`Chart`] to a worksheet at a cell location.
@@ -3420,7 +3954,7 @@ impl Worksheet {
     /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
     /// * [`XlsxError::ChartError`] - A general error that is raised when a
-    /// chart parameter is incorrect or a chart is configured incorrectly.
+    ///   chart parameter is incorrect or a chart is configured incorrectly.
     ///
     /// # Examples
     ///
@@ -3631,6 +4165,76 @@ impl Worksheet {
         self.set_row_height(row, height)
     }
 
+    /// Set the height for a row of cells, in centimeters.
+    ///
+    /// The `set_row_height_cm()` method is used to change the default height
+    /// of a row using centimeters, which can be more convenient than points
+    /// for print oriented layouts. The height is converted to points at 72
+    /// points per inch. See [`set_row_height()`](Worksheet::set_row_height)
+    /// for more details.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `height` - The row height in centimeters.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn set_row_height_cm(
+        &mut self,
+        row: RowNum,
+        height: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_row_height_inches(row, height.into() / CM_PER_INCH)
+    }
+
+    /// Set the height for a row of cells, in millimeters.
+    ///
+    /// See [`set_row_height_cm()`](Worksheet::set_row_height_cm) for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `height` - The row height in millimeters.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn set_row_height_mm(
+        &mut self,
+        row: RowNum,
+        height: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_row_height_inches(row, height.into() / (CM_PER_INCH * 10.0))
+    }
+
+    /// Set the height for a row of cells, in inches.
+    ///
+    /// See [`set_row_height_cm()`](Worksheet::set_row_height_cm) for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `height` - The row height in inches.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn set_row_height_inches(
+        &mut self,
+        row: RowNum,
+        height: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_row_height(row, height.into() * 72.0)
+    }
+
     /// Set the format for a row of cells.
     ///
     /// The `set_row_format()` method is used to change the default format of a
@@ -3971,18 +4575,219 @@ impl Worksheet {
         col: ColNum,
         width: u16,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Properties for Calibri 11.
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
-        let mut width = f64::from(width);
+        self.set_column_width(col, Self::column_pixels_to_width(width))
+    }
 
-        if width < 12.0 {
-            width /= max_digit_width + padding;
-        } else {
-            width = (width - padding) / max_digit_width;
+    /// Set the width for a worksheet column, in centimeters.
+    ///
+    /// The `set_column_width_cm()` method is used to change the default width
+    /// of a worksheet column using centimeters, which can be more convenient
+    /// than character units for print oriented layouts.
+    ///
+    /// Excel column widths are stored in character units and displayed in
+    /// whole pixels so the width is converted to the nearest pixel, at 96
+    /// pixels per inch, and then to character units in the same way as
+    /// [`set_column_width_pixels()`](Worksheet::set_column_width_pixels).
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The zero indexed column number.
+    /// * `width` - The column width in centimeters.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the width of columns, the
+    /// height of rows and the page margins in metric units.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_column_width_cm.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Set the column widths and row heights in metric units.
+    ///     worksheet.set_column_width_cm(0, 5)?;
+    ///     worksheet.set_column_width_mm(1, 25)?;
+    ///     worksheet.set_row_height_cm(0, 1.5)?;
+    ///
+    ///     // Set the page margins in centimeters.
+    ///     worksheet.set_margins_cm(2.0, 2.0, 2.5, 2.5, -1.0, -1.0);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_width_cm(
+        &mut self,
+        col: ColNum,
+        width: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_column_width_inches(col, width.into() / CM_PER_INCH)
+    }
+
+    /// Set the width for a worksheet column, in millimeters.
+    ///
+    /// See [`set_column_width_cm()`](Worksheet::set_column_width_cm) for
+    /// details.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The zero indexed column number.
+    /// * `width` - The column width in millimeters.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn set_column_width_mm(
+        &mut self,
+        col: ColNum,
+        width: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_column_width_inches(col, width.into() / (CM_PER_INCH * 10.0))
+    }
+
+    /// Set the width for a worksheet column, in inches.
+    ///
+    /// See [`set_column_width_cm()`](Worksheet::set_column_width_cm) for
+    /// details.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The zero indexed column number.
+    /// * `width` - The column width in inches.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn set_column_width_inches(
+        &mut self,
+        col: ColNum,
+        width: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_column_width_pixels(col, Self::inches_to_pixels(width.into()))
+    }
+
+    /// Set the width for a range of worksheet columns.
+    ///
+    /// The `set_column_range_width()` method is used to set the same width
+    /// for a contiguous range of columns. It is equivalent to calling
+    /// [`set_column_width()`](Worksheet::set_column_width()) for each column
+    /// in the range and the columns are written to the file as a single
+    /// `<col>` range record, which keeps the XML compact for wide worksheets.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_col` - The first zero indexed column of the range.
+    /// * `last_col` - The last zero indexed column of the range.
+    /// * `width` - The column width in character units.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the width of a range of
+    /// columns in Excel.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_column_range_width.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Add some text.
+    ///     worksheet.write_string(0, 0, "Normal")?;
+    ///     worksheet.write_string(0, 2, "Wider")?;
+    ///
+    ///     // Set the width of columns C to J in Excel character units.
+    ///     worksheet.set_column_range_width(2, 9, 16)?;
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_column_range_width(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        width: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let width = width.into();
+
+        // Check if the columns are in the allowed range without updating
+        // dimensions.
+        if last_col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Contiguous columns with the same properties are written as a single
+        // range by write_cols().
+        for col in first_col..=last_col {
+            self.set_column_width(col, width)?;
         }
 
-        self.set_column_width(col, width)
+        Ok(self)
+    }
+
+    /// Set the width for a range of worksheet columns in pixels.
+    ///
+    /// The `set_column_range_width_pixels()` method is the same as
+    /// [`set_column_range_width()`](Worksheet::set_column_range_width()) except
+    /// that the width is given in pixels, as with
+    /// [`set_column_width_pixels()`](Worksheet::set_column_width_pixels()).
+    ///
+    /// # Arguments
+    ///
+    /// * `first_col` - The first zero indexed column of the range.
+    /// * `last_col` - The last zero indexed column of the range.
+    /// * `width` - The column width in pixels.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// * [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    ///
+    pub fn set_column_range_width_pixels(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        width: u16,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_column_range_width(first_col, last_col, Self::column_pixels_to_width(width))
     }
 
     /// Set the format for a column of cells.
@@ -5218,7 +6023,7 @@ impl Worksheet {
         result: impl Into<String>,
     ) -> &mut Worksheet {
         if let Some(columns) = self.data_table.get_mut(&row) {
-            if let Some(cell) = columns.get_mut(&col) {
+            if let Some(cell) = columns.get_mut(col) {
                 match cell {
                     CellType::Formula {
                         formula: _,
@@ -5246,80 +6051,267 @@ impl Worksheet {
 
     /// Write the default formula result for worksheet formulas.
     ///
-    /// The `rust_xlsxwriter` library doesn’t calculate the result of a formula
-    /// written using [`write_formula_with_format()`](Worksheet::write_formula_with_format()) or
-    /// [`write_formula()`](Worksheet::write_formula()). Instead it
-    /// stores the value 0 as the formula result. It then sets a global flag in
-    /// the xlsx file to say that all formulas and functions should be
-    /// recalculated when the file is opened.
+    /// The `rust_xlsxwriter` library doesn’t calculate the result of a formula
+    /// written using [`write_formula_with_format()`](Worksheet::write_formula_with_format()) or
+    /// [`write_formula()`](Worksheet::write_formula()). Instead it
+    /// stores the value 0 as the formula result. It then sets a global flag in
+    /// the xlsx file to say that all formulas and functions should be
+    /// recalculated when the file is opened.
+    ///
+    /// However, for `LibreOffice` the default formula result should be set to the
+    /// empty string literal `""`, via the `set_formula_result_default()`
+    /// method, to force calculation of the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The default formula result to write to the cell.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates manually setting the default result
+    /// for all non-calculated formulas in a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_formula_result_default.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.set_formula_result_default("");
+    ///
+    /// #     workbook.save("formulas.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_formula_result_default(&mut self, result: impl Into<String>) -> &mut Worksheet {
+        self.default_result = Box::from(result.into());
+        self
+    }
+
+    /// Enable the use of newer Excel future functions.
+    ///
+    /// Enable the use of newer Excel “future” functions without having to
+    /// prefix them with with `_xlfn`.
+    ///
+    /// Excel 2010 and later versions added functions which weren't defined in
+    /// the original file specification. These functions are referred to by
+    /// Microsoft as "Future Functions". Examples of these functions are `ACOT`,
+    /// `CHISQ.DIST.RT` , `CONFIDENCE.NORM`, `STDEV.P`, `STDEV.S` and
+    /// `WORKDAY.INTL`.
+    ///
+    /// When written using [`write_formula()`](Worksheet::write_formula()) these
+    /// functions need to be fully qualified with a prefix such as `_xlfn.`
+    ///
+    /// Alternatively you can use the `worksheet.use_future_functions()`
+    /// function to have `rust_xlsxwriter` automatically handle future functions
+    /// for you, or use a [`Formula`] struct and the
+    /// [`Formula::use_future_functions()`](Formula::use_future_functions)
+    /// method, see below.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates different ways to handle writing
+    /// Future Functions to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_use_future_functions.rs
+    /// #
+    /// # use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // The following is a "Future" function and will generate a "#NAME?" warning
+    ///     // in Excel.
+    ///     worksheet.write_formula(0, 0, "=ISFORMULA($B$1)")?;
+    ///
+    ///     // The following adds the required prefix. This will work without a warning.
+    ///     worksheet.write_formula(1, 0, "=_xlfn.ISFORMULA($B$1)")?;
+    ///
+    ///     // The following uses a Formula object and expands out any future functions.
+    ///     // This also works without a warning.
+    ///     worksheet.write_formula(2, 0, Formula::new("=ISFORMULA($B$1)").use_future_functions())?;
+    ///
+    ///     // The following expands out all future functions used in the worksheet from
+    ///     // this point forward. This also works without a warning.
+    ///     worksheet.use_future_functions(true);
+    ///     worksheet.write_formula(3, 0, "=ISFORMULA($B$1)")?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_use_future_functions.png">
+    ///
+    pub fn use_future_functions(&mut self, enable: bool) -> &mut Worksheet {
+        self.use_future_functions = enable;
+        self
+    }
+
+    /// Turn on/off the default number formats for unformatted dates and times.
+    ///
+    /// Excel stores dates and times as numbers, so they need a number format
+    /// to be displayed as a date or time. When a [`NaiveDateTime`],
+    /// [`NaiveDate`] or [`NaiveTime`] is written with
+    /// [`write()`](Worksheet::write) and no format, `rust_xlsxwriter` adds one
+    /// of the following default formats:
+    ///
+    /// | Type            | Default number format   |
+    /// | :-------------- | :---------------------- |
+    /// | `NaiveDateTime` | `yyyy-mm-dd hh:mm:ss`   |
+    /// | `NaiveDate`     | `yyyy-mm-dd`            |
+    /// | `NaiveTime`     | `hh:mm:ss`              |
+    ///
+    /// If you manage the date formats yourself, for example with
+    /// [`set_column_format()`](Worksheet::set_column_format), you can turn the
+    /// defaults off. Unformatted dates and times are then written like other
+    /// unformatted numbers.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates turning off the default date format
+    /// so that dates adopt a column format.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_use_default_datetime_formats.rs
+    /// #
+    /// # use chrono::NaiveDate;
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let date_format = Format::new().set_num_format("d mmmm yyyy");
+    ///     worksheet.set_column_format(0, &date_format)?;
+    ///     worksheet.set_column_width(0, 20)?;
+    ///
+    ///     worksheet.use_default_datetime_formats(false);
+    ///
+    ///     let date = NaiveDate::from_ymd_opt(2023, 1, 25).unwrap();
+    ///     worksheet.write(0, 0, &date)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn use_default_datetime_formats(&mut self, enable: bool) -> &mut Worksheet {
+        self.use_default_datetime_formats = enable;
+        self
+    }
+
+    /// Write worksheet strings inline instead of in the shared string table.
+    ///
+    /// By default strings are stored by Excel, and rust_xlsxwriter, in a
+    /// shared string table that is common to all the worksheets in the
+    /// workbook. Each string cell in the worksheet then holds an index into the
+    /// table. This is efficient for strings that are repeated but, for
+    /// worksheets with mostly unique strings, building the table costs time
+    /// and memory when the file is saved.
+    ///
+    /// The `set_inline_strings()` method writes the worksheet strings directly
+    /// in the cells, as "inline" strings, which avoids the shared string table.
+    /// Excel reads inline strings without any issue but converts them to
+    /// shared strings when the file is resaved.
     ///
-    /// However, for `LibreOffice` the default formula result should be set to the
-    /// empty string literal `""`, via the `set_formula_result_default()`
-    /// method, to force calculation of the result.
+    /// Note, some third party applications that read xlsx files may not
+    /// support inline strings.
     ///
     /// # Arguments
     ///
-    /// * `result` - The default formula result to write to the cell.
+    /// * `enable` - Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates manually setting the default result
-    /// for all non-calculated formulas in a worksheet.
+    /// The following example demonstrates writing the worksheet strings as
+    /// inline strings.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_formula_result_default.rs
+    /// # // This code is available in examples/doc_worksheet_set_inline_strings.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     worksheet.set_formula_result_default("");
+    ///     worksheet.set_inline_strings(true);
     ///
-    /// #     workbook.save("formulas.xlsx")?;
+    ///     for row in 0..100 {
+    ///         worksheet.write_string(row, 0, format!("Order {}", row + 1))?;
+    ///     }
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    pub fn set_formula_result_default(&mut self, result: impl Into<String>) -> &mut Worksheet {
-        self.default_result = Box::from(result.into());
+    pub fn set_inline_strings(&mut self, enable: bool) -> &mut Worksheet {
+        self.use_inline_strings = enable;
         self
     }
 
-    /// Enable the use of newer Excel future functions.
+    /// Set how strings that exceed Excel's length limit are handled.
     ///
-    /// Enable the use of newer Excel “future” functions without having to
-    /// prefix them with with `_xlfn`.
+    /// Excel limits the length of a string in a cell to 32,767 characters. By
+    /// default writing a longer string returns an
+    /// [`XlsxError::MaxStringLengthExceeded`] error. This is the safest option
+    /// but data pipelines that export free text fields can't always clean the
+    /// data in advance.
     ///
-    /// Excel 2010 and later versions added functions which weren't defined in
-    /// the original file specification. These functions are referred to by
-    /// Microsoft as "Future Functions". Examples of these functions are `ACOT`,
-    /// `CHISQ.DIST.RT` , `CONFIDENCE.NORM`, `STDEV.P`, `STDEV.S` and
-    /// `WORKDAY.INTL`.
+    /// The `set_string_overflow()` method can be used to truncate long strings
+    /// instead, or to split them across the cells to the right of the target
+    /// cell. See [`StringOverflow`] for the available options.
     ///
-    /// When written using [`write_formula()`](Worksheet::write_formula()) these
-    /// functions need to be fully qualified with a prefix such as `_xlfn.`
+    /// The policy applies to strings written with
+    /// [`write_string()`](Worksheet::write_string),
+    /// [`write_string_with_format()`](Worksheet::write_string_with_format) and
+    /// the generic [`write()`](Worksheet::write) methods. Rich strings still
+    /// return an error since their formatting can't be split safely.
     ///
-    /// Alternatively you can use the `worksheet.use_future_functions()`
-    /// function to have `rust_xlsxwriter` automatically handle future functions
-    /// for you, or use a [`Formula`] struct and the
-    /// [`Formula::use_future_functions()`](Formula::use_future_functions)
-    /// method, see below.
+    /// A default for all the worksheets in a workbook can be set with
+    /// [`workbook.set_string_overflow()`](crate::Workbook::set_string_overflow).
+    ///
+    /// # Arguments
+    ///
+    /// * `overflow` - A [`StringOverflow`] enum value.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates different ways to handle writing
-    /// Future Functions to a worksheet.
+    /// The following example demonstrates truncating strings that are longer
+    /// than Excel's limit.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_use_future_functions.rs
+    /// # // This code is available in examples/doc_worksheet_set_string_overflow.rs
     /// #
-    /// # use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{StringOverflow, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
@@ -5327,35 +6319,92 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // The following is a "Future" function and will generate a "#NAME?" warning
-    ///     // in Excel.
-    ///     worksheet.write_formula(0, 0, "=ISFORMULA($B$1)")?;
-    ///
-    ///     // The following adds the required prefix. This will work without a warning.
-    ///     worksheet.write_formula(1, 0, "=_xlfn.ISFORMULA($B$1)")?;
+    ///     worksheet.set_string_overflow(StringOverflow::Truncate);
     ///
-    ///     // The following uses a Formula object and expands out any future functions.
-    ///     // This also works without a warning.
-    ///     worksheet.write_formula(2, 0, Formula::new("=ISFORMULA($B$1)").use_future_functions())?;
+    ///     // The string is truncated to 32,767 characters, ending in "…".
+    ///     let notes = "Lorem ipsum. ".repeat(3000);
+    ///     worksheet.write_string(0, 0, notes)?;
     ///
-    ///     // The following expands out all future functions used in the worksheet from
-    ///     // this point forward. This also works without a warning.
-    ///     worksheet.use_future_functions(true);
-    ///     worksheet.write_formula(3, 0, "=ISFORMULA($B$1)")?;
-    /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// Output file:
+    pub fn set_string_overflow(&mut self, overflow: StringOverflow) -> &mut Worksheet {
+        self.string_overflow = overflow;
+        self
+    }
+
+    /// Write urls as plain text once Excel's hyperlink limit is reached.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_use_future_functions.png">
+    /// Excel limits the number of hyperlinks in a worksheet to 65,530. By
+    /// default the url write methods return an
+    /// [`XlsxError::MaxHyperlinksExceeded`] error once that limit is reached.
     ///
-    pub fn use_future_functions(&mut self, enable: bool) {
-        self.use_future_functions = enable;
+    /// The `set_hyperlink_limit_fallback()` method changes that so the
+    /// remaining urls are written as strings, without a link. The text of the
+    /// url is written with the user supplied format, if there is one, but
+    /// without the default hyperlink format so that it doesn't look like a
+    /// link. This is useful for link heavy exports where the data is more
+    /// important than the links.
+    ///
+    /// The number of urls that were written as text can be checked with
+    /// [`downgraded_hyperlinks()`](Worksheet::downgraded_hyperlinks), for
+    /// example to log a warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_hyperlink_limit_fallback(&mut self, enable: bool) -> &mut Worksheet {
+        self.hyperlink_limit_fallback = enable;
+        self
+    }
+
+    /// Get the number of urls that were written as plain text.
+    ///
+    /// Returns the number of urls that were written as strings, without a
+    /// link, because the worksheet hyperlink limit was reached and
+    /// [`set_hyperlink_limit_fallback()`](Worksheet::set_hyperlink_limit_fallback)
+    /// was enabled. It is 0 if all the urls were written as hyperlinks.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates checking the number of urls that
+    /// were written as text once the hyperlink limit was reached.
+    ///
+    /// ```no_run
+    /// # // This code is available in examples/doc_worksheet_downgraded_hyperlinks.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.set_hyperlink_limit_fallback(true);
+    ///
+    ///     // Write more urls than Excel allows in a worksheet. The urls after
+    ///     // the limit are written as text.
+    ///     for row in 0..70_000 {
+    ///         worksheet.write_url(row, 0, "https://www.rust-lang.org")?;
+    ///     }
+    ///
+    ///     // Get the number of urls written as text. This could be logged as a
+    ///     // warning that some of the links are missing.
+    ///     let count = worksheet.downgraded_hyperlinks();
+    ///     assert_eq!(count, 4470);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn downgraded_hyperlinks(&self) -> usize {
+        self.downgraded_hyperlinks
     }
 
     // -----------------------------------------------------------------------
@@ -5437,6 +6486,70 @@ impl Worksheet {
         self
     }
 
+    /// Turn on/off the gridlines that are displayed on the screen.
+    ///
+    /// The `set_screen_gridlines()` method is used to hide the gridlines that
+    /// Excel displays around worksheet cells. This is useful for dashboard or
+    /// form style worksheets.
+    ///
+    /// This option only affects the on-screen display. To print gridlines use
+    /// [`set_print_gridlines()`](Worksheet::set_print_gridlines).
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates hiding the worksheet gridlines and
+    /// the row and column headers on the screen.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_screen_gridlines.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write_string(1, 1, "Form style worksheet")?;
+    ///
+    ///     // Hide the gridlines and the row and column headers.
+    ///     worksheet.set_screen_gridlines(false);
+    ///     worksheet.set_row_col_headers(false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_screen_gridlines(&mut self, enable: bool) -> &mut Worksheet {
+        self.screen_gridlines = enable;
+        self
+    }
+
+    /// Turn on/off the row and column headers that are displayed on the
+    /// screen.
+    ///
+    /// The `set_row_col_headers()` method is used to hide the row numbers and
+    /// column letters that Excel displays at the edges of the worksheet. See
+    /// [`set_screen_gridlines()`](Worksheet::set_screen_gridlines) for an
+    /// example.
+    ///
+    /// This option only affects the on-screen display. To print the headers
+    /// use [`set_print_headings()`](Worksheet::set_print_headings).
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn set_row_col_headers(&mut self, enable: bool) -> &mut Worksheet {
+        self.row_col_headers = enable;
+        self
+    }
+
     /// Make a worksheet the active/initially visible worksheet in a workbook.
     ///
     /// The `set_active()` method is used to specify which worksheet is
@@ -5697,6 +6810,110 @@ impl Worksheet {
         self
     }
 
+    /// Apply a set of worksheet options to the worksheet.
+    ///
+    /// The `set_options()` method applies the options defined in a
+    /// [`WorksheetOptions`] struct to the worksheet. This is useful when the
+    /// same configuration is required for several worksheets. Each option that
+    /// is set in the `WorksheetOptions` is applied using the equivalent
+    /// worksheet method, such as [`set_zoom()`](Worksheet::set_zoom) or
+    /// [`set_freeze_panes()`](Worksheet::set_freeze_panes). Options that aren't
+    /// set are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - A [`WorksheetOptions`] struct reference.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::SheetnameCannotBeBlank`] - Worksheet name cannot be
+    ///   blank.
+    /// * [`XlsxError::SheetnameLengthExceeded`] - Worksheet name exceeds
+    ///   Excel's limit of 31 characters.
+    /// * [`XlsxError::SheetnameContainsInvalidCharacter`] - Worksheet name
+    ///   cannot contain invalid characters: `[ ] : * ? / \`
+    /// * [`XlsxError::SheetnameStartsOrEndsWithApostrophe`] - Worksheet name
+    ///   cannot start or end with an apostrophe.
+    /// * [`XlsxError::SheetnameReserved`] - Worksheet name "History" is
+    ///   disallowed
+    /// * [`XlsxError::RowColumnLimitError`] - The freeze panes row or column
+    ///   exceeds Excel's worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates applying the same options to several
+    /// worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_options_intro.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Workbook, WorksheetOptions, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let options = WorksheetOptions::new()
+    ///         .set_tab_color(Color::Green)
+    ///         .set_zoom(80)
+    ///         .set_freeze_panes(1, 0)
+    ///         .set_screen_gridlines(false);
+    ///
+    ///     for region in ["North", "South", "East", "West"] {
+    ///         let worksheet = workbook.add_worksheet();
+    ///         worksheet.set_name(region)?.set_options(&options)?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_options(&mut self, options: &WorksheetOptions) -> Result<&mut Worksheet, XlsxError> {
+        // Check the fallible options first so that the worksheet is unchanged
+        // if there is an error.
+        let name = match &options.name {
+            Some(name) => Some(Name::new(name)?),
+            None => None,
+        };
+
+        if let Some((row, col)) = options.freeze_panes {
+            if !self.check_dimensions_only(row, col) {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+        }
+
+        if name.is_some() {
+            self.name = name;
+        }
+
+        if let Some(color) = options.tab_color {
+            self.set_tab_color(color);
+        }
+
+        if let Some(zoom) = options.zoom {
+            self.set_zoom(zoom);
+        }
+
+        if let Some((row, col)) = options.freeze_panes {
+            self.set_freeze_panes(row, col)?;
+        }
+
+        if let Some(enable) = options.right_to_left {
+            self.set_right_to_left(enable);
+        }
+
+        if let Some(enable) = options.screen_gridlines {
+            self.set_screen_gridlines(enable);
+        }
+
+        if let Some(enable) = options.hidden {
+            self.set_hidden(enable);
+        }
+
+        Ok(self)
+    }
+
     /// Set the paper type/size when printing.
     ///
     /// This method is used to set the paper format for the printed output of a
@@ -6202,29 +7419,112 @@ impl Worksheet {
     ///     // Pre-scroll to the row 20.
     ///     worksheet.set_freeze_panes_top_cell(19, 0)?;
     ///
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
+    ///
+    pub fn set_freeze_panes_top_cell(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        self.panes.top_cell = (row, col);
+        Ok(self)
+    }
+
+    /// Freeze the top row of the worksheet.
+    ///
+    /// This is a shortcut for `set_freeze_panes(1, 0)`, which keeps a header
+    /// row visible when the worksheet is scrolled down. See
+    /// [`set_freeze_panes()`](Worksheet::set_freeze_panes) for more details.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates freezing the top row and the first
+    /// column of worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_freeze_top_row.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Freeze the top row only.
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     worksheet1.write_string(0, 0, "Scroll down")?;
+    ///     worksheet1.freeze_top_row();
+    ///
+    ///     // Freeze the leftmost column only.
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     worksheet2.write_string(0, 0, "Scroll across")?;
+    ///     worksheet2.freeze_first_column();
+    ///
+    ///     // Freeze the top two rows and leftmost column.
+    ///     let worksheet3 = workbook.add_worksheet();
+    ///     worksheet3.write_string(0, 0, "Scroll down or across")?;
+    ///     worksheet3.freeze_headers(2, 1)?;
+    ///
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn freeze_top_row(&mut self) -> &mut Worksheet {
+        self.panes.freeze_cell = (1, 0);
+        self
+    }
+
+    /// Freeze the first column of the worksheet.
+    ///
+    /// This is a shortcut for `set_freeze_panes(0, 1)`, which keeps the
+    /// leftmost column visible when the worksheet is scrolled across. See
+    /// [`set_freeze_panes()`](Worksheet::set_freeze_panes) for more details
+    /// and [`freeze_top_row()`](Worksheet::freeze_top_row) for an example.
+    ///
+    pub fn freeze_first_column(&mut self) -> &mut Worksheet {
+        self.panes.freeze_cell = (0, 1);
+        self
+    }
+
+    /// Freeze a number of header rows and columns in the worksheet.
+    ///
+    /// This is a shortcut for `set_freeze_panes(rows, cols)` where the
+    /// arguments are the number of rows at the top, and columns on the left,
+    /// to keep visible when the worksheet is scrolled. Either can be 0. See
+    /// [`set_freeze_panes()`](Worksheet::set_freeze_panes) for more details
+    /// and [`freeze_top_row()`](Worksheet::freeze_top_row) for an example.
+    ///
+    /// # Arguments
     ///
-    /// Output file:
+    /// * `rows` - The number of header rows to freeze.
+    /// * `cols` - The number of header columns to freeze.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
+    /// # Errors
     ///
-    pub fn set_freeze_panes_top_cell(
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    pub fn freeze_headers(
         &mut self,
-        row: RowNum,
-        col: ColNum,
+        rows: RowNum,
+        cols: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and col are in the allowed range.
-        if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        self.panes.top_cell = (row, col);
-        Ok(self)
+        self.set_freeze_panes(rows, cols)
     }
 
     /// Set the printed page header caption.
@@ -6377,6 +7677,18 @@ impl Worksheet {
     ///  </headerFooter>
     /// ```
     ///
+    /// As an alternative to writing the control characters by hand the header
+    /// or footer string can be built with the [`HeaderFooter`](crate::HeaderFooter) struct, which
+    /// handles the section codes, fields and `&` escaping:
+    ///
+    /// ```text
+    /// let header = HeaderFooter::new()
+    ///     .add_left(HeaderFooterText::new("Confidential").set_bold())
+    ///     .add_center(HeaderFooterField::PageNumber);
+    ///
+    /// worksheet.set_header(&header);  // "&L&BConfidential&B&C&P"
+    /// ```
+    ///
     /// Note: Excel requires that the header or footer string be less than 256
     /// characters, including the control characters. Strings longer than this
     /// will not be written, and a warning will be output.
@@ -6482,6 +7794,11 @@ impl Worksheet {
     /// `&[Picture]` element is added to the header formatting string such as
     /// `"&L&[Picture]"`.
     ///
+    /// The image can be created from a file or from an in-memory buffer with
+    /// [`Image::new_from_buffer()`](crate::Image::new_from_buffer). To set the
+    /// images for several sections in one call see
+    /// [`set_header_images()`](Worksheet::set_header_images()).
+    ///
     /// # Arguments
     ///
     /// * `position` - The image position as defined by the [`HeaderImagePosition`]
@@ -6635,6 +7952,105 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Insert images in the left, center and right sections of a worksheet
+    /// header.
+    ///
+    /// This is a convenience method for setting all of the header images with
+    /// one call. The images are given in Left, Center and Right order and a
+    /// `None` value clears the image in that section. As with
+    /// [`set_header_image()`](Worksheet::set_header_image()) each image
+    /// requires a corresponding `&[Picture]`/`&G` variable in the header
+    /// string.
+    ///
+    /// # Arguments
+    ///
+    /// * `images` - An array of optional [`Image`] references for the left,
+    ///   center and right sections of the header.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string. In
+    ///   this case none of the header images are changed.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding images to the left and right
+    /// sections of a worksheet header.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_header_images.rs
+    /// #
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create an image from a file and another from an in-memory buffer.
+    ///     let mut image1 = Image::new("examples/rust_logo.png")?;
+    ///     image1.set_scale_width(0.5).set_scale_height(0.5);
+    ///
+    ///     let buffer = std::fs::read("examples/rust_logo.png")?;
+    ///     let mut image2 = Image::new_from_buffer(&buffer)?;
+    ///     image2.set_scale_width(0.5).set_scale_height(0.5);
+    ///
+    ///     // Insert the images in the left and right sections of the header.
+    ///     worksheet.set_header("&L&[Picture]&CQuarterly Report&R&[Picture]");
+    ///     worksheet.set_header_images([Some(&image1), None, Some(&image2)])?;
+    /// #
+    /// #     // Increase the top margin to 1.2 for clarity. The -1.0 values are ignored.
+    /// #     worksheet.set_margins(-1.0, -1.0, 1.2, -1.0, -1.0, -1.0);
+    /// #
+    /// #     // Set Page View mode so the images are visible.
+    /// #     worksheet.set_view_page_layout();
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_header_images(
+        &mut self,
+        images: [Option<&Image>; 3],
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_header_footer_images(images, true)?;
+
+        Ok(self)
+    }
+
+    /// Insert images in the left, center and right sections of a worksheet
+    /// footer.
+    ///
+    /// See the documentation for
+    /// [`set_header_images()`](Worksheet::set_header_images()) for more
+    /// details.
+    ///
+    /// # Arguments
+    ///
+    /// * `images` - An array of optional [`Image`] references for the left,
+    ///   center and right sections of the footer.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the footer string. In
+    ///   this case none of the footer images are changed.
+    ///
+    pub fn set_footer_images(
+        &mut self,
+        images: [Option<&Image>; 3],
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.set_header_footer_images(images, false)?;
+
+        Ok(self)
+    }
+
     /// Set the page setup option to scale the header/footer with the document.
     ///
     /// This option determines whether the headers and footers use the same
@@ -6675,7 +8091,7 @@ impl Worksheet {
     /// # Arguments
     ///
     /// * `enable` - Turn the property on/off. It is on by default.
-    ///S
+    ///
     pub fn set_header_footer_align_with_page(&mut self, enable: bool) -> &mut Worksheet {
         self.header_footer_align_with_page = enable;
 
@@ -6767,6 +8183,72 @@ impl Worksheet {
         self
     }
 
+    /// Set the page margins for the printed page, in centimeters.
+    ///
+    /// This is the same as [`set_margins()`](Worksheet::set_margins) except
+    /// that the margins are in centimeters. As with `set_margins()` a negative
+    /// value will give the default Excel value.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Left margin in centimeters.
+    /// * `right` - Right margin in centimeters.
+    /// * `top` - Top margin in centimeters.
+    /// * `bottom` - Bottom margin in centimeters.
+    /// * `header` - Header margin in centimeters.
+    /// * `footer` - Footer margin in centimeters.
+    ///
+    pub fn set_margins_cm(
+        &mut self,
+        left: f64,
+        right: f64,
+        top: f64,
+        bottom: f64,
+        header: f64,
+        footer: f64,
+    ) -> &mut Worksheet {
+        self.set_margins(
+            left / CM_PER_INCH,
+            right / CM_PER_INCH,
+            top / CM_PER_INCH,
+            bottom / CM_PER_INCH,
+            header / CM_PER_INCH,
+            footer / CM_PER_INCH,
+        )
+    }
+
+    /// Set the page margins for the printed page, in millimeters.
+    ///
+    /// See [`set_margins_cm()`](Worksheet::set_margins_cm) for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `left` - Left margin in millimeters.
+    /// * `right` - Right margin in millimeters.
+    /// * `top` - Top margin in millimeters.
+    /// * `bottom` - Bottom margin in millimeters.
+    /// * `header` - Header margin in millimeters.
+    /// * `footer` - Footer margin in millimeters.
+    ///
+    pub fn set_margins_mm(
+        &mut self,
+        left: f64,
+        right: f64,
+        top: f64,
+        bottom: f64,
+        header: f64,
+        footer: f64,
+    ) -> &mut Worksheet {
+        self.set_margins_cm(
+            left / 10.0,
+            right / 10.0,
+            top / 10.0,
+            bottom / 10.0,
+            header / 10.0,
+            footer / 10.0,
+        )
+    }
+
     /// Set the first page number when printing.
     ///
     /// The `set_print_first_page_number()` method is used to set the page
@@ -7075,6 +8557,31 @@ impl Worksheet {
         self
     }
 
+    /// Set the print quality of the printed page in dots per inch.
+    ///
+    /// Set the horizontal and vertical print resolution for the worksheet.
+    /// Excel uses the printer's available resolutions so the value should be
+    /// one that the target printer supports, such as 300 or 600.
+    ///
+    /// See also the `rust_xlsxwriter` documentation on [Worksheet - Page Setup].
+    ///
+    /// [Worksheet - Page Setup]: https://rustxlsxwriter.github.io/worksheet/page_setup.html
+    ///
+    /// # Arguments
+    ///
+    /// * `dpi` - The print quality in dots per inch. The default is 200. A
+    ///   value of 0 is ignored.
+    ///
+    pub fn set_print_quality(&mut self, dpi: u16) -> &mut Worksheet {
+        if dpi == 0 {
+            return self;
+        }
+
+        self.print_quality = dpi;
+        self.page_setup_changed = true;
+        self
+    }
+
     /// Set the page setup option to print the row and column headers on the
     /// printed page.
     ///
@@ -7209,6 +8716,98 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Apply a set of page setup options to the worksheet.
+    ///
+    /// The `set_page_setup()` method applies the options defined in a
+    /// [`PageSetup`] struct to the worksheet. This is useful when the same page
+    /// setup is required for several worksheets. Each option that is set in the
+    /// `PageSetup` is applied using the equivalent worksheet method, such as
+    /// [`set_landscape()`](Worksheet::set_landscape) or
+    /// [`set_margins()`](Worksheet::set_margins). Options that aren't set are
+    /// left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_setup` - A [`PageSetup`] struct reference.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - The print area row or column
+    ///   exceeds Excel's worksheet limits.
+    /// * [`XlsxError::RowColumnOrderError`] - The print area first row or
+    ///   column is larger than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates applying the same page setup to
+    /// several worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_page_setup_intro.rs
+    /// #
+    /// # use rust_xlsxwriter::{PageSetup, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let page_setup = PageSetup::new()
+    ///         .set_landscape()
+    ///         .set_paper_size(9) // A4 paper size.
+    ///         .set_margins(0.5, 0.5, 0.75, 0.75, 0.3, 0.3)
+    ///         .set_print_fit_to_pages(1, 0)
+    ///         .set_header("&CQuarterly Sales")
+    ///         .set_footer("&CPage &P of &N");
+    ///
+    ///     for region in ["North", "South", "East", "West"] {
+    ///         let worksheet = workbook.add_worksheet();
+    ///         worksheet.set_name(region)?;
+    ///         worksheet.set_page_setup(&page_setup)?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_page_setup(&mut self, page_setup: &PageSetup) -> Result<&mut Worksheet, XlsxError> {
+        if let Some((first_row, first_col, last_row, last_col)) = page_setup.print_area {
+            self.set_print_area(first_row, first_col, last_row, last_col)?;
+        }
+
+        match page_setup.portrait {
+            Some(true) => self.set_portrait(),
+            Some(false) => self.set_landscape(),
+            None => self,
+        };
+
+        if let Some(paper_size) = page_setup.paper_size {
+            self.set_paper_size(paper_size);
+        }
+
+        if let Some((left, right, top, bottom, header, footer)) = page_setup.margins {
+            self.set_margins(left, right, top, bottom, header, footer);
+        }
+
+        if let Some(scale) = page_setup.print_scale {
+            self.set_print_scale(scale);
+        }
+
+        if let Some((width, height)) = page_setup.print_fit_to_pages {
+            self.set_print_fit_to_pages(width, height);
+        }
+
+        if let Some(header) = &page_setup.header {
+            self.set_header(header);
+        }
+
+        if let Some(footer) = &page_setup.footer {
+            self.set_footer(footer);
+        }
+
+        Ok(self)
+    }
+
     /// Set the number of rows to repeat at the top of each printed page.
     ///
     /// For large Excel documents it is often desirable to have the first row or
@@ -7450,6 +9049,8 @@ impl Worksheet {
     /// src="https://rustxlsxwriter.github.io/images/worksheet_autofit.png">
     ///
     pub fn autofit(&mut self) -> &mut Worksheet {
+        self.flush_buffered_row(None);
+
         let mut max_widths: HashMap<ColNum, u16> = HashMap::new();
 
         // Iterate over all of the data in the worksheet and find the max data
@@ -7457,7 +9058,7 @@ impl Worksheet {
         for row_num in self.dimensions.first_row..=self.dimensions.last_row {
             if let Some(columns) = self.data_table.get(&row_num) {
                 for col_num in self.dimensions.first_col..=self.dimensions.last_col {
-                    if let Some(cell) = columns.get(&col_num) {
+                    if let Some(cell) = columns.get(col_num) {
                         let mut pixel_width = match cell {
                             // For strings we do a calculation based on
                             // character widths taken from Excel. For rich
@@ -7473,12 +9074,12 @@ impl Worksheet {
                                 if string.contains('\n') {
                                     let mut max = 0;
                                     for segment in string.split('\n') {
-                                        let length = utility::pixel_width(segment);
+                                        let length = self.autofit_pixel_width(segment);
                                         max = cmp::max(max, length);
                                     }
                                     max
                                 } else {
-                                    utility::pixel_width(string)
+                                    self.autofit_pixel_width(string)
                                 }
                             }
 
@@ -7487,12 +9088,21 @@ impl Worksheet {
                             // gives a slightly greater width for the decimal
                             // place and minus sign but only by a few pixels and
                             // over-estimation is okay.
-                            CellType::Number { number, .. } => 7 * number.to_string().len() as u16,
+                            CellType::Number { number, .. } => {
+                                if self.autofit_string_width.is_some() {
+                                    self.autofit_pixel_width(&number.to_string())
+                                } else {
+                                    7 * number.to_string().len() as u16
+                                }
+                            }
 
                             // For Boolean types we use the Excel standard
                             // widths for TRUE and FALSE.
                             CellType::Boolean { boolean, .. } => {
-                                if *boolean {
+                                if self.autofit_string_width.is_some() {
+                                    let string = if *boolean { "TRUE" } else { "FALSE" };
+                                    self.autofit_pixel_width(string)
+                                } else if *boolean {
                                     31
                                 } else {
                                     36
@@ -7515,7 +9125,7 @@ impl Worksheet {
                                 if result.as_ref() == "0" || result.is_empty() {
                                     0
                                 } else {
-                                    utility::pixel_width(result)
+                                    self.autofit_pixel_width(result)
                                 }
                             }
 
@@ -7524,7 +9134,13 @@ impl Worksheet {
                             // number format to get the actual string width for
                             // all format types so we use a width based on the
                             // Excel's default format: mm/dd/yyyy.
-                            CellType::DateTime { .. } => 68,
+                            CellType::DateTime { .. } => {
+                                if self.autofit_string_width.is_some() {
+                                    self.autofit_pixel_width("00/00/0000")
+                                } else {
+                                    68
+                                }
+                            }
 
                             // Ignore blank cells, like Excel.
                             CellType::Blank { .. } => 0,
@@ -7558,19 +9174,183 @@ impl Worksheet {
             }
         }
 
-        // Set the max character width for each column.
-        for (col, pixels) in &max_widths {
-            let width = Self::pixels_to_width(*pixels + 7);
-            self.store_column_width(*col, width, true);
+        // Set the max character width for each column.
+        for (col, pixels) in &max_widths {
+            let width = Self::pixels_to_width(*pixels + 7);
+            self.store_column_width(*col, width, true);
+        }
+
+        self
+    }
+
+    /// Set a custom function to measure string widths for autofit.
+    ///
+    /// By default [`autofit()`](Worksheet::autofit) estimates the display
+    /// width of the cell data using character widths for Excel's default font
+    /// of Calibri 11. This method lets you supply a function that returns the
+    /// width, in pixels, of a string in the font actually used, for example
+    /// based on metrics from a font library or a scaled version of the default
+    /// widths for a different font size. It can also be used to give a more
+    /// accurate width for CJK and other wide characters, which the default
+    /// estimate treats as 8 pixels wide.
+    ///
+    /// The function is used for strings, formula results and, since they also
+    /// depend on the font, for the displayed form of numbers, booleans and
+    /// dates. The column padding that Excel adds is applied to the result, so
+    /// the function should only return the width of the text.
+    ///
+    /// The default widths are available as
+    /// [`utility::pixel_width()`](crate::utility::pixel_width).
+    ///
+    /// # Arguments
+    ///
+    /// * `string_width` - A function or closure that returns the pixel width
+    ///   of a string.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates autofitting columns for data written
+    /// in a larger font and with CJK characters.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_autofit_string_width.rs
+    /// #
+    /// # use rust_xlsxwriter::{utility, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let format = Format::new().set_font_size(16);
+    ///
+    ///     worksheet.write_with_format(0, 0, "Quarterly sales report", &format)?;
+    ///     worksheet.write_with_format(1, 0, "四半期売上報告", &format)?;
+    ///
+    ///     // Scale the default Calibri 11 widths to 16 points, and treat CJK
+    ///     // characters as double width.
+    ///     worksheet.set_autofit_string_width(|string| {
+    ///         let width: u32 = string
+    ///             .chars()
+    ///             .map(|char| {
+    ///                 if char > '\u{2E80}' {
+    ///                     16
+    ///                 } else {
+    ///                     u32::from(utility::pixel_width(&char.to_string()))
+    ///                 }
+    ///             })
+    ///             .sum();
+    ///
+    ///         (width * 16 / 11) as u16
+    ///     });
+    ///
+    ///     worksheet.autofit();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_autofit_string_width<F>(&mut self, string_width: F) -> &mut Worksheet
+    where
+        F: Fn(&str) -> u16 + Send + Sync + 'static,
+    {
+        self.autofit_string_width = Some(Box::new(string_width));
+        self
+    }
+
+    /// Get statistics about the content of the worksheet.
+    ///
+    /// The `stats()` method returns a [`WorksheetStats`] struct with the
+    /// number of cells of each type, the number of images, charts and tables,
+    /// and the used range of the worksheet. This can be used to log or check
+    /// the content of a worksheet before the workbook is saved.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting statistics about the content
+    /// of a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_stats.rs
+    /// #
+    /// # use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Write some data.
+    ///     worksheet.write(0, 0, "Apples")?;
+    ///     worksheet.write(0, 1, 10)?;
+    ///     worksheet.write(1, 0, "Pears")?;
+    ///     worksheet.write(1, 1, 20)?;
+    ///     worksheet.write(2, 1, Formula::new("=SUM(B1:B2)"))?;
+    ///
+    ///     // Check the worksheet content.
+    ///     let stats = worksheet.stats();
+    ///
+    ///     assert_eq!(stats.strings, 2);
+    ///     assert_eq!(stats.numbers, 2);
+    ///     assert_eq!(stats.formulas, 1);
+    ///     assert_eq!(stats.used_range, Some((0, 0, 2, 1)));
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn stats(&self) -> WorksheetStats {
+        let mut stats = WorksheetStats {
+            images: self.images.len() + self.absolute_images.len(),
+            charts: self.charts.len(),
+            tables: self.tables.len(),
+            ..WorksheetStats::default()
+        };
+
+        for (_, cell) in self.data_table.values().flat_map(|row| row.cells.iter()) {
+            match cell {
+                CellType::String { .. } | CellType::RichString { .. } => stats.strings += 1,
+                CellType::Number { .. } => stats.numbers += 1,
+                CellType::DateTime { .. } => stats.datetimes += 1,
+                CellType::Boolean { .. } => stats.booleans += 1,
+                CellType::Formula { .. } | CellType::ArrayFormula { .. } => stats.formulas += 1,
+                CellType::Blank { .. } => stats.blanks += 1,
+            }
+        }
+
+        // The dimensions are only changed from their initial values when data
+        // is written to the worksheet.
+        if self.dimensions.first_row != ROW_MAX {
+            stats.used_range = Some((
+                self.dimensions.first_row,
+                self.dimensions.first_col,
+                self.dimensions.last_row,
+                self.dimensions.last_col,
+            ));
         }
 
-        self
+        stats
     }
 
     // -----------------------------------------------------------------------
     // Crate level helper methods.
     // -----------------------------------------------------------------------
 
+    // Get the pixel width of a string for autofit, using the user supplied
+    // string width function, if any.
+    fn autofit_pixel_width(&self, string: &str) -> u16 {
+        match &self.autofit_string_width {
+            Some(string_width) => string_width(string),
+            None => utility::pixel_width(string),
+        }
+    }
+
     // Get the minimum row number for the dimension check/set.
     fn get_min_row(&self) -> RowNum {
         if self.dimensions.first_row == ROW_MAX {
@@ -7638,7 +9418,7 @@ impl Worksheet {
         let mut has_cell_data = false;
 
         if let Some(columns) = self.data_table.get(&row_num) {
-            if let Some(cell) = columns.get(&col_num) {
+            if let Some(cell) = columns.get(col_num) {
                 has_cell_data = true;
 
                 match cell {
@@ -7669,10 +9449,8 @@ impl Worksheet {
                             }
                         }
                     }
-                    CellType::Blank { .. } => {
-                        if filter_condition.should_match_blanks {
-                            return true;
-                        }
+                    CellType::Blank { .. } if filter_condition.should_match_blanks => {
+                        return true;
                     }
                     // We don't currently try to handle matching any other data types.
                     _ => {}
@@ -7734,7 +9512,7 @@ impl Worksheet {
         filter: &FilterData,
     ) -> bool {
         if let Some(columns) = self.data_table.get(&row_num) {
-            if let Some(cell) = columns.get(&col_num) {
+            if let Some(cell) = columns.get(col_num) {
                 match cell {
                     CellType::String { string, .. }
                     | CellType::RichString {
@@ -7860,6 +9638,25 @@ impl Worksheet {
         self.store_number_type(row, col, number, format, true)
     }
 
+    // Store an unformatted datetime cell in the worksheet data table
+    // structure. Excel dates/times require a number format or else they will
+    // appear as a number, so a default format is added unless the user has
+    // turned it off.
+    fn store_unformatted_datetime(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: f64,
+        num_format: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if self.use_default_datetime_formats {
+            let format = Format::new().set_num_format(num_format);
+            self.store_datetime(row, col, number, Some(&format))
+        } else {
+            self.store_datetime(row, col, number, None)
+        }
+    }
+
     // Store a number/datetime cell in the worksheet data table structure.
     fn store_number_type(
         &mut self,
@@ -7925,8 +9722,9 @@ impl Worksheet {
         }
 
         //  Check that the string is < Excel limit of 32767 chars.
-        if string.chars().count() > MAX_STRING_LEN {
-            return Err(XlsxError::MaxStringLengthExceeded);
+        let num_chars = string.chars().count();
+        if num_chars > MAX_STRING_LEN {
+            return self.store_long_string(row, col, &string, num_chars, format);
         }
 
         // Get the index of the format object, if any.
@@ -7937,7 +9735,7 @@ impl Worksheet {
 
         // Create the appropriate cell type to hold the data.
         let cell = CellType::String {
-            string: Arc::from(string),
+            string: self.intern_string(&string),
             xf_index,
         };
 
@@ -7947,6 +9745,47 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Handle a string that exceeds Excel's length limit according to the
+    // worksheet string overflow policy.
+    fn store_long_string(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        string: &str,
+        num_chars: usize,
+        format: Option<&Format>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        match self.string_overflow {
+            StringOverflow::Error => Err(XlsxError::MaxStringLengthExceeded(
+                self.cell_location(row, col),
+            )),
+
+            StringOverflow::Truncate => {
+                let mut truncated: String = string.chars().take(MAX_STRING_LEN - 1).collect();
+                truncated.push('…');
+
+                self.store_string(row, col, truncated, format)
+            }
+
+            StringOverflow::Split => {
+                // Check that all the cells fit in the row before writing any.
+                let num_cells = num_chars.div_ceil(MAX_STRING_LEN);
+                let last_col = col as usize + num_cells - 1;
+                if last_col >= COL_MAX as usize {
+                    return Err(XlsxError::RowColumnLimitError);
+                }
+
+                let chunks = string.chars().chunks(MAX_STRING_LEN);
+                for (offset, chunk) in chunks.into_iter().enumerate() {
+                    let chunk: String = chunk.collect();
+                    self.store_string(row, col + offset as ColNum, chunk, format)?;
+                }
+
+                Ok(self)
+            }
+        }
+    }
+
     // Store a rich string cell in the worksheet data table structure.
     fn store_rich_string(
         &mut self,
@@ -7972,7 +9811,9 @@ impl Worksheet {
 
         //  Check that the string is < Excel limit of 32767 chars.
         if string.chars().count() > MAX_STRING_LEN {
-            return Err(XlsxError::MaxStringLengthExceeded);
+            return Err(XlsxError::MaxStringLengthExceeded(
+                self.cell_location(row, col),
+            ));
         }
 
         // Get the index of the format object, if any.
@@ -7983,7 +9824,7 @@ impl Worksheet {
 
         // Create the appropriate cell type to hold the data.
         let cell = CellType::RichString {
-            string: Arc::from(string),
+            string: self.intern_string(string),
             xf_index,
             raw_string: Arc::from(raw_string),
         };
@@ -8163,6 +10004,24 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Check that an image hyperlink, if present, has a known type and is
+    // within Excel's limits.
+    fn check_image_url(&self, image: &Image, location: String) -> Result<(), XlsxError> {
+        if let Some(url) = &image.url {
+            let hyperlink = Hyperlink::new(url.clone());
+
+            if hyperlink.drawing_relationship().is_none() {
+                return Err(XlsxError::UnknownUrlType(url.link.clone()));
+            }
+
+            if hyperlink.exceeds_max_length() {
+                return Err(XlsxError::MaxUrlLengthExceeded(location));
+            }
+        }
+
+        Ok(())
+    }
+
     // Store a url and associated properties. Urls in Excel are stored in a
     // number of places: they are written as a string similar to
     // write_string_with_format(), they are written in the <hyperlinks> element
@@ -8174,7 +10033,31 @@ impl Worksheet {
         url: Url,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let hyperlink = Hyperlink::new(url)?;
+        let hyperlink = Hyperlink::new(url);
+
+        if hyperlink.exceeds_max_length() {
+            return Err(XlsxError::MaxUrlLengthExceeded(
+                self.cell_location(row, col),
+            ));
+        }
+
+        // Check the Excel limit on the number of hyperlinks in a worksheet.
+        // Overwriting an existing hyperlink doesn't add to the count.
+        if self.hyperlinks.len() >= MAX_HYPERLINKS && !self.hyperlinks.contains_key(&(row, col)) {
+            if !self.hyperlink_limit_fallback {
+                return Err(XlsxError::MaxHyperlinksExceeded(
+                    self.cell_location(row, col),
+                ));
+            }
+
+            match format {
+                Some(format) => self.write_string_with_format(row, col, &hyperlink.text, format)?,
+                None => self.write_string(row, col, &hyperlink.text)?,
+            };
+
+            self.downgraded_hyperlinks += 1;
+            return Ok(self);
+        }
 
         match format {
             Some(format) => self.write_string_with_format(row, col, &hyperlink.text, format)?,
@@ -8215,22 +10098,22 @@ impl Worksheet {
             // Accumulate the string segments into a unformatted string.
             raw_string.push_str(string);
 
-            let attributes =
+            let attributes: &[(&str, &str)] =
                 if string.starts_with(['\t', '\n', ' ']) || string.ends_with(['\t', '\n', ' ']) {
-                    vec![("xml:space", "preserve")]
+                    &[("xml:space", "preserve")]
                 } else {
-                    vec![]
+                    &[]
                 };
 
             // First segment doesn't require a font run for the default format.
             if format.is_default() && first_segment {
                 styler.writer.xml_start_tag_only("r");
-                styler.writer.xml_data_element("t", string, &attributes);
+                styler.writer.xml_data_element("t", string, attributes);
                 styler.writer.xml_end_tag("r");
             } else {
                 styler.writer.xml_start_tag_only("r");
                 styler.write_font(&format.font);
-                styler.writer.xml_data_element("t", string, &attributes);
+                styler.writer.xml_data_element("t", string, attributes);
                 styler.writer.xml_end_tag("r");
             }
             first_segment = false;
@@ -8239,23 +10122,86 @@ impl Worksheet {
         Ok((styler.writer.read_to_string(), raw_string))
     }
 
+    // Get a shared copy of a string for storing in the worksheet data table.
+    // Worksheets often contain a small number of distinct strings repeated
+    // over a large number of cells so each unique string is only allocated
+    // once and the other cells hold a reference to it.
+    fn intern_string(&mut self, string: &str) -> Arc<str> {
+        if let Some(interned) = self.string_cache.get(string) {
+            return Arc::clone(interned);
+        }
+
+        let interned: Arc<str> = Arc::from(string);
+        self.string_cache.insert(Arc::clone(&interned));
+        interned
+    }
+
     // Insert a cell value into the worksheet data table structure.
     fn insert_cell(&mut self, row: RowNum, col: ColNum, cell: CellType) {
-        match self.data_table.entry(row) {
-            Entry::Occupied(mut entry) => {
-                // The row already exists. Insert/replace column value.
-                let columns = entry.get_mut();
-                columns.insert(col, cell);
+        // The row being written by a RowWriter is held outside the data table
+        // so that cells can be appended to it without a row lookup.
+        if let Some((buffered_row, cells)) = &mut self.buffered_row {
+            if *buffered_row == row {
+                cells.insert(col, cell);
+                return;
+            }
+        }
+
+        // Insert/replace the column value, creating the row if it doesn't
+        // exist.
+        self.data_table.entry(row).or_default().insert(col, cell);
+    }
+
+    // Return the row buffered by a RowWriter to the worksheet data table
+    // structure and optionally take the next row out of it.
+    fn flush_buffered_row(&mut self, next_row: Option<RowNum>) {
+        if let Some((row, cells)) = self.buffered_row.take() {
+            let capacity = cells.cells.len();
+
+            if !cells.cells.is_empty() {
+                self.data_table.insert(row, cells);
             }
-            Entry::Vacant(entry) => {
-                // The row doesn't exist, create a new row with columns and insert
-                // the cell value.
-                let columns = BTreeMap::from([(col, cell)]);
-                entry.insert(columns);
+
+            if let Some(next_row) = next_row {
+                self.buffer_row(next_row, capacity);
             }
         }
     }
 
+    // Take a row out of the worksheet data table structure for a RowWriter.
+    // New rows are allocated with the size of the previous row since the rows
+    // of row ordered data are generally the same length.
+    fn buffer_row(&mut self, row: RowNum, capacity: usize) {
+        let cells = self.data_table.remove(&row).unwrap_or_else(|| CellRow {
+            cells: Vec::with_capacity(capacity),
+        });
+
+        self.buffered_row = Some((row, cells));
+    }
+
+    // Convert a column width in pixels to Excel character units.
+    fn column_pixels_to_width(width: u16) -> f64 {
+        // Properties for Calibri 11.
+        let max_digit_width = 7.0_f64;
+        let padding = 5.0_f64;
+        let mut width = f64::from(width);
+
+        if width < 12.0 {
+            width /= max_digit_width + padding;
+        } else {
+            width = (width - padding) / max_digit_width;
+        }
+
+        width
+    }
+
+    // Convert a length in inches to the nearest whole pixel at 96 pixels per
+    // inch, which is the resolution that Excel uses for column widths.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn inches_to_pixels(inches: f64) -> u16 {
+        (inches * 96.0).round().clamp(0.0, f64::from(u16::MAX)) as u16
+    }
+
     // Store the column width in Excel character units. Updates to the width can
     // come from the external user or from the internal autofit() routines.
     fn store_column_width(&mut self, col: ColNum, width: f64, autofit: bool) {
@@ -8293,6 +10239,74 @@ impl Worksheet {
         }
     }
 
+    // Get a cell location like "Sheet1!C3" for use in error messages.
+    fn cell_location(&self, row: RowNum, col: ColNum) -> String {
+        let cell = utility::rowcol_to_cell(row, col);
+
+        match &self.name {
+            Some(name) => format!("{}!{cell}", utility::quote_sheetname(&name.to_string())),
+            None => cell,
+        }
+    }
+
+    // Check that a merge range is valid and that it doesn't overlap a previous
+    // merge range, which is a major error in Excel. The range isn't stored
+    // until the cells have been written successfully.
+    fn check_merge_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<CellRange, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions(first_row, first_col)
+            || !self.check_dimensions(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Check that the range isn't a singe cell, which isn't allowed by Excel.
+        if first_row == last_row && first_col == last_col {
+            return Err(XlsxError::MergeRangeSingleCell);
+        }
+
+        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
+
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                if let Some(index) = self.merged_cells.get(&(row, col)) {
+                    let previous_cell_range = &self.merged_ranges[*index];
+                    return Err(XlsxError::MergeRangeOverlaps(
+                        cell_range.to_error_string(),
+                        previous_cell_range.to_error_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(cell_range)
+    }
+
+    // Store a validated merge range. Note, the ranges are stored in a separate
+    // Vec to the cells to cut down on storage size.
+    fn store_merge_range(&mut self, cell_range: CellRange) {
+        let new_index = self.merged_ranges.len();
+
+        for row in cell_range.first_row..=cell_range.last_row {
+            for col in cell_range.first_col..=cell_range.last_col {
+                self.merged_cells.insert((row, col), new_index);
+            }
+        }
+
+        self.merged_ranges.push(cell_range);
+    }
+
     // Check that row and col are within the allowed Excel range and store max
     // and min values for use in other methods/elements.
     fn check_dimensions(&mut self, row: RowNum, col: ColNum) -> bool {
@@ -8343,7 +10357,13 @@ impl Worksheet {
     // indexes will be replaced by global/workbook indices before the worksheet
     // is saved.
     fn format_index(&mut self, format: &Format) -> u32 {
-        match self.xf_indices.get_mut(format) {
+        // Cells are often written in runs with the same format so check the
+        // last format used before hashing the format for the lookup.
+        if self.xf_formats[self.last_xf_index as usize] == *format {
+            return self.last_xf_index;
+        }
+
+        let xf_index = match self.xf_indices.get_mut(format) {
             Some(xf_index) => *xf_index,
             None => {
                 let xf_index = self.xf_formats.len() as u32;
@@ -8354,7 +10374,10 @@ impl Worksheet {
                 }
                 xf_index
             }
-        }
+        };
+
+        self.last_xf_index = xf_index;
+        xf_index
     }
 
     // Set the mapping between the local format indices and the global/workbook
@@ -8462,45 +10485,15 @@ impl Worksheet {
         let mut rel_ids: HashMap<u64, u32> = HashMap::new();
 
         for (cell, image) in &self.images.clone() {
-            let row = cell.0;
-            let col = cell.1;
-
-            let image_id = match image_ids.get(&image.hash) {
-                Some(image_id) => *image_id,
-                None => {
-                    let image_id = 1 + image_ids.len() as u32;
-                    image_ids.insert(image.hash, image_id);
-                    image_id
-                }
-            };
-
-            let rel_id = match rel_ids.get(&image.hash) {
-                Some(rel_id) => *rel_id,
-                None => {
-                    let rel_id = 1 + rel_ids.len() as u32;
-                    rel_ids.insert(image.hash, rel_id);
-
-                    // Store the linkage to the drawings rels file.
-                    let image_name =
-                        format!("../media/image{image_id}.{}", image.image_type.extension());
-                    self.drawing_relationships.push((
-                        "image".to_string(),
-                        image_name,
-                        String::new(),
-                    ));
-
-                    rel_id
-                }
-            };
-
-            // Convert the image dimensions to drawing dimensions and store the
-            // drawing object.
-            let mut drawing_info = self.position_object_emus(row, col, image);
-            drawing_info.rel_id = rel_id;
-            self.drawing.drawings.push(drawing_info);
+            // Convert the image dimensions to drawing dimensions.
+            let drawing_info = self.position_object_emus(cell.0, cell.1, image);
+            self.add_drawing_image(image, drawing_info, image_ids, &mut rel_ids);
+        }
 
-            // Store the used image type for the Content Type file.
-            self.image_types[image.image_type.clone() as usize] = true;
+        for (x, y, image) in &self.absolute_images.clone() {
+            // Absolute images are already positioned in EMUs.
+            let drawing_info = Self::position_object_absolute(*x, *y, image);
+            self.add_drawing_image(image, drawing_info, image_ids, &mut rel_ids);
         }
 
         // Store the linkage to the worksheets rels file.
@@ -8514,6 +10507,60 @@ impl Worksheet {
         self.has_drawing_object_linkage = true;
     }
 
+    // Set the rel linkages for a worksheet image and store its drawing object.
+    fn add_drawing_image(
+        &mut self,
+        image: &Image,
+        mut drawing_info: DrawingInfo,
+        image_ids: &mut HashMap<u64, u32>,
+        rel_ids: &mut HashMap<u64, u32>,
+    ) {
+        let image_id = match image_ids.get(&image.hash) {
+            Some(image_id) => *image_id,
+            None => {
+                let image_id = 1 + image_ids.len() as u32;
+                image_ids.insert(image.hash, image_id);
+                image_id
+            }
+        };
+
+        // Store the linkage to the drawings rels file for the image hyperlink,
+        // if present. Each hyperlink has its own rel id.
+        if let Some(url) = &image.url {
+            let hyperlink = Hyperlink::new(url.clone());
+
+            if let Some((target, target_mode)) = hyperlink.drawing_relationship() {
+                self.drawing_relationships
+                    .push(("hyperlink".to_string(), target, target_mode));
+
+                drawing_info.url_rel_id = self.drawing_relationships.len() as u32;
+                drawing_info.url_tip = hyperlink.tip;
+            }
+        }
+
+        drawing_info.rel_id = match rel_ids.get(&image.hash) {
+            Some(rel_id) => *rel_id,
+            None => {
+                // Store the linkage to the drawings rels file.
+                let image_name =
+                    format!("../media/image{image_id}.{}", image.image_type.extension());
+                self.drawing_relationships
+                    .push(("image".to_string(), image_name, String::new()));
+
+                let rel_id = self.drawing_relationships.len() as u32;
+                rel_ids.insert(image.hash, rel_id);
+
+                rel_id
+            }
+        };
+
+        drawing_info.crop = image.crop;
+        self.drawing.drawings.push(drawing_info);
+
+        // Store the used image type for the Content Type file.
+        self.image_types[image.image_type.clone() as usize] = true;
+    }
+
     // Set up images used in headers and footers. Excel handles these
     // differently from worksheet images and stores them in a VML file rather
     // than an Drawing file.
@@ -8602,7 +10649,8 @@ impl Worksheet {
 
             // Convert the chart dimensions to drawing dimensions and store the
             // drawing object.
-            let drawing_info = self.position_object_emus(row, col, chart);
+            let mut drawing_info = self.position_object_emus(row, col, chart);
+            drawing_info.rel_id = self.drawing_relationships.len() as u32;
             self.drawing.drawings.push(drawing_info);
         }
 
@@ -8667,6 +10715,39 @@ impl Worksheet {
         drawing_info
     }
 
+    // Create the drawing information for an object at an absolute position in
+    // the worksheet. The position is already in EMUs and the cell based
+    // coordinates aren't used.
+    fn position_object_absolute<T>(x: u32, y: u32, object: &T) -> DrawingInfo
+    where
+        T: DrawingObject,
+    {
+        let coordinates = DrawingCoordinates {
+            col: 0,
+            row: 0,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        DrawingInfo {
+            from: coordinates.clone(),
+            to: coordinates,
+            col_absolute: x,
+            row_absolute: y,
+            width: round_to_emus(object.width_scaled()),
+            height: round_to_emus(object.height_scaled()),
+            description: object.alt_text(),
+            decorative: object.decorative(),
+            object_movement: object.object_movement(),
+            drawing_type: object.drawing_type(),
+            rel_id: 0,
+            url_rel_id: 0,
+            url_tip: String::new(),
+            crop: [0.0; 4],
+            is_absolute: true,
+        }
+    }
+
     // Calculate the vertices that define the position of a graphical object
     // within the worksheet in pixels.
     //
@@ -8814,6 +10895,10 @@ impl Worksheet {
             object_movement: object.object_movement(),
             drawing_type: object.drawing_type(),
             rel_id: 0,
+            url_rel_id: 0,
+            url_tip: String::new(),
+            crop: [0.0; 4],
+            is_absolute: false,
         }
     }
 
@@ -8864,6 +10949,10 @@ impl Worksheet {
 
     // Reset an worksheet global data or structures between saves.
     pub(crate) fn reset(&mut self) {
+        // Add any cells left in the RowWriter buffer, in case the writer
+        // wasn't dropped normally, so that they are seen by the save steps.
+        self.flush_buffered_row(None);
+
         self.writer.reset();
         self.drawing.writer.reset();
 
@@ -8883,6 +10972,7 @@ impl Worksheet {
         self.drawing_relationships.clear();
         self.vml_drawing_relationships.clear();
         self.header_footer_vml_info.clear();
+        self.workbook_right_to_left = false;
     }
 
     // Check if any external relationships are required.
@@ -8892,6 +10982,30 @@ impl Worksheet {
             || !self.table_relationships.is_empty()
     }
 
+    // Check if there are any url or file hyperlinks. These add to the
+    // worksheet relationships when the worksheet is assembled.
+    pub(crate) fn has_url_hyperlinks(&self) -> bool {
+        self.hyperlinks.values().any(|hyperlink| {
+            matches!(
+                hyperlink.link_type,
+                HyperlinkType::Url | HyperlinkType::File
+            )
+        })
+    }
+
+    // Check if there are any images in the worksheet drawing.
+    pub(crate) fn has_images(&self) -> bool {
+        !self.images.is_empty() || !self.absolute_images.is_empty()
+    }
+
+    // Get the worksheet drawing images in the order that they are stored in
+    // the drawing.
+    pub(crate) fn drawing_images(&self) -> impl Iterator<Item = &Image> {
+        self.images
+            .values()
+            .chain(self.absolute_images.iter().map(|(_, _, image)| image))
+    }
+
     // Check if there is a header image.
     pub(crate) fn has_header_footer_images(&self) -> bool {
         self.header_footer_images[0].is_some()
@@ -8902,6 +11016,48 @@ impl Worksheet {
             || self.header_footer_images[5].is_some()
     }
 
+    // Set the left, center and right header or footer images. All of the
+    // images are checked before any of them are stored.
+    fn set_header_footer_images(
+        &mut self,
+        images: [Option<&Image>; 3],
+        is_header: bool,
+    ) -> Result<(), XlsxError> {
+        let positions = [
+            HeaderImagePosition::Left,
+            HeaderImagePosition::Center,
+            HeaderImagePosition::Right,
+        ];
+
+        let (string, name, offset) = if is_header {
+            (&self.header, "header", 0)
+        } else {
+            (&self.footer, "footer", 3)
+        };
+
+        for (image, position) in images.iter().zip(&positions) {
+            if image.is_some() && !Self::verify_header_footer_image(string, position) {
+                let error = format!(
+                    "No &[Picture] or &[G] variable in {name} string: '{string}' for position = '{position:?}'"
+                );
+                return Err(XlsxError::ParameterError(error));
+            }
+        }
+
+        for (image, position) in images.into_iter().zip(positions) {
+            let index = offset + position.clone() as usize;
+
+            self.header_footer_images[index] = image.map(|image| {
+                let mut image = image.clone();
+                image.header_position = position;
+                image.is_header = is_header;
+                image
+            });
+        }
+
+        Ok(())
+    }
+
     // Check that there is a header/footer &[Picture] variable in the correct
     // position to match the corresponding image object.
     fn verify_header_footer_image(string: &str, position: &HeaderImagePosition) -> bool {
@@ -8960,7 +11116,7 @@ impl Worksheet {
             match self.data_table.get(&row_num) {
                 Some(columns) => {
                     for col_num in first_col..=last_col {
-                        match columns.get(&col_num) {
+                        match columns.get(col_num) {
                             Some(cell) => match cell {
                                 CellType::String { string, .. } => {
                                     data.push(string.to_string());
@@ -8986,8 +11142,23 @@ impl Worksheet {
     // XML assembly methods.
     // -----------------------------------------------------------------------
 
-    //  Assemble and write the XML file.
+    //  Assemble and write the XML file. The packager writes the file in chunks
+    //  so this is used for tests.
+    #[allow(dead_code)]
     pub(crate) fn assemble_xml_file(&mut self, string_table: &mut SharedStringsTable) {
+        self.assemble_xml_file_in_chunks(string_table, None);
+    }
+
+    // Assemble the worksheet xml file, passing the xml to the flush callback,
+    // if any, in chunks as the cell data is written. This avoids holding the
+    // xml for the entire cell data in memory.
+    pub(crate) fn assemble_xml_file_in_chunks(
+        &mut self,
+        string_table: &mut SharedStringsTable,
+        mut flush: Option<XmlFlush>,
+    ) {
+        self.flush_buffered_row(None);
+
         self.writer.xml_declaration();
 
         // Write the worksheet element.
@@ -9009,7 +11180,7 @@ impl Worksheet {
         self.write_cols();
 
         // Write the sheetData element.
-        self.write_sheet_data(string_table);
+        self.write_sheet_data(string_table, &mut flush);
 
         // Write the sheetProtection element.
         if self.protection_on {
@@ -9183,7 +11354,16 @@ impl Worksheet {
     fn write_sheet_view(&mut self) {
         let mut attributes = vec![];
 
-        if self.right_to_left {
+        if !self.screen_gridlines {
+            attributes.push(("showGridLines", "0".to_string()));
+        }
+
+        if !self.row_col_headers {
+            attributes.push(("showRowColHeaders", "0".to_string()));
+        }
+
+        // The direction can also be set for all worksheets by the workbook.
+        if self.right_to_left || self.workbook_right_to_left {
             attributes.push(("rightToLeft", "1".to_string()));
         }
 
@@ -9325,12 +11505,16 @@ impl Worksheet {
     }
 
     // Write the <sheetData> element.
-    fn write_sheet_data(&mut self, string_table: &mut SharedStringsTable) {
+    fn write_sheet_data(
+        &mut self,
+        string_table: &mut SharedStringsTable,
+        flush: &mut Option<XmlFlush>,
+    ) {
         if self.data_table.is_empty() && self.changed_rows.is_empty() {
             self.writer.xml_empty_tag_only("sheetData");
         } else {
             self.writer.xml_start_tag_only("sheetData");
-            self.write_data_table(string_table);
+            self.write_data_table(string_table, flush);
             self.writer.xml_end_tag("sheetData");
         }
     }
@@ -9473,6 +11657,12 @@ impl Worksheet {
             attributes.push(("pageOrder", "overThenDown".to_string()));
         }
 
+        // The first page number defaults to 1 in Excel so it is only written
+        // for other values.
+        if self.first_page_number > 1 {
+            attributes.push(("firstPageNumber", self.first_page_number.to_string()));
+        }
+
         if self.portrait {
             attributes.push(("orientation", "portrait".to_string()));
         } else {
@@ -9480,7 +11670,7 @@ impl Worksheet {
         }
 
         if self.first_page_number > 0 {
-            attributes.push(("useFirstPageNumber", self.first_page_number.to_string()));
+            attributes.push(("useFirstPageNumber", "1".to_string()));
         }
 
         if self.print_black_and_white {
@@ -9491,8 +11681,8 @@ impl Worksheet {
             attributes.push(("draft", "1".to_string()));
         }
 
-        attributes.push(("horizontalDpi", "200".to_string()));
-        attributes.push(("verticalDpi", "200".to_string()));
+        attributes.push(("horizontalDpi", self.print_quality.to_string()));
+        attributes.push(("verticalDpi", self.print_quality.to_string()));
 
         self.writer.xml_empty_tag("pageSetup", &attributes);
     }
@@ -9595,12 +11785,16 @@ impl Worksheet {
     }
 
     // Write out all the row and cell data in the worksheet data table.
-    fn write_data_table(&mut self, string_table: &mut SharedStringsTable) {
+    fn write_data_table(
+        &mut self,
+        string_table: &mut SharedStringsTable,
+        flush: &mut Option<XmlFlush>,
+    ) {
         let spans = self.calculate_spans();
 
         // Swap out the worksheet data structures so we can iterate over it and
         // still call self.write_xml() methods.
-        let mut temp_table: BTreeMap<RowNum, BTreeMap<ColNum, CellType>> = BTreeMap::new();
+        let mut temp_table: BTreeMap<RowNum, CellRow> = BTreeMap::new();
         let mut temp_changed_rows: HashMap<RowNum, RowOptions> = HashMap::new();
         mem::swap(&mut temp_table, &mut self.data_table);
         mem::swap(&mut temp_changed_rows, &mut self.changed_rows);
@@ -9619,7 +11813,7 @@ impl Worksheet {
             };
 
             self.write_table_row(row_num, span, row_options, true);
-            for (&col_num, cell) in columns {
+            for (col_num, cell) in columns.iter() {
                 match cell {
                     CellType::Number { number, xf_index }
                     | CellType::DateTime { number, xf_index } => {
@@ -9631,8 +11825,15 @@ impl Worksheet {
                         string, xf_index, ..
                     } => {
                         let xf_index = self.get_cell_xf_index(*xf_index, row_options, col_num);
-                        let string_index = string_table.shared_string_index(Arc::clone(string));
-                        self.write_string_cell(row_num, col_num, string_index, xf_index);
+                        if self.use_inline_strings {
+                            let is_rich = matches!(cell, CellType::RichString { .. });
+                            self.write_inline_string_cell(
+                                row_num, col_num, string, xf_index, is_rich,
+                            );
+                        } else {
+                            let string_index = string_table.shared_string_index(Arc::clone(string));
+                            self.write_string_cell(row_num, col_num, string_index, xf_index);
+                        }
                     }
                     CellType::Formula {
                         formula,
@@ -9671,6 +11872,7 @@ impl Worksheet {
                 }
             }
             self.writer.xml_end_tag("row");
+            self.writer.flush_chunk(flush);
         }
 
         // Swap back in data.
@@ -9688,7 +11890,7 @@ impl Worksheet {
 
         for row_num in self.dimensions.first_row..=self.dimensions.last_row {
             if let Some(columns) = self.data_table.get(&row_num) {
-                for &col_num in columns.keys() {
+                for col_num in columns.keys() {
                     if span_min == COL_MAX {
                         span_min = col_num;
                         span_max = col_num;
@@ -9723,11 +11925,14 @@ impl Worksheet {
         row_options: Option<&RowOptions>,
         has_data: bool,
     ) {
-        let row_num = (row_num + 1).to_string();
-        let mut attributes = vec![("r", row_num)];
+        // This is called for every row so the attribute values are borrowed
+        // where possible to avoid allocations.
+        let mut row_buffer = itoa::Buffer::new();
+        let row_num = row_buffer.format(row_num + 1);
+        let mut attributes = vec![("r", Cow::Borrowed(row_num))];
 
         if let Some(span_range) = span {
-            attributes.push(("spans", span_range.to_string()));
+            attributes.push(("spans", Cow::Borrowed(span_range)));
         }
 
         if let Some(row_options) = row_options {
@@ -9735,20 +11940,20 @@ impl Worksheet {
 
             if xf_index != 0 {
                 let xf_index = self.global_xf_indices[xf_index as usize];
-                attributes.push(("s", xf_index.to_string()));
-                attributes.push(("customFormat", "1".to_string()));
+                attributes.push(("s", Cow::Owned(xf_index.to_string())));
+                attributes.push(("customFormat", Cow::Borrowed("1")));
             }
 
             if row_options.height != DEFAULT_ROW_HEIGHT {
-                attributes.push(("ht", row_options.height.to_string()));
+                attributes.push(("ht", Cow::Owned(row_options.height.to_string())));
             }
 
             if row_options.hidden {
-                attributes.push(("hidden", "1".to_string()));
+                attributes.push(("hidden", Cow::Borrowed("1")));
             }
 
             if row_options.height != DEFAULT_ROW_HEIGHT {
-                attributes.push(("customHeight", "1".to_string()));
+                attributes.push(("customHeight", Cow::Borrowed("1")));
             }
         }
 
@@ -9762,50 +11967,111 @@ impl Worksheet {
     // Write the <c> element for a number.
     fn write_number_cell(&mut self, row: RowNum, col: ColNum, number: f64, xf_index: u32) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
+        let mut row_buffer = itoa::Buffer::new();
+        let row_name = row_buffer.format(row + 1);
+        let mut number_buffer = itoa::Buffer::new();
+        let number = Self::number_to_str(number, &mut number_buffer);
 
         if xf_index > 0 {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" s="{}"><v>{}</v></c>"#,
-                col_name,
-                row + 1,
-                xf_index,
-                number
+                r#"<c r="{col_name}{row_name}" s="{xf_index}"><v>{number}</v></c>"#,
             )
             .expect(XML_WRITE_ERROR);
         } else {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}"><v>{}</v></c>"#,
-                col_name,
-                row + 1,
-                number
+                r#"<c r="{col_name}{row_name}"><v>{number}</v></c>"#,
             )
             .expect(XML_WRITE_ERROR);
         }
     }
 
+    // Faster version of the f64 Display formatting to use in the inner loop.
+    // Integers, which are the most common numbers in worksheets, are written
+    // as i64 values. Other numbers, and -0.0, use the Display formatting.
+    fn number_to_str(number: f64, buffer: &mut itoa::Buffer) -> Cow<'_, str> {
+        // The largest integer that can be represented exactly in an f64.
+        const MAX_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+        if number.fract() == 0.0
+            && number.abs() <= MAX_INTEGER
+            && !(number == 0.0 && number.is_sign_negative())
+        {
+            Cow::Borrowed(buffer.format(number as i64))
+        } else {
+            Cow::Owned(number.to_string())
+        }
+    }
+
     // Write the <c> element for a string.
     fn write_string_cell(&mut self, row: RowNum, col: ColNum, string_index: u32, xf_index: u32) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
+        let mut row_buffer = itoa::Buffer::new();
+        let row_name = row_buffer.format(row + 1);
+        let mut index_buffer = itoa::Buffer::new();
+        let string_index = index_buffer.format(string_index);
 
         if xf_index > 0 {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" s="{}" t="s"><v>{}</v></c>"#,
-                col_name,
-                row + 1,
-                xf_index,
-                string_index
+                r#"<c r="{col_name}{row_name}" s="{xf_index}" t="s"><v>{string_index}</v></c>"#,
             )
             .expect(XML_WRITE_ERROR);
         } else {
             write!(
                 &mut self.writer.xmlfile,
-                r#"<c r="{}{}" t="s"><v>{}</v></c>"#,
-                col_name,
-                row + 1,
-                string_index
+                r#"<c r="{col_name}{row_name}" t="s"><v>{string_index}</v></c>"#,
+            )
+            .expect(XML_WRITE_ERROR);
+        }
+    }
+
+    // Write the <c> element for an inline string. Rich strings are stored as
+    // the xml for their <r> runs and are written without further escaping.
+    fn write_inline_string_cell(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        string: &str,
+        xf_index: u32,
+        is_rich: bool,
+    ) {
+        let col_name = Self::col_to_name(&mut self.col_names, col);
+
+        write!(
+            &mut self.writer.xmlfile,
+            r#"<c r="{}{}""#,
+            col_name,
+            row + 1
+        )
+        .expect(XML_WRITE_ERROR);
+
+        if xf_index > 0 {
+            write!(&mut self.writer.xmlfile, r#" s="{xf_index}""#).expect(XML_WRITE_ERROR);
+        }
+
+        // Rich strings are stored as pre-generated, escaped, xml runs.
+        if is_rich {
+            write!(
+                &mut self.writer.xmlfile,
+                r#" t="inlineStr"><is>{string}</is></c>"#
+            )
+            .expect(XML_WRITE_ERROR);
+        } else {
+            let preserve_whitespace =
+                string.starts_with(['\t', '\n', ' ']) || string.ends_with(['\t', '\n', ' ']);
+            let space = if preserve_whitespace {
+                r#" xml:space="preserve""#
+            } else {
+                ""
+            };
+
+            write!(
+                &mut self.writer.xmlfile,
+                r#" t="inlineStr"><is><t{}>{}</t></is></c>"#,
+                space,
+                escape_si_data(&escape_xml_escapes(string))
             )
             .expect(XML_WRITE_ERROR);
         }
@@ -9822,11 +12088,17 @@ impl Worksheet {
     ) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
 
-        let style = if xf_index > 0 {
-            format!(r#" s="{xf_index}""#)
-        } else {
-            String::new()
-        };
+        write!(
+            &mut self.writer.xmlfile,
+            r#"<c r="{}{}""#,
+            col_name,
+            row + 1
+        )
+        .expect(XML_WRITE_ERROR);
+
+        if xf_index > 0 {
+            write!(&mut self.writer.xmlfile, r#" s="{xf_index}""#).expect(XML_WRITE_ERROR);
+        }
 
         let result_type = if result.parse::<f64>().is_err() {
             r#" t="str""#
@@ -9836,10 +12108,7 @@ impl Worksheet {
 
         write!(
             &mut self.writer.xmlfile,
-            r#"<c r="{}{}"{}{}><f>{}</f><v>{}</v></c>"#,
-            col_name,
-            row + 1,
-            style,
+            r#"{}><f>{}</f><v>{}</v></c>"#,
             result_type,
             crate::xmlwriter::escape_data(formula),
             crate::xmlwriter::escape_data(result),
@@ -9861,11 +12130,17 @@ impl Worksheet {
     ) {
         let col_name = Self::col_to_name(&mut self.col_names, col);
 
-        let style = if xf_index > 0 {
-            format!(r#" s="{xf_index}""#)
-        } else {
-            String::new()
-        };
+        write!(
+            &mut self.writer.xmlfile,
+            r#"<c r="{}{}""#,
+            col_name,
+            row + 1
+        )
+        .expect(XML_WRITE_ERROR);
+
+        if xf_index > 0 {
+            write!(&mut self.writer.xmlfile, r#" s="{xf_index}""#).expect(XML_WRITE_ERROR);
+        }
 
         let cm = if is_dynamic { r#" cm="1""# } else { "" };
 
@@ -9877,10 +12152,7 @@ impl Worksheet {
 
         write!(
             &mut self.writer.xmlfile,
-            r#"<c r="{}{}"{}{}{}><f t="array" ref="{}">{}</f><v>{}</v></c>"#,
-            col_name,
-            row + 1,
-            style,
+            r#"{}{}><f t="array" ref="{}">{}</f><v>{}</v></c>"#,
             cm,
             result_type,
             range,
@@ -10511,9 +12783,8 @@ impl IntoExcelData for bool {
     }
 }
 
-// Note, for the date/time type traits below we add a default number format for
-// the `write()` variants since Excel dates/times require a number format or
-// else they will appear as a number.
+// Note, for the date/time type traits below the `write()` variants add a
+// default number format, see `store_unformatted_datetime()`.
 impl IntoExcelData for &NaiveDateTime {
     fn write(
         self,
@@ -10522,8 +12793,7 @@ impl IntoExcelData for &NaiveDateTime {
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
         let number = Worksheet::datetime_to_excel(self);
-        let format = &Format::new().set_num_format("yyyy\\-mm\\-dd\\ hh:mm:ss");
-        worksheet.store_datetime(row, col, number, Some(format))
+        worksheet.store_unformatted_datetime(row, col, number, "yyyy\\-mm\\-dd\\ hh:mm:ss")
     }
 
     fn write_with_format<'a>(
@@ -10546,8 +12816,7 @@ impl IntoExcelData for &NaiveDate {
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
         let number = Worksheet::date_to_excel(*self);
-        let format = &Format::new().set_num_format("yyyy\\-mm\\-dd;@");
-        worksheet.store_datetime(row, col, number, Some(format))
+        worksheet.store_unformatted_datetime(row, col, number, "yyyy\\-mm\\-dd;@")
     }
 
     fn write_with_format<'a>(
@@ -10570,8 +12839,7 @@ impl IntoExcelData for &NaiveTime {
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
         let number = Worksheet::time_to_excel(*self);
-        let format = &Format::new().set_num_format("hh:mm:ss;@");
-        worksheet.store_datetime(row, col, number, Some(format))
+        worksheet.store_unformatted_datetime(row, col, number, "hh:mm:ss;@")
     }
 
     fn write_with_format<'a>(
@@ -10649,10 +12917,342 @@ impl IntoExcelData for Url {
     }
 }
 
+impl IntoExcelData for CellValue {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        match self {
+            CellValue::String(string) => string.write(worksheet, row, col),
+            CellValue::Number(number) => number.write(worksheet, row, col),
+            CellValue::Boolean(boolean) => boolean.write(worksheet, row, col),
+            CellValue::DateTime(datetime) => (&datetime).write(worksheet, row, col),
+            CellValue::Date(date) => (&date).write(worksheet, row, col),
+            CellValue::Time(time) => (&time).write(worksheet, row, col),
+            CellValue::Formula(formula) => formula.write(worksheet, row, col),
+            CellValue::Url(url) => url.write(worksheet, row, col),
+            // Excel ignores blank cells without a format.
+            CellValue::Blank => Ok(worksheet),
+        }
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &'a Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        match self {
+            CellValue::String(string) => string.write_with_format(worksheet, row, col, format),
+            CellValue::Number(number) => number.write_with_format(worksheet, row, col, format),
+            CellValue::Boolean(boolean) => boolean.write_with_format(worksheet, row, col, format),
+            CellValue::DateTime(datetime) => {
+                (&datetime).write_with_format(worksheet, row, col, format)
+            }
+            CellValue::Date(date) => (&date).write_with_format(worksheet, row, col, format),
+            CellValue::Time(time) => (&time).write_with_format(worksheet, row, col, format),
+            CellValue::Formula(formula) => formula.write_with_format(worksheet, row, col, format),
+            CellValue::Url(url) => url.write_with_format(worksheet, row, col, format),
+            CellValue::Blank => worksheet.store_blank(row, col, format),
+        }
+    }
+}
+
+impl IntoExcelData for &CellValue {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.clone().write(worksheet, row, col)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &'a Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        self.clone().write_with_format(worksheet, row, col, format)
+    }
+}
+
+// -----------------------------------------------------------------------
+// RowWriter
+// -----------------------------------------------------------------------
+
+/// The `RowWriter` struct is used to append cells to a worksheet in row order.
+///
+/// A `RowWriter` is created with
+/// [`worksheet.row_writer()`](Worksheet::row_writer). Cells are written from
+/// left to right in the current row with [`push_cell()`](RowWriter::push_cell)
+/// and [`end_row()`](RowWriter::end_row) moves the writer to the first column
+/// of the next row.
+///
+/// The current row is held by the writer and is added back to the worksheet
+/// when the row ends or when the `RowWriter` goes out of scope.
+///
+/// See [`worksheet.row_writer()`](Worksheet::row_writer) for an example.
+///
+pub struct RowWriter<'a> {
+    worksheet: &'a mut Worksheet,
+    row: RowNum,
+    col: ColNum,
+}
+
+impl<'a> RowWriter<'a> {
+    // Create a new RowWriter starting at the first column of `row`.
+    fn new(worksheet: &'a mut Worksheet, row: RowNum) -> RowWriter<'a> {
+        worksheet.buffer_row(row, 0);
+
+        RowWriter {
+            worksheet,
+            row,
+            col: 0,
+        }
+    }
+
+    /// Write generic data to the next cell in the current row.
+    ///
+    /// The `push_cell()` method writes data that implements [`IntoExcelData`]
+    /// to the next cell in the current row, in the same way as
+    /// [`worksheet.write()`](Worksheet::write).
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data that implements [`IntoExcelData`].
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    pub fn push_cell(&mut self, data: impl IntoExcelData) -> Result<&mut RowWriter<'a>, XlsxError> {
+        data.write(self.worksheet, self.row, self.col)?;
+        self.col = self.col.saturating_add(1);
+
+        Ok(self)
+    }
+
+    /// Write formatted generic data to the next cell in the current row.
+    ///
+    /// The `push_cell_with_format()` method writes data that implements
+    /// [`IntoExcelData`] to the next cell in the current row, in the same way
+    /// as [`worksheet.write_with_format()`](Worksheet::write_with_format).
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data that implements [`IntoExcelData`].
+    /// * `format` - The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    pub fn push_cell_with_format(
+        &mut self,
+        data: impl IntoExcelData,
+        format: &Format,
+    ) -> Result<&mut RowWriter<'a>, XlsxError> {
+        data.write_with_format(self.worksheet, self.row, self.col, format)?;
+        self.col = self.col.saturating_add(1);
+
+        Ok(self)
+    }
+
+    /// Skip the next cell in the current row, leaving it empty.
+    pub fn skip_cell(&mut self) -> &mut RowWriter<'a> {
+        self.col = self.col.saturating_add(1);
+        self
+    }
+
+    /// End the current row and move to the first column of the next row.
+    pub fn end_row(&mut self) -> &mut RowWriter<'a> {
+        self.row = self.row.saturating_add(1);
+        self.col = 0;
+        self.worksheet.flush_buffered_row(Some(self.row));
+        self
+    }
+
+    /// Get the zero indexed row number that the writer is currently writing.
+    pub fn row(&self) -> RowNum {
+        self.row
+    }
+
+    /// Get the zero indexed column number of the next cell to be written.
+    pub fn col(&self) -> ColNum {
+        self.col
+    }
+}
+
+impl Drop for RowWriter<'_> {
+    fn drop(&mut self) {
+        self.worksheet.flush_buffered_row(None);
+    }
+}
+
 // -----------------------------------------------------------------------
 // Helper enums/structs/functions.
 // -----------------------------------------------------------------------
 
+/// Options for handling strings that exceed Excel's length limit.
+///
+/// Used with
+/// [`worksheet.set_string_overflow()`](Worksheet::set_string_overflow) and
+/// [`workbook.set_string_overflow()`](crate::Workbook::set_string_overflow).
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringOverflow {
+    /// Return an [`XlsxError::MaxStringLengthExceeded`] error. This is the
+    /// default.
+    Error,
+
+    /// Truncate the string to 32,767 characters, with an ellipsis "…" as the
+    /// last character to indicate that it was truncated.
+    Truncate,
+
+    /// Split the string into chunks of 32,767 characters and write them to
+    /// the target cell and the cells to its right in the same row. This
+    /// overwrites any existing data in those cells.
+    Split,
+}
+
+/// Statistics about the content of a worksheet.
+///
+/// Returned by [`worksheet.stats()`](Worksheet::stats).
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WorksheetStats {
+    /// The number of string and rich string cells.
+    pub strings: usize,
+
+    /// The number of number cells, excluding dates and times.
+    pub numbers: usize,
+
+    /// The number of date and time cells.
+    pub datetimes: usize,
+
+    /// The number of boolean cells, including checkboxes.
+    pub booleans: usize,
+
+    /// The number of formula and array formula cells. Array formulas are
+    /// counted once, at their first cell, and the other cells in the range
+    /// are counted as numbers since they are stored with a zero value.
+    pub formulas: usize,
+
+    /// The number of blank formatted cells.
+    pub blanks: usize,
+
+    /// The number of images, excluding header and footer images.
+    pub images: usize,
+
+    /// The number of charts.
+    pub charts: usize,
+
+    /// The number of tables.
+    pub tables: usize,
+
+    /// The used range of the worksheet as `(first_row, first_col, last_row,
+    /// last_col)`, or `None` if no data has been written. This is the range
+    /// that Excel reports as the worksheet dimensions.
+    pub used_range: Option<(RowNum, ColNum, RowNum, ColNum)>,
+}
+
+/// A cell value of any of the types that can be written to a worksheet.
+///
+/// `CellValue` allows data of different types, such as the fields of a
+/// database record, to be held in the same collection and written with the
+/// generic [`write()`](Worksheet::write) method or with
+/// [`write_row()`](Worksheet::write_row) and
+/// [`write_column()`](Worksheet::write_column), without converting everything
+/// to strings.
+///
+/// # Examples
+///
+/// The following example demonstrates writing rows of mixed data types to a
+/// worksheet.
+///
+/// ```
+/// # // This code is available in examples/doc_cell_value.rs
+/// #
+/// # use chrono::NaiveDate;
+/// # use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #
+/// #     // Add a worksheet to the workbook.
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     let date = NaiveDate::from_ymd_opt(2023, 1, 25).unwrap();
+///
+///     let records = [
+///         vec![
+///             CellValue::String("Alice".to_string()),
+///             CellValue::Number(1234.5),
+///             CellValue::Boolean(true),
+///             CellValue::Date(date),
+///         ],
+///         vec![
+///             CellValue::String("Bob".to_string()),
+///             CellValue::Blank,
+///             CellValue::Boolean(false),
+///             CellValue::Date(date),
+///         ],
+///     ];
+///
+///     for (row, record) in records.iter().enumerate() {
+///         worksheet.write_row(row as u32, 0, record)?;
+///     }
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone)]
+pub enum CellValue {
+    /// A string value.
+    String(String),
+
+    /// A number value.
+    Number(f64),
+
+    /// A boolean value.
+    Boolean(bool),
+
+    /// A datetime value. See
+    /// [`write_datetime()`](Worksheet::write_datetime).
+    DateTime(NaiveDateTime),
+
+    /// A date value. See [`write_date()`](Worksheet::write_date).
+    Date(NaiveDate),
+
+    /// A time value. See [`write_time()`](Worksheet::write_time).
+    Time(NaiveTime),
+
+    /// A formula value.
+    Formula(Formula),
+
+    /// A url value.
+    Url(Url),
+
+    /// A blank cell. This is only written if a format is supplied, see
+    /// [`write_blank()`](Worksheet::write_blank).
+    Blank,
+}
+
 /// Pre-validated worksheet name
 // This should NOT derive Default, as an Empty String is an invalid sheet name!
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
@@ -10690,22 +13290,14 @@ impl Name {
         &self.0
     }
     /// Returns the name properly quoted if it's applicable.
-    /// 
-    /// This respects existing quotes and characters of interest inside the sheet name
     pub(crate) fn properly_quoted(&self) -> String {
-        let double_quoted = self.0.replace('\'', "''");
-        // self cannot be quoted (due to the checks in `new`), so this is enough to check
-        if double_quoted.contains([' ','!','\'','$',',',';','-','{','}']) {
-            format!("'{}'", self.0.replace('\'', "''"))
-        } else {
-            self.0.clone()
-        }
+        utility::quote_sheetname(&self.0)
     }
 }
 
-impl ToString for Name {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
@@ -10820,6 +13412,57 @@ enum CellType {
     },
 }
 
+// The cells in a worksheet row, stored in column order. Cells are generally
+// written in row-column order so new cells can usually be appended to the
+// end of the row. This uses less memory than a map per row and is faster to
+// iterate when writing the worksheet xml.
+#[derive(Clone, Default)]
+struct CellRow {
+    cells: Vec<(ColNum, CellType)>,
+}
+
+impl CellRow {
+    // Get the cell in a column, if it exists.
+    fn get(&self, col: ColNum) -> Option<&CellType> {
+        self.position(col).ok().map(|index| &self.cells[index].1)
+    }
+
+    // Get a mutable reference to the cell in a column, if it exists.
+    fn get_mut(&mut self, col: ColNum) -> Option<&mut CellType> {
+        self.position(col)
+            .ok()
+            .map(|index| &mut self.cells[index].1)
+    }
+
+    // Insert or replace the cell in a column.
+    fn insert(&mut self, col: ColNum, cell: CellType) {
+        match self.position(col) {
+            Ok(index) => self.cells[index].1 = cell,
+            Err(index) => self.cells.insert(index, (col, cell)),
+        }
+    }
+
+    // Iterate over the column numbers and cells in column order.
+    fn iter(&self) -> impl Iterator<Item = (ColNum, &CellType)> {
+        self.cells.iter().map(|(col, cell)| (*col, cell))
+    }
+
+    // Iterate over the column numbers in column order.
+    fn keys(&self) -> impl Iterator<Item = ColNum> + '_ {
+        self.cells.iter().map(|(col, _)| *col)
+    }
+
+    // Find the index of a column, or the index where it should be inserted.
+    // The common case of appending to the end of the row is checked first.
+    fn position(&self, col: ColNum) -> Result<usize, usize> {
+        match self.cells.last() {
+            None => Err(0),
+            Some((last_col, _)) if *last_col < col => Err(self.cells.len()),
+            _ => self.cells.binary_search_by_key(&col, |(col, _)| *col),
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 enum PageView {
     Normal,
@@ -10859,7 +13502,7 @@ struct Hyperlink {
 }
 
 impl Hyperlink {
-    fn new(url: Url) -> Result<Hyperlink, XlsxError> {
+    fn new(url: Url) -> Hyperlink {
         let mut hyperlink = Hyperlink {
             url: url.link,
             text: url.text,
@@ -10871,16 +13514,15 @@ impl Hyperlink {
 
         Self::initialize(&mut hyperlink);
 
-        // Check the hyperlink string lengths are within Excel's limits. The text
-        // length is checked by write_string_with_format().
-        if hyperlink.url.chars().count() > MAX_URL_LEN
-            || hyperlink.location.chars().count() > MAX_URL_LEN
-            || hyperlink.tip.chars().count() > MAX_PARAMETER_LEN
-        {
-            return Err(XlsxError::MaxUrlLengthExceeded);
-        }
+        hyperlink
+    }
 
-        Ok(hyperlink)
+    // Check the hyperlink string lengths are within Excel's limits. The text
+    // length is checked by write_string_with_format().
+    fn exceeds_max_length(&self) -> bool {
+        self.url.chars().count() > MAX_URL_LEN
+            || self.location.chars().count() > MAX_URL_LEN
+            || self.tip.chars().count() > MAX_PARAMETER_LEN
     }
 
     // This method handles a variety of different string processing that needs
@@ -10950,6 +13592,25 @@ impl Hyperlink {
         }
     }
 
+    // Get the relationship target and target mode for a hyperlink on a drawing
+    // object such as an image. Unlike worksheet hyperlinks, internal links
+    // also use a relationship.
+    fn drawing_relationship(&self) -> Option<(String, String)> {
+        match self.link_type {
+            HyperlinkType::Url | HyperlinkType::File => {
+                let target = if self.location.is_empty() {
+                    self.url.clone()
+                } else {
+                    format!("{}#{}", self.url, self.location)
+                };
+
+                Some((target, "External".to_string()))
+            }
+            HyperlinkType::Internal => Some((format!("#{}", self.location), String::new())),
+            HyperlinkType::Unknown => None,
+        }
+    }
+
     // Increment the ref id
     fn increment_ref_id(&mut self, ref_id: u16) -> u16 {
         match self.link_type {
@@ -7,10 +7,11 @@
 #[cfg(test)]
 mod worksheet_tests {
 
+    use crate::image::XlsxImageType;
     use crate::test_functions::xml_to_vec;
     use crate::worksheet::SharedStringsTable;
     use crate::worksheet::*;
-    use crate::XlsxError;
+    use crate::{ChartType, XlsxError};
     use pretty_assertions::assert_eq;
     use std::collections::HashMap;
 
@@ -179,6 +180,28 @@ mod worksheet_tests {
         assert!(matches!(result, Err(XlsxError::ParameterError(_))));
     }
 
+    #[test]
+    fn set_header_images() {
+        let mut worksheet = Worksheet::new();
+
+        let image = Image::new("tests/input/images/red.jpg").unwrap();
+        worksheet.set_header("&R&G");
+
+        // Test that no images are stored if one position doesn't match.
+        let result = worksheet.set_header_images([Some(&image), None, Some(&image)]);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        assert!(!worksheet.has_header_footer_images());
+
+        worksheet
+            .set_header_images([None, None, Some(&image)])
+            .unwrap();
+        assert!(worksheet.header_footer_images[2].is_some());
+
+        // Test that a None value clears the image.
+        worksheet.set_header_images([None, None, None]).unwrap();
+        assert!(!worksheet.has_header_footer_images());
+    }
+
     #[test]
     fn rich_string() {
         let mut worksheet = Worksheet::new();
@@ -594,6 +617,57 @@ mod worksheet_tests {
         assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
     }
 
+    #[test]
+    fn merge_range_only() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::default();
+
+        worksheet.merge_range_only(1, 1, 2, 2, "Foo").unwrap();
+        worksheet.merge_range_only(4, 1, 4, 3, 123).unwrap();
+        assert_eq!(worksheet.merged_ranges.len(), 2);
+
+        // Test for overlap with both types of merge.
+        let result = worksheet.merge_range_only(2, 2, 3, 3, "Foo");
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+
+        let result = worksheet.merge_range(0, 0, 1, 1, "Foo", &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+
+        // A failed merge shouldn't be stored or leave any merged cells.
+        assert_eq!(worksheet.merged_ranges.len(), 2);
+        assert!(!worksheet.merged_cells.contains_key(&(3, 3)));
+        assert!(!worksheet.merged_cells.contains_key(&(0, 0)));
+
+        // Test single merge cell.
+        let result = worksheet.merge_range_only(10, 1, 10, 1, "Foo");
+        assert!(matches!(result, Err(XlsxError::MergeRangeSingleCell)));
+    }
+
+    #[test]
+    fn unmerge_range() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.merge_range_only(1, 1, 2, 2, "Foo").unwrap();
+        worksheet.merge_range_only(4, 1, 4, 3, "Bar").unwrap();
+
+        // The range must match a merged range exactly.
+        let result = worksheet.unmerge_range(1, 1, 2, 3);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet.unmerge_range(1, 1, 2, 2).unwrap();
+        assert_eq!(worksheet.merged_ranges.len(), 1);
+        assert!(!worksheet.merged_cells.contains_key(&(1, 1)));
+        assert_eq!(worksheet.merged_cells.get(&(4, 2)), Some(&0));
+
+        // The unmerged cells can be merged again.
+        worksheet.merge_range_only(1, 1, 1, 2, "Foo").unwrap();
+        assert_eq!(worksheet.merged_ranges.len(), 2);
+
+        worksheet.unmerge_range(4, 1, 4, 3).unwrap();
+        let result = worksheet.unmerge_range(4, 1, 4, 3);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
     #[test]
     fn check_dimensions() {
         let mut worksheet = Worksheet::new();
@@ -640,7 +714,550 @@ mod worksheet_tests {
         let long_string = std::str::from_utf8(&chars);
 
         let result = worksheet.write_string(0, 0, long_string.unwrap());
-        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
+        assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded(_))));
+
+        worksheet.set_name("Sales Data").unwrap();
+        let result = worksheet.write_string(12344, 2, long_string.unwrap());
+        match result {
+            Err(XlsxError::MaxStringLengthExceeded(location)) => {
+                assert_eq!("'Sales Data'!C12345", location);
+            }
+            _ => panic!("expected MaxStringLengthExceeded"),
+        }
+    }
+
+    #[test]
+    fn string_overflow() {
+        let mut worksheet = Worksheet::new();
+        let long_string = "a".repeat(MAX_STRING_LEN * 2 + 10);
+
+        let strings = |worksheet: &Worksheet, row: RowNum| -> Vec<Arc<str>> {
+            match worksheet.data_table.get(&row) {
+                Some(cell_row) => cell_row
+                    .iter()
+                    .map(|(_, cell)| match cell {
+                        CellType::String { string, .. } => Arc::clone(string),
+                        _ => unreachable!(),
+                    })
+                    .collect(),
+                None => vec![],
+            }
+        };
+
+        worksheet.set_string_overflow(StringOverflow::Truncate);
+        worksheet.write_string(0, 0, &long_string).unwrap();
+
+        let truncated = strings(&worksheet, 0);
+        assert_eq!(1, truncated.len());
+        assert_eq!(MAX_STRING_LEN, truncated[0].chars().count());
+        assert!(truncated[0].ends_with("a…"));
+
+        worksheet.set_string_overflow(StringOverflow::Split);
+        worksheet.write_string(1, 0, &long_string).unwrap();
+
+        let lengths: Vec<usize> = strings(&worksheet, 1)
+            .iter()
+            .map(|chunk| chunk.len())
+            .collect();
+        assert_eq!(vec![MAX_STRING_LEN, MAX_STRING_LEN, 10], lengths);
+
+        let result = worksheet.write_string(2, COL_MAX - 2, &long_string);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(strings(&worksheet, 2).is_empty());
+    }
+
+    #[test]
+    fn set_autofit_string_width() {
+        let width = |worksheet: &Worksheet, col: ColNum| worksheet.changed_cols[&col].width;
+
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "Hello").unwrap();
+        worksheet.write(0, 1, 12345).unwrap();
+        worksheet.write(0, 2, true).unwrap();
+        worksheet.autofit();
+
+        let default_widths = [
+            width(&worksheet, 0),
+            width(&worksheet, 1),
+            width(&worksheet, 2),
+        ];
+
+        // A function that returns the default widths gives the same result.
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "Hello").unwrap();
+        worksheet.set_autofit_string_width(utility::pixel_width);
+        worksheet.autofit();
+
+        assert_eq!(default_widths[0], width(&worksheet, 0));
+
+        // A function that doubles the widths applies to all the cell types.
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "Hello").unwrap();
+        worksheet.write(0, 1, 12345).unwrap();
+        worksheet.write(0, 2, true).unwrap();
+        worksheet.set_autofit_string_width(|string| 2 * utility::pixel_width(string));
+        worksheet.autofit();
+
+        for col in 0..3 {
+            assert!(width(&worksheet, col) > default_widths[col as usize]);
+        }
+    }
+
+    #[test]
+    fn write_cell_values() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new().set_bold();
+        let date = NaiveDate::from_ymd_opt(2023, 1, 25).unwrap();
+
+        let values = vec![
+            CellValue::String("Hello".to_string()),
+            CellValue::Number(123.0),
+            CellValue::Boolean(true),
+            CellValue::Date(date),
+            CellValue::Formula(Formula::new("=1+1")),
+            CellValue::Url(Url::new("https://www.rust-lang.org")),
+            CellValue::Blank,
+        ];
+
+        worksheet.write_row(0, 0, &values).unwrap();
+        worksheet
+            .write_with_format(1, 0, CellValue::Blank, &format)
+            .unwrap();
+
+        let row = worksheet.data_table.get(&0).unwrap();
+        assert!(matches!(row.get(0), Some(CellType::String { .. })));
+        assert!(matches!(row.get(1), Some(CellType::Number { .. })));
+        assert!(matches!(row.get(2), Some(CellType::Boolean { .. })));
+        assert!(matches!(row.get(3), Some(CellType::DateTime { .. })));
+        assert!(matches!(row.get(4), Some(CellType::Formula { .. })));
+        assert!(matches!(row.get(5), Some(CellType::String { .. })));
+        assert!(row.get(6).is_none());
+        assert_eq!(1, worksheet.hyperlinks.len());
+
+        let row = worksheet.data_table.get(&1).unwrap();
+        assert!(matches!(row.get(0), Some(CellType::Blank { .. })));
+    }
+
+    #[test]
+    fn use_default_datetime_formats() {
+        let mut worksheet = Worksheet::new();
+        let datetime = NaiveDate::from_ymd_opt(2023, 1, 25)
+            .unwrap()
+            .and_hms_opt(12, 30, 0)
+            .unwrap();
+
+        let xf_index = |worksheet: &Worksheet, col: ColNum| -> u32 {
+            match worksheet.data_table.get(&0).unwrap().get(col) {
+                Some(CellType::DateTime { xf_index, .. }) => *xf_index,
+                _ => unreachable!(),
+            }
+        };
+
+        worksheet.write(0, 0, &datetime).unwrap();
+        worksheet.write(0, 1, &datetime.date()).unwrap();
+        worksheet.write(0, 2, &datetime.time()).unwrap();
+
+        assert_ne!(0, xf_index(&worksheet, 0));
+        assert_ne!(0, xf_index(&worksheet, 1));
+        assert_ne!(0, xf_index(&worksheet, 2));
+
+        worksheet.use_default_datetime_formats(false);
+        worksheet.write(0, 3, &datetime).unwrap();
+        worksheet.write(0, 4, &datetime.date()).unwrap();
+        worksheet.write(0, 5, &datetime.time()).unwrap();
+
+        assert_eq!(0, xf_index(&worksheet, 3));
+        assert_eq!(0, xf_index(&worksheet, 4));
+        assert_eq!(0, xf_index(&worksheet, 5));
+    }
+
+    #[test]
+    fn set_column_range_width() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+
+        worksheet.set_column_range_width(2, 9, 16).unwrap();
+        worksheet
+            .set_column_range_width_pixels(11, 12, 117)
+            .unwrap();
+
+        worksheet.assemble_xml_file(&mut string_table);
+        let got = worksheet.writer.read_to_str();
+
+        assert!(got.contains(r#"<cols><col min="3" max="10" width="16.7109375" customWidth="1"/><col min="12" max="13" width="16.7109375" customWidth="1"/></cols>"#));
+
+        let result = worksheet.set_column_range_width(0, COL_MAX, 20);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.set_column_range_width(5, 4, 20);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result = worksheet.set_column_range_width_pixels(5, 4, 20);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+    }
+
+    #[test]
+    fn metric_units() {
+        let mut worksheet1 = Worksheet::new();
+        let mut worksheet2 = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+
+        // 2.54cm is 1 inch which is 96 pixels or 72 points.
+        worksheet1.set_column_width_cm(0, 2.54).unwrap();
+        worksheet1.set_column_width_mm(1, 25.4).unwrap();
+        worksheet1.set_column_width_inches(2, 0.5).unwrap();
+        worksheet1.set_row_height_cm(0, 2.54).unwrap();
+        worksheet1.set_row_height_mm(1, 12.7).unwrap();
+        worksheet1.set_row_height_inches(2, 0.25).unwrap();
+        worksheet1.set_margins_cm(2.54, 1.27, -1.0, -1.0, 0.0, 0.0);
+        worksheet1.write(2, 2, 1).unwrap();
+
+        worksheet2.set_column_width_pixels(0, 96).unwrap();
+        worksheet2.set_column_width_pixels(1, 96).unwrap();
+        worksheet2.set_column_width_pixels(2, 48).unwrap();
+        worksheet2.set_row_height(0, 72).unwrap();
+        worksheet2.set_row_height(1, 36).unwrap();
+        worksheet2.set_row_height(2, 18).unwrap();
+        worksheet2.set_margins(1.0, 0.5, -1.0, -1.0, 0.0, 0.0);
+        worksheet2.write(2, 2, 1).unwrap();
+
+        worksheet1.assemble_xml_file(&mut string_table);
+        worksheet2.assemble_xml_file(&mut string_table);
+
+        let got = worksheet1.writer.read_to_str();
+        assert_eq!(got, worksheet2.writer.read_to_str());
+        assert!(got.contains(r#"<col min="1" max="2" width="13.7109375" customWidth="1"/>"#));
+        assert!(got.contains(r#"<pageMargins left="1" right="0.5""#));
+
+        let result = worksheet1.set_column_width_cm(COL_MAX, 1);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn print_quality() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+
+        worksheet.set_print_quality(600).set_print_quality(0);
+
+        worksheet.assemble_xml_file(&mut string_table);
+        let got = worksheet.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<pageSetup orientation="portrait" horizontalDpi="600" verticalDpi="600"/>"#
+        ));
+    }
+
+    #[test]
+    fn screen_gridlines_and_headers() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+
+        worksheet
+            .set_screen_gridlines(false)
+            .set_row_col_headers(false)
+            .set_right_to_left(true);
+
+        worksheet.assemble_xml_file(&mut string_table);
+        let got = worksheet.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<sheetView showGridLines="0" showRowColHeaders="0" rightToLeft="1" workbookViewId="0"/>"#
+        ));
+    }
+
+    #[test]
+    fn workbook_right_to_left() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+
+        // The workbook level direction is set on the worksheet for each save.
+        worksheet.workbook_right_to_left = true;
+
+        worksheet.assemble_xml_file(&mut string_table);
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView rightToLeft="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // The workbook direction is cleared for the next save.
+        worksheet.reset();
+
+        worksheet.assemble_xml_file(&mut string_table);
+        let got = worksheet.writer.read_to_str();
+
+        assert!(got.contains(r#"<sheetView workbookViewId="0"/>"#));
+        assert!(!worksheet.right_to_left);
+    }
+
+    #[test]
+    fn first_page_number() {
+        let mut worksheet = Worksheet::new();
+        let mut string_table = SharedStringsTable::new();
+
+        worksheet.set_print_first_page_number(5);
+
+        worksheet.assemble_xml_file(&mut string_table);
+        let got = worksheet.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<pageSetup firstPageNumber="5" orientation="portrait" useFirstPageNumber="1" horizontalDpi="200" verticalDpi="200"/>"#
+        ));
+
+        // Excel's default first page number isn't written.
+        let mut worksheet = Worksheet::new();
+        worksheet.set_print_first_page_number(1);
+
+        worksheet.assemble_xml_file(&mut string_table);
+        let got = worksheet.writer.read_to_str();
+
+        assert!(got.contains(
+            r#"<pageSetup orientation="portrait" useFirstPageNumber="1" horizontalDpi="200" verticalDpi="200"/>"#
+        ));
+    }
+
+    #[test]
+    fn stats() {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new().set_bold();
+        let date = NaiveDate::from_ymd_opt(2023, 1, 25).unwrap();
+
+        assert_eq!(worksheet.stats(), WorksheetStats::default());
+
+        worksheet.write(2, 1, "Hello").unwrap();
+        worksheet.write(2, 2, 123).unwrap();
+        worksheet.write(3, 1, &date).unwrap();
+        worksheet.write(3, 2, true).unwrap();
+        worksheet.write(4, 1, Formula::new("=1+1")).unwrap();
+        worksheet.write_blank(5, 3, &format).unwrap();
+        worksheet
+            .write_array_formula(6, 1, 7, 1, "{=SUM(1)}")
+            .unwrap();
+
+        let expected = WorksheetStats {
+            strings: 1,
+            numbers: 2,
+            datetimes: 1,
+            booleans: 1,
+            formulas: 2,
+            blanks: 1,
+            used_range: Some((2, 1, 7, 3)),
+            ..WorksheetStats::default()
+        };
+
+        assert_eq!(worksheet.stats(), expected);
+    }
+
+    #[test]
+    fn freeze_presets() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.freeze_top_row();
+        assert_eq!(worksheet.panes.freeze_cell, (1, 0));
+
+        worksheet.freeze_first_column();
+        assert_eq!(worksheet.panes.freeze_cell, (0, 1));
+
+        worksheet.freeze_headers(2, 3).unwrap();
+        assert_eq!(worksheet.panes.freeze_cell, (2, 3));
+
+        let result = worksheet.freeze_headers(ROW_MAX, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn write_range_with() {
+        let mut worksheet = Worksheet::new();
+        let mut calls = vec![];
+
+        worksheet
+            .write_range_with(2, 1, 3, 3, |row, col| {
+                calls.push((row, col));
+                row * 10 + u32::from(col)
+            })
+            .unwrap();
+
+        assert_eq!(vec![(2, 1), (2, 2), (2, 3), (3, 1), (3, 2), (3, 3)], calls);
+
+        for row in 2..=3 {
+            for col in 1..=3_u16 {
+                match worksheet.data_table.get(&row).unwrap().get(col) {
+                    Some(CellType::Number { number, .. }) => {
+                        assert_eq!(f64::from(row * 10 + u32::from(col)), *number);
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        let result = worksheet.write_range_with(3, 0, 2, 0, |_, _| 1);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result = worksheet.write_range_with(0, 0, ROW_MAX, 0, |_, _| 1);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(!worksheet.data_table.contains_key(&0));
+    }
+
+    #[test]
+    fn set_page_setup() {
+        let page_setup = PageSetup::new()
+            .set_landscape()
+            .set_paper_size(9)
+            .set_margins(0.5, 0.5, -1.0, -1.0, 0.2, 0.2)
+            .set_print_fit_to_pages(1, 0)
+            .set_print_area(0, 0, 9, 4)
+            .set_header("&CQuarterly Sales")
+            .set_footer("&CPage &P of &N");
+
+        let mut worksheet1 = Worksheet::new();
+        worksheet1.set_page_setup(&page_setup).unwrap();
+
+        let mut worksheet2 = Worksheet::new();
+        worksheet2
+            .set_landscape()
+            .set_paper_size(9)
+            .set_margins(0.5, 0.5, -1.0, -1.0, 0.2, 0.2)
+            .set_print_fit_to_pages(1, 0)
+            .set_header("&CQuarterly Sales")
+            .set_footer("&CPage &P of &N")
+            .set_print_area(0, 0, 9, 4)
+            .unwrap();
+
+        let mut string_table = SharedStringsTable::new();
+        worksheet1.assemble_xml_file(&mut string_table);
+        worksheet2.assemble_xml_file(&mut string_table);
+
+        assert_eq!(
+            worksheet2.writer.read_to_str(),
+            worksheet1.writer.read_to_str()
+        );
+        assert!(worksheet1.print_area_defined_name.in_use);
+
+        // An invalid print area is an error and no options are applied.
+        let page_setup = page_setup.set_print_area(9, 0, 0, 4);
+        let mut worksheet = Worksheet::new();
+        let result = worksheet.set_page_setup(&page_setup);
+
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+        assert!(!worksheet.page_setup_changed);
+    }
+
+    #[test]
+    fn set_options() {
+        let options = WorksheetOptions::new()
+            .set_name("Sales")
+            .set_tab_color(Color::Green)
+            .set_zoom(80)
+            .set_freeze_panes(1, 0)
+            .set_right_to_left(true)
+            .set_screen_gridlines(false);
+
+        let mut worksheet1 = Worksheet::new();
+        worksheet1.set_options(&options).unwrap();
+
+        let mut worksheet2 = Worksheet::new();
+        worksheet2
+            .set_name("Sales")
+            .unwrap()
+            .set_tab_color(Color::Green)
+            .set_zoom(80)
+            .set_right_to_left(true)
+            .set_screen_gridlines(false)
+            .set_freeze_panes(1, 0)
+            .unwrap();
+
+        let mut string_table = SharedStringsTable::new();
+        worksheet1.assemble_xml_file(&mut string_table);
+        worksheet2.assemble_xml_file(&mut string_table);
+
+        assert_eq!(
+            worksheet2.writer.read_to_str(),
+            worksheet1.writer.read_to_str()
+        );
+        assert_eq!("Sales", worksheet1.name());
+
+        // An invalid option is an error and no options are applied.
+        let options = options.set_freeze_panes(ROW_MAX, 0);
+        let mut worksheet = Worksheet::new();
+        let result = worksheet.set_options(&options);
+
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert_eq!("", worksheet.name());
+        assert_eq!(100, worksheet.zoom);
+    }
+
+    #[test]
+    fn hyperlink_limit() {
+        let mut worksheet = Worksheet::new();
+        for row in 0..MAX_HYPERLINKS as RowNum {
+            worksheet
+                .write_url(row, 0, "https://www.rust-lang.org")
+                .unwrap();
+        }
+
+        // Overwriting an existing hyperlink is allowed.
+        worksheet.write_url(0, 0, "https://crates.io").unwrap();
+
+        let result = worksheet.write_url(0, 1, "https://crates.io");
+        assert!(matches!(result, Err(XlsxError::MaxHyperlinksExceeded(_))));
+        assert_eq!(0, worksheet.downgraded_hyperlinks());
+
+        worksheet.set_hyperlink_limit_fallback(true);
+        worksheet.write_url(0, 1, "https://crates.io").unwrap();
+        worksheet.write_url(1, 1, "https://crates.io").unwrap();
+
+        assert_eq!(MAX_HYPERLINKS, worksheet.hyperlinks.len());
+        assert_eq!(2, worksheet.downgraded_hyperlinks());
+        assert!(matches!(
+            worksheet.data_table[&0].get(1),
+            Some(CellType::String { xf_index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn sanitize_name() {
+        let names = [
+            ("Sheet1", "Sheet1"),
+            ("Q1/Q2 [draft]", "Q1Q2 draft"),
+            ("'quoted'", "quoted"),
+            ("", "Sheet"),
+            ("[]", "Sheet"),
+            ("history", "history_"),
+            (
+                "This name is longer than 31 characters",
+                "This name is longer than 31 cha",
+            ),
+            (
+                "This name is 30 characters lon'g",
+                "This name is 30 characters lon",
+            ),
+        ];
+
+        for (name, expected) in names {
+            let sanitized = Worksheet::sanitize_name(name);
+            assert_eq!(expected, sanitized);
+            assert!(Worksheet::validate_name(&sanitized).is_ok());
+        }
+
+        assert!(matches!(
+            Worksheet::validate_name("Q1/Q2"),
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
     }
 
     #[test]
@@ -1082,4 +1699,488 @@ mod worksheet_tests {
             assert!(diff < 0.00000000001);
         }
     }
+
+    #[test]
+    fn inline_strings() {
+        let bold = Format::new().set_bold();
+        let default = Format::default();
+        let mut string_table = SharedStringsTable::new();
+        let mut worksheet = Worksheet::new();
+
+        worksheet.set_inline_strings(true);
+        worksheet.write(0, 0, "Foo").unwrap();
+        worksheet.write(1, 0, " Bar ").unwrap();
+        worksheet.write_with_format(2, 0, "a < b", &bold).unwrap();
+        worksheet
+            .write_rich_string(3, 0, &[(&default, "a "), (&bold, "b")])
+            .unwrap();
+
+        // A plain string that looks like a rich string is still escaped.
+        worksheet.write(4, 0, "<r><t>x</t></r>").unwrap();
+        worksheet.set_global_xf_indices(&[0, 1]);
+        worksheet.assemble_xml_file(&mut string_table);
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A5"/>
+              <sheetViews>
+                <sheetView workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1" t="inlineStr"><is><t>Foo</t></is></c>
+                </row>
+                <row r="2" spans="1:1">
+                  <c r="A2" t="inlineStr"><is><t xml:space="preserve"> Bar </t></is></c>
+                </row>
+                <row r="3" spans="1:1">
+                  <c r="A3" s="1" t="inlineStr"><is><t>a &lt; b</t></is></c>
+                </row>
+                <row r="4" spans="1:1">
+                  <c r="A4" t="inlineStr"><is><r><t xml:space="preserve">a </t></r><r><rPr><b/><sz val="11"/><color theme="1"/><rFont val="Calibri"/><family val="2"/><scheme val="minor"/></rPr><t>b</t></r></is></c>
+                </row>
+                <row r="5" spans="1:1">
+                  <c r="A5" t="inlineStr"><is><t>&lt;r&gt;&lt;t&gt;x&lt;/t&gt;&lt;/r&gt;</t></is></c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+        assert_eq!(0, string_table.count);
+    }
+
+    #[test]
+    fn insert_checkbox() {
+        let mut string_table = SharedStringsTable::new();
+        let mut worksheet = Worksheet::new();
+
+        worksheet.insert_checkbox(0, 0, true).unwrap();
+        worksheet.insert_checkbox(1, 0, false).unwrap();
+        worksheet.write(2, 0, true).unwrap();
+
+        // The checkbox format is stored like any other cell format.
+        assert!(worksheet.xf_formats[1].checkbox);
+
+        worksheet.set_global_xf_indices(&[0, 1]);
+        worksheet.assemble_xml_file(&mut string_table);
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A3"/>
+              <sheetViews>
+                <sheetView workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1" s="1" t="b"><v>1</v></c>
+                </row>
+                <row r="2" spans="1:1">
+                  <c r="A2" s="1" t="b"><v>0</v></c>
+                </row>
+                <row r="3" spans="1:1">
+                  <c r="A3" t="b"><v>1</v></c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn insert_tiff_image() {
+        let mut worksheet = Worksheet::new();
+        let image = Image::new("tests/input/images/red.tiff").unwrap();
+
+        worksheet.insert_image(1, 1, &image).unwrap();
+        worksheet.prepare_worksheet_images(&mut HashMap::new(), 1);
+
+        assert_eq!(
+            vec![(
+                "image".to_string(),
+                "../media/image1.tiff".to_string(),
+                String::new()
+            )],
+            worksheet.drawing_relationships
+        );
+        assert!(worksheet.image_types[XlsxImageType::Tiff as usize]);
+
+        worksheet.drawing.assemble_xml_file();
+
+        let got = worksheet.drawing.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+              <xdr:twoCellAnchor editAs="oneCell">
+                <xdr:from>
+                  <xdr:col>1</xdr:col>
+                  <xdr:colOff>0</xdr:colOff>
+                  <xdr:row>1</xdr:row>
+                  <xdr:rowOff>0</xdr:rowOff>
+                </xdr:from>
+                <xdr:to>
+                  <xdr:col>1</xdr:col>
+                  <xdr:colOff>304800</xdr:colOff>
+                  <xdr:row>2</xdr:row>
+                  <xdr:rowOff>114300</xdr:rowOff>
+                </xdr:to>
+                <xdr:pic>
+                  <xdr:nvPicPr>
+                    <xdr:cNvPr id="2" name="Picture 1"/>
+                    <xdr:cNvPicPr>
+                      <a:picLocks noChangeAspect="1"/>
+                    </xdr:cNvPicPr>
+                  </xdr:nvPicPr>
+                  <xdr:blipFill>
+                    <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                    <a:stretch>
+                      <a:fillRect/>
+                    </a:stretch>
+                  </xdr:blipFill>
+                  <xdr:spPr>
+                    <a:xfrm>
+                      <a:off x="609600" y="190500"/>
+                      <a:ext cx="304800" cy="304800"/>
+                    </a:xfrm>
+                    <a:prstGeom prst="rect">
+                      <a:avLst/>
+                    </a:prstGeom>
+                  </xdr:spPr>
+                </xdr:pic>
+                <xdr:clientData/>
+              </xdr:twoCellAnchor>
+            </xdr:wsDr>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn insert_image_with_url() {
+        let mut worksheet = Worksheet::new();
+        let mut image = Image::new("tests/input/images/red.png").unwrap();
+        image.set_url(Url::new("https://www.rust-lang.org").set_tip("Rust"));
+
+        let mut chart = Chart::new(ChartType::Bar);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+
+        worksheet.insert_image(1, 1, &image).unwrap();
+        worksheet.insert_chart(10, 1, &chart).unwrap();
+        worksheet.prepare_worksheet_images(&mut HashMap::new(), 1);
+        worksheet.prepare_worksheet_charts(1, 1);
+
+        // The hyperlink has its own rel id, before the image and the chart.
+        assert_eq!(
+            vec![
+                (
+                    "hyperlink".to_string(),
+                    "https://www.rust-lang.org".to_string(),
+                    "External".to_string()
+                ),
+                (
+                    "image".to_string(),
+                    "../media/image1.png".to_string(),
+                    String::new()
+                ),
+                (
+                    "chart".to_string(),
+                    "../charts/chart1.xml".to_string(),
+                    String::new()
+                ),
+            ],
+            worksheet.drawing_relationships
+        );
+
+        let image_drawing = &worksheet.drawing.drawings[0];
+        assert_eq!(1, image_drawing.url_rel_id);
+        assert_eq!("Rust", image_drawing.url_tip);
+        assert_eq!(2, image_drawing.rel_id);
+        assert_eq!(3, worksheet.drawing.drawings[1].rel_id);
+
+        // Urls that can't be used as a hyperlink are an error.
+        let mut image = Image::new("tests/input/images/red.png").unwrap();
+        image.set_url("gopher://www.example.com");
+
+        let result = worksheet.insert_image(1, 1, &image);
+        assert!(matches!(result, Err(XlsxError::UnknownUrlType(_))));
+    }
+
+    #[test]
+    fn insert_image_absolute() {
+        let mut worksheet = Worksheet::new();
+        let image = Image::new("tests/input/images/red.png").unwrap();
+
+        worksheet.insert_image(1, 1, &image).unwrap();
+        worksheet
+            .insert_image_absolute(&image, 952500, 476250)
+            .unwrap();
+        assert_eq!(2, worksheet.stats().images);
+
+        worksheet.prepare_worksheet_images(&mut HashMap::new(), 1);
+
+        // The image data is shared between both anchors.
+        assert_eq!(
+            vec![(
+                "image".to_string(),
+                "../media/image1.png".to_string(),
+                String::new()
+            )],
+            worksheet.drawing_relationships
+        );
+
+        let drawings = &worksheet.drawing.drawings;
+        assert_eq!(2, drawings.len());
+        assert!(!drawings[0].is_absolute);
+        assert!(drawings[1].is_absolute);
+        assert_eq!(
+            (952500, 476250),
+            (drawings[1].col_absolute, drawings[1].row_absolute)
+        );
+        assert_eq!(1, drawings[1].rel_id);
+    }
+
+    #[test]
+    fn insert_cropped_image() {
+        let mut worksheet = Worksheet::new();
+        let mut image = Image::new("tests/input/images/red.png").unwrap();
+        image.set_crop(0.0, 12.5, 50.0, 0.0);
+
+        worksheet.insert_image(1, 1, &image).unwrap();
+        worksheet.prepare_worksheet_images(&mut HashMap::new(), 1);
+
+        // The drawing uses the crop values and the cropped size.
+        let drawing_info = &worksheet.drawing.drawings[0];
+        assert_eq!([0.0, 12.5, 50.0, 0.0], drawing_info.crop);
+        assert_eq!(152400.0, drawing_info.width);
+        assert_eq!(266700.0, drawing_info.height);
+    }
+
+    #[test]
+    fn number_to_str() {
+        let mut numbers = vec![
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            0.5,
+            1.0 / 3.0,
+            1.25e-7,
+            123_456_789.123,
+            1e15,
+            1e16,
+            1e21,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::INFINITY,
+            f64::NAN,
+            9_007_199_254_740_992.0,
+            9_007_199_254_740_994.0,
+        ];
+
+        let mut number = 1.0e-10;
+        while number < 1.0e25 {
+            numbers.push(number);
+            numbers.push(-number * 7.3);
+            number *= 3.1;
+        }
+
+        for number in numbers {
+            let mut buffer = itoa::Buffer::new();
+            assert_eq!(
+                number.to_string(),
+                Worksheet::number_to_str(number, &mut buffer)
+            );
+        }
+    }
+
+    #[test]
+    fn format_index_cache() {
+        let bold = Format::new().set_bold();
+        let italic = Format::new().set_italic();
+        let mut worksheet = Worksheet::new();
+
+        let formats = [&bold, &bold, &italic, &bold, &Format::default(), &italic];
+        for (col, format) in formats.iter().enumerate() {
+            worksheet
+                .write_with_format(0, col as ColNum, 1, format)
+                .unwrap();
+        }
+
+        let xf_indices: Vec<u32> = worksheet.data_table[&0]
+            .iter()
+            .map(|(_, cell)| match cell {
+                CellType::Number { xf_index, .. } => *xf_index,
+                _ => unreachable!(),
+            })
+            .collect();
+
+        assert_eq!(vec![1, 1, 2, 1, 0, 2], xf_indices);
+        assert_eq!(3, worksheet.xf_formats.len());
+    }
+
+    #[test]
+    fn interned_strings() {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write(0, 0, "Label").unwrap();
+        worksheet.write(1, 0, "Label".to_string()).unwrap();
+        worksheet.write(2, 0, "Other").unwrap();
+
+        let string = |row: RowNum| match worksheet.data_table[&row].get(0) {
+            Some(CellType::String { string, .. }) => Arc::clone(string),
+            _ => unreachable!(),
+        };
+
+        assert!(Arc::ptr_eq(&string(0), &string(1)));
+        assert!(!Arc::ptr_eq(&string(0), &string(2)));
+    }
+
+    #[test]
+    fn cell_row_ordering() {
+        let mut string_table = SharedStringsTable::new();
+
+        let mut expected = Worksheet::new();
+        for col in 0..10 {
+            expected.write(0, col, col).unwrap();
+        }
+        expected.assemble_xml_file(&mut string_table);
+
+        // Write the cells out of order and overwrite some of them.
+        let mut worksheet = Worksheet::new();
+        for col in (0..10).rev() {
+            worksheet.write(0, col, "Overwritten").unwrap();
+        }
+        for col in [5, 0, 9, 2, 7, 1, 8, 3, 6, 4] {
+            worksheet.write(0, col, col).unwrap();
+        }
+
+        let mut string_table = SharedStringsTable::new();
+        worksheet.assemble_xml_file(&mut string_table);
+
+        let expected = xml_to_vec(expected.writer.read_to_str());
+        let got = xml_to_vec(worksheet.writer.read_to_str());
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn row_writer() {
+        let format = Format::new().set_bold();
+        let mut string_table = SharedStringsTable::new();
+
+        // Write the data cell by cell.
+        let mut expected = Worksheet::new();
+        expected.write(0, 0, "Header").unwrap();
+        expected.write(1, 0, "Foo").unwrap();
+        expected.write(1, 1, 1).unwrap();
+        expected.write_with_format(1, 3, true, &format).unwrap();
+        expected.write(2, 0, "Bar").unwrap();
+        expected.write(2, 1, 2).unwrap();
+        expected.set_global_xf_indices(&[0, 1]);
+        expected.assemble_xml_file(&mut string_table);
+
+        // Write the same data with a row writer, after an existing row.
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "Header").unwrap();
+
+        let mut row_writer = worksheet.row_writer();
+        assert_eq!(1, row_writer.row());
+
+        row_writer
+            .push_cell("Foo")
+            .unwrap()
+            .push_cell(1)
+            .unwrap()
+            .skip_cell()
+            .push_cell_with_format(true, &format)
+            .unwrap()
+            .end_row();
+
+        row_writer.push_cell("Bar").unwrap().push_cell(2).unwrap();
+        assert_eq!(2, row_writer.row());
+        assert_eq!(2, row_writer.col());
+
+        // The last row is added to the worksheet when the writer is dropped.
+        drop(row_writer);
+        assert!(worksheet.buffered_row.is_none());
+
+        let mut string_table = SharedStringsTable::new();
+        worksheet.set_global_xf_indices(&[0, 1]);
+        worksheet.assemble_xml_file(&mut string_table);
+
+        let expected = xml_to_vec(expected.writer.read_to_str());
+        let got = xml_to_vec(worksheet.writer.read_to_str());
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn row_writer_errors() {
+        let mut worksheet = Worksheet::new();
+        worksheet.write(ROW_MAX - 1, 0, "Last").unwrap();
+
+        let mut row_writer = worksheet.row_writer();
+        let result = row_writer.push_cell("Foo");
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        // Skipping past the last column shouldn't overflow.
+        let mut worksheet = Worksheet::new();
+        let mut row_writer = worksheet.row_writer();
+        for _ in 0..=u16::MAX {
+            row_writer.skip_cell();
+        }
+        assert_eq!(u16::MAX, row_writer.col());
+
+        let result = row_writer.push_cell("Foo");
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+    }
+
+    #[test]
+    fn row_writer_forget() {
+        let mut string_table = SharedStringsTable::new();
+
+        let mut expected = Worksheet::new();
+        expected.write(0, 0, "Foo").unwrap();
+        expected.write(0, 1, "Bar").unwrap();
+        expected.assemble_xml_file(&mut string_table);
+
+        // The buffered row should be written even if the writer isn't dropped.
+        let mut worksheet = Worksheet::new();
+        let mut row_writer = worksheet.row_writer();
+        row_writer.push_cell("Foo").unwrap();
+        std::mem::forget(row_writer);
+        worksheet.write(0, 1, "Bar").unwrap();
+
+        // A new row writer should start after the buffered row.
+        assert_eq!(1, worksheet.row_writer().row());
+
+        let mut string_table = SharedStringsTable::new();
+        worksheet.assemble_xml_file(&mut string_table);
+
+        let expected = xml_to_vec(expected.writer.read_to_str());
+        let got = xml_to_vec(worksheet.writer.read_to_str());
+
+        assert_eq!(expected, got);
+    }
 }
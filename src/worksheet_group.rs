@@ -0,0 +1,202 @@
+// worksheet_group - A module for applying the same operations to several
+// worksheets.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use crate::{ColNum, Format, IntoExcelData, PageSetup, RowNum, Worksheet, XlsxError};
+
+/// The `WorksheetGroup` struct is used to apply the same operations to several
+/// worksheets at once.
+///
+/// This is similar to grouped sheet editing in Excel where the same data,
+/// formatting and page setup is applied to all of the selected worksheets. It
+/// is useful for report packs where, for example, each month has a worksheet
+/// with the same layout.
+///
+/// A `WorksheetGroup` is created with
+/// [`workbook.group()`](crate::Workbook::group) and holds mutable references
+/// to the grouped worksheets until it goes out of scope. Operations that
+/// aren't directly supported by the group can be applied to each worksheet
+/// with [`WorksheetGroup::apply()`].
+///
+/// # Examples
+///
+/// The following example demonstrates writing the same headers and page setup
+/// to a group of worksheets.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_group_intro.rs
+/// #
+/// # use rust_xlsxwriter::{Format, PageSetup, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #
+///     let bold = Format::new().set_bold();
+///     let page_setup = PageSetup::new().set_landscape().set_header("&CMonthly Sales");
+///
+///     for month in ["Jan", "Feb", "Mar"] {
+///         let worksheet = workbook.add_worksheet();
+///         worksheet.set_name(month)?;
+///     }
+///
+///     // Apply the same layout to all three worksheets.
+///     let mut group = workbook.group(&["Jan", "Feb", "Mar"])?;
+///     group.write_with_format(0, 0, "Region", &bold)?;
+///     group.write_with_format(0, 1, "Sales", &bold)?;
+///     group.set_page_setup(&page_setup)?;
+///     group.apply(|worksheet| {
+///         worksheet.set_column_width(0, 16)?;
+///         Ok(())
+///     })?;
+///
+///     // Add the worksheet specific data.
+///     let worksheet = workbook.worksheet_from_name("Feb")?;
+///     worksheet.write(1, 0, "North")?;
+///     worksheet.write(1, 1, 1234)?;
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+pub struct WorksheetGroup<'a> {
+    pub(crate) worksheets: Vec<&'a mut Worksheet>,
+}
+
+impl<'a> WorksheetGroup<'a> {
+    /// Get the number of worksheets in the group.
+    pub fn len(&self) -> usize {
+        self.worksheets.len()
+    }
+
+    /// Check if the group doesn't contain any worksheets.
+    pub fn is_empty(&self) -> bool {
+        self.worksheets.is_empty()
+    }
+
+    /// Apply a function or closure to each worksheet in the group.
+    ///
+    /// The function is called with a mutable reference to each worksheet, in
+    /// workbook order. This can be used for any worksheet operation that isn't
+    /// directly supported by the group.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - A function or closure that takes a mutable reference to
+    ///   a [`Worksheet`].
+    ///
+    /// # Errors
+    ///
+    /// Any error returned by the function is returned immediately and the
+    /// remaining worksheets aren't updated.
+    ///
+    pub fn apply<F>(&mut self, mut function: F) -> Result<&mut WorksheetGroup<'a>, XlsxError>
+    where
+        F: FnMut(&mut Worksheet) -> Result<(), XlsxError>,
+    {
+        for worksheet in &mut self.worksheets {
+            function(worksheet)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write generic data to a cell in each worksheet of the group.
+    ///
+    /// See [`Worksheet::write()`](crate::Worksheet::write).
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `data` - An type that implements the [`IntoExcelData`] trait and
+    ///   [`Clone`].
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    pub fn write<T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: T,
+    ) -> Result<&mut WorksheetGroup<'a>, XlsxError>
+    where
+        T: IntoExcelData + Clone,
+    {
+        self.apply(|worksheet| {
+            worksheet.write(row, col, data.clone())?;
+            Ok(())
+        })
+    }
+
+    /// Write formatted generic data to a cell in each worksheet of the group.
+    ///
+    /// See [`Worksheet::write_with_format()`](crate::Worksheet::write_with_format).
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    /// * `data` - An type that implements the [`IntoExcelData`] trait and
+    ///   [`Clone`].
+    /// * `format` - The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// * [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    pub fn write_with_format<T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: T,
+        format: &Format,
+    ) -> Result<&mut WorksheetGroup<'a>, XlsxError>
+    where
+        T: IntoExcelData + Clone,
+    {
+        self.apply(|worksheet| {
+            worksheet.write_with_format(row, col, data.clone(), format)?;
+            Ok(())
+        })
+    }
+
+    /// Apply a set of page setup options to each worksheet in the group.
+    ///
+    /// See [`Worksheet::set_page_setup()`](crate::Worksheet::set_page_setup).
+    ///
+    /// # Arguments
+    ///
+    /// * `page_setup` - A [`PageSetup`] struct with the options to apply.
+    ///
+    /// # Errors
+    ///
+    /// * [`XlsxError::RowColumnLimitError`] - The print area row or column
+    ///   exceeds Excel's worksheet limits.
+    /// * [`XlsxError::RowColumnOrderError`] - The print area first row or
+    ///   column is larger than the last row or column.
+    ///
+    pub fn set_page_setup(
+        &mut self,
+        page_setup: &PageSetup,
+    ) -> Result<&mut WorksheetGroup<'a>, XlsxError> {
+        self.apply(|worksheet| {
+            worksheet.set_page_setup(page_setup)?;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,170 @@
+// worksheet_options - A module for defining reusable worksheet view options.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use crate::{ColNum, Color, IntoColor, RowNum};
+
+/// The `WorksheetOptions` struct is used to define a worksheet configuration
+/// that can be applied in a single call.
+///
+/// The common worksheet setup methods, such as
+/// [`set_name()`](crate::Worksheet::set_name) or
+/// [`set_freeze_panes()`](crate::Worksheet::set_freeze_panes), can be chained
+/// on the worksheet. Alternatively the options can be defined once in a
+/// `WorksheetOptions` struct and applied with
+/// [`worksheet.set_options()`](crate::Worksheet::set_options).
+///
+/// The methods of `WorksheetOptions` have the same names, arguments and
+/// defaults as the equivalent [`Worksheet`](crate::Worksheet) methods, and
+/// only the options that are set are applied to the worksheet. Page setup
+/// options are defined separately in a [`PageSetup`](crate::PageSetup)
+/// struct.
+///
+/// # Examples
+///
+/// The following example demonstrates applying the same options to several
+/// worksheets.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_options_intro.rs
+/// #
+/// # use rust_xlsxwriter::{Color, Workbook, WorksheetOptions, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #
+///     let options = WorksheetOptions::new()
+///         .set_tab_color(Color::Green)
+///         .set_zoom(80)
+///         .set_freeze_panes(1, 0)
+///         .set_screen_gridlines(false);
+///
+///     for region in ["North", "South", "East", "West"] {
+///         let worksheet = workbook.add_worksheet();
+///         worksheet.set_name(region)?.set_options(&options)?;
+///     }
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct WorksheetOptions {
+    pub(crate) name: Option<String>,
+    pub(crate) tab_color: Option<Color>,
+    pub(crate) zoom: Option<u16>,
+    pub(crate) freeze_panes: Option<(RowNum, ColNum)>,
+    pub(crate) right_to_left: Option<bool>,
+    pub(crate) screen_gridlines: Option<bool>,
+    pub(crate) hidden: Option<bool>,
+}
+
+impl WorksheetOptions {
+    /// Create a new `WorksheetOptions` struct with no options set.
+    pub fn new() -> WorksheetOptions {
+        WorksheetOptions::default()
+    }
+
+    /// Set the worksheet name.
+    ///
+    /// See [`Worksheet::set_name()`](crate::Worksheet::set_name). The name is
+    /// checked when the options are applied to a worksheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The worksheet name. It must follow the Excel rules, shown
+    ///   in `Worksheet::set_name()`.
+    ///
+    pub fn set_name(mut self, name: impl Into<String>) -> WorksheetOptions {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the color of the worksheet tab.
+    ///
+    /// See [`Worksheet::set_tab_color()`](crate::Worksheet::set_tab_color).
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The tab color property defined by a [`Color`] enum
+    ///   value.
+    ///
+    pub fn set_tab_color(mut self, color: impl IntoColor) -> WorksheetOptions {
+        self.tab_color = Some(color.new_color());
+        self
+    }
+
+    /// Set the worksheet zoom factor.
+    ///
+    /// See [`Worksheet::set_zoom()`](crate::Worksheet::set_zoom).
+    ///
+    /// # Arguments
+    ///
+    /// * `zoom` - The worksheet zoom level in the range 10 <= zoom <= 400.
+    ///
+    pub fn set_zoom(mut self, zoom: u16) -> WorksheetOptions {
+        self.zoom = Some(zoom);
+        self
+    }
+
+    /// Freeze panes in a worksheet.
+    ///
+    /// See [`Worksheet::set_freeze_panes()`](crate::Worksheet::set_freeze_panes).
+    /// The cell is checked when the options are applied to a worksheet.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The zero indexed row number.
+    /// * `col` - The zero indexed column number.
+    ///
+    pub fn set_freeze_panes(mut self, row: RowNum, col: ColNum) -> WorksheetOptions {
+        self.freeze_panes = Some((row, col));
+        self
+    }
+
+    /// Display the worksheet cells from right to left for some versions of
+    /// Excel.
+    ///
+    /// See [`Worksheet::set_right_to_left()`](crate::Worksheet::set_right_to_left).
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off.
+    ///
+    pub fn set_right_to_left(mut self, enable: bool) -> WorksheetOptions {
+        self.right_to_left = Some(enable);
+        self
+    }
+
+    /// Set the option to turn on/off the screen gridlines.
+    ///
+    /// See [`Worksheet::set_screen_gridlines()`](crate::Worksheet::set_screen_gridlines).
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is on by default.
+    ///
+    pub fn set_screen_gridlines(mut self, enable: bool) -> WorksheetOptions {
+        self.screen_gridlines = Some(enable);
+        self
+    }
+
+    /// Hide the worksheet.
+    ///
+    /// See [`Worksheet::set_hidden()`](crate::Worksheet::set_hidden).
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Turn the property on/off. It is off by default.
+    ///
+    pub fn set_hidden(mut self, enable: bool) -> WorksheetOptions {
+        self.hidden = Some(enable);
+        self
+    }
+}
@@ -8,12 +8,21 @@
 
 use std::borrow::Cow;
 use std::io::{Cursor, Write};
+use std::mem;
 use std::str;
 
 use regex::Regex;
 
 pub(crate) const XML_WRITE_ERROR: &str = "Couldn't write to xml file";
 
+// The size at which large xml files, such as worksheets, are passed to the
+// flush callback while they are being written.
+pub(crate) const XML_CHUNK_SIZE: usize = 1024 * 1024;
+
+// Callback that takes a filled xml buffer, to be written to the output, and
+// returns an empty buffer to continue writing to.
+pub(crate) type XmlFlush<'a> = &'a mut dyn FnMut(Vec<u8>) -> Vec<u8>;
+
 #[derive(Clone)]
 pub struct XMLWriter {
     pub(crate) xmlfile: Cursor<Vec<u8>>,
@@ -50,6 +59,18 @@ impl XMLWriter {
         self.xmlfile.set_position(0);
     }
 
+    // Pass the xml written so far to the flush callback, if there is one and
+    // the buffer has reached the chunk size, and continue in a new buffer.
+    pub(crate) fn flush_chunk(&mut self, flush: &mut Option<XmlFlush>) {
+        if let Some(flush) = flush {
+            if self.xmlfile.get_ref().len() >= XML_CHUNK_SIZE {
+                let buffer = mem::take(self.xmlfile.get_mut());
+                *self.xmlfile.get_mut() = flush(buffer);
+                self.xmlfile.set_position(0);
+            }
+        }
+    }
+
     // Write an XML file declaration.
     pub(crate) fn xml_declaration(&mut self) {
         self.xmlfile
@@ -156,25 +177,25 @@ impl XMLWriter {
 }
 
 // Escape XML characters in attributes.
-pub(crate) fn escape_attributes(attribute: &str) -> Cow<str> {
+pub(crate) fn escape_attributes(attribute: &str) -> Cow<'_, str> {
     escape_string(attribute, match_attribute_html_char)
 }
 
 // Escape XML characters in data sections of tags.  Note, this
 // is different from escape_attributes() because double quotes
 // and newline are not escaped by Excel.
-pub(crate) fn escape_data(data: &str) -> Cow<str> {
+pub(crate) fn escape_data(data: &str) -> Cow<'_, str> {
     escape_string(data, match_data_html_char)
 }
 
 // Escape XML characters in shared strings. In particular we need to escape
 // control and non-printing characters in the range '\x00' - '\x1F'.
-pub(crate) fn escape_si_data(data: &str) -> Cow<str> {
+pub(crate) fn escape_si_data(data: &str) -> Cow<'_, str> {
     escape_string(data, match_shared_string_char)
 }
 
 // Escape non-url characters in a hyperlink/url.
-pub(crate) fn escape_url(data: &str) -> Cow<str> {
+pub(crate) fn escape_url(data: &str) -> Cow<'_, str> {
     escape_string(data, match_url_char)
 }
 
@@ -266,7 +287,7 @@ fn match_url_char(ch: char) -> Option<&'static str> {
 }
 
 // Generic escape function with function pointer for the required handler.
-fn escape_string<F>(original: &str, char_handler: F) -> Cow<str>
+fn escape_string<F>(original: &str, char_handler: F) -> Cow<'_, str>
 where
     F: FnOnce(char) -> Option<&'static str> + Copy,
 {
@@ -293,7 +314,7 @@ where
 // Excel escapes control characters with _xHHHH_ and also escapes any literal
 // strings of that type by encoding the leading underscore. So "\0" -> _x0000_
 // and "_x0000_" -> _x005F_x0000_.
-fn escape_xml_escapes(si_string: &str) -> Cow<str> {
+pub(crate) fn escape_xml_escapes(si_string: &str) -> Cow<'_, str> {
     lazy_static! {
         static ref XML_ESCAPE: Regex = Regex::new("(_x[0-9a-fA-F]{4}_)").unwrap();
     }
@@ -305,15 +326,18 @@ pub(crate) trait IntoAttribute {
     fn write_to(&self, xmlfile: &mut Cursor<Vec<u8>>);
 }
 
-impl IntoAttribute for (&str, &str) {
+// The attribute value can be any string type, such as a &str, String or
+// Cow<str>. Cow<str> values allow an attribute list to mix borrowed strings
+// with owned strings that are created for numeric values.
+impl<T: AsRef<str>> IntoAttribute for (&str, T) {
     fn write_to(&self, xmlfile: &mut Cursor<Vec<u8>>) {
-        write!(xmlfile, r#" {}="{}""#, self.0, escape_attributes(self.1)).expect(XML_WRITE_ERROR);
-    }
-}
-
-impl IntoAttribute for (&str, String) {
-    fn write_to(&self, xmlfile: &mut Cursor<Vec<u8>>) {
-        write!(xmlfile, r#" {}="{}""#, self.0, escape_attributes(&self.1)).expect(XML_WRITE_ERROR);
+        write!(
+            xmlfile,
+            r#" {}="{}""#,
+            self.0,
+            escape_attributes(self.1.as_ref())
+        )
+        .expect(XML_WRITE_ERROR);
     }
 }
 
@@ -325,6 +349,7 @@ mod tests {
 
     use crate::xmlwriter::XMLWriter;
     use pretty_assertions::assert_eq;
+    use std::borrow::Cow;
 
     #[test]
     fn test_xml_declaration() {
@@ -371,6 +396,22 @@ mod tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn test_xml_start_tag_with_cow_attributes() {
+        let expected = r#"<foo span="8" baz="7" bar="&amp;">"#;
+        let attributes = vec![
+            ("span", Cow::Borrowed("8")),
+            ("baz", Cow::Owned(7.to_string())),
+            ("bar", Cow::Borrowed("&")),
+        ];
+
+        let mut writer = XMLWriter::new();
+        writer.xml_start_tag("foo", &attributes);
+
+        let got = writer.read_to_str();
+        assert_eq!(expected, got);
+    }
+
     #[test]
     fn test_xml_end_tag() {
         let expected = "</foo>";
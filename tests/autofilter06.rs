@@ -13,7 +13,7 @@ extern crate lazy_static;
 mod common;
 
 // Test to demonstrate autofilters.
-fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
 
     let worksheet = workbook.add_worksheet();
@@ -49,11 +49,60 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test with the explicit non-blanks filter.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Write the headers.
+    worksheet.write_string(0, 0, "Region")?;
+    worksheet.write_string(0, 1, "Item")?;
+    worksheet.write_string(0, 2, "Volume")?;
+    worksheet.write_string(0, 3, "Month")?;
+
+    // Write the data used in the autofilter.
+    let mut data = common::get_autofilter_data();
+
+    // Create a blank cell for testing.
+    data[5].0 = "";
+
+    for (row, data) in data.iter().enumerate() {
+        let row = 1 + row as u32;
+        worksheet.write_string(row, 0, data.0)?;
+        worksheet.write_string(row, 1, data.1)?;
+        worksheet.write_number(row, 2, data.2)?;
+        worksheet.write_string(row, 3, data.3)?;
+    }
+
+    worksheet.autofilter(0, 0, 50, 3)?;
+
+    let filter_condition = FilterCondition::new().add_list_non_blanks_filter();
+    worksheet.filter_column(0, &filter_condition)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_autofilter06_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("autofilter06")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
 #[test]
-fn test_autofilter06() {
+fn test_autofilter06_2() {
     let test_runner = common::TestRunner::new()
         .set_name("autofilter06")
-        .set_function(create_new_xlsx_file)
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
         .initialize();
 
     test_runner.assert_eq();
@@ -5,7 +5,9 @@
 //
 // Copyright 2022-2023, John McNamara, jmcnamara@cpan.org
 
+use std::cell::RefCell;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 use rust_xlsxwriter::{Workbook, XlsxError};
 
@@ -73,6 +75,49 @@ fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case to demonstrate creating a basic file via a non-seekable writer.
+fn create_new_xlsx_file_7(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let _worksheet = workbook.add_worksheet();
+
+    let mut buf = vec![];
+    workbook.save_to_writer(&mut buf)?;
+
+    // Write the buffer out to a file.
+    let mut file = std::fs::File::create(filename)?;
+    std::io::Write::write_all(&mut file, &buf)?;
+
+    Ok(())
+}
+
+// Test case to demonstrate creating a basic file via a writer that can't be
+// sent to another thread.
+fn create_new_xlsx_file_8(filename: &str) -> Result<(), XlsxError> {
+    struct SharedWriter(Rc<RefCell<Vec<u8>>>);
+
+    impl std::io::Write for SharedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut workbook = Workbook::new();
+    let _worksheet = workbook.add_worksheet();
+
+    let buf = Rc::new(RefCell::new(vec![]));
+    workbook.save_to_writer(SharedWriter(Rc::clone(&buf)))?;
+
+    // Write the buffer out to a file.
+    let mut file = std::fs::File::create(filename)?;
+    std::io::Write::write_all(&mut file, &buf.borrow())?;
+
+    Ok(())
+}
+
 // Test case for multiple saves.
 fn create_new_xlsx_file_6(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
@@ -144,6 +189,30 @@ fn bootstrap01_new_from_buffer() {
     test_runner.cleanup();
 }
 
+#[test]
+fn bootstrap01_new_from_writer() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap01")
+        .set_function(create_new_xlsx_file_7)
+        .unique("7")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn bootstrap01_new_from_non_send_writer() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap01")
+        .set_function(create_new_xlsx_file_8)
+        .unique("8")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
 #[test]
 fn bootstrap01_multi_save1() {
     let test_runner = common::TestRunner::new()
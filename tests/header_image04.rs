@@ -13,7 +13,7 @@ extern crate lazy_static;
 mod common;
 
 // Test to demonstrate adding header/footer images to worksheets.
-fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
 
     let worksheet = workbook.add_worksheet();
@@ -32,11 +32,42 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test with all of the footer images set in one call.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    let image1 = Image::new("tests/input/images/red.jpg")?;
+    let image2 = Image::new("tests/input/images/blue.jpg")?;
+    let image3 = Image::new("tests/input/images/yellow.jpg")?;
+
+    worksheet.set_footer("&L&G&C&G&R&G");
+    worksheet.set_footer_images([Some(&image1), Some(&image2), Some(&image3)])?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_header_image04_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("header_image04")
+        .set_function(create_new_xlsx_file_1)
+        .unique("1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
 #[test]
-fn test_header_image04() {
+fn test_header_image04_2() {
     let test_runner = common::TestRunner::new()
         .set_name("header_image04")
-        .set_function(create_new_xlsx_file)
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
         .initialize();
 
     test_runner.assert_eq();